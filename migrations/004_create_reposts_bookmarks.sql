@@ -0,0 +1,21 @@
+-- Create reposts table for tracking user reposts
+CREATE TABLE IF NOT EXISTS reposts (
+    user_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    post_id UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_uid, post_id)
+);
+
+-- Index for checking if user reposted a post
+CREATE INDEX IF NOT EXISTS idx_reposts_post ON reposts(post_id);
+
+-- Create bookmarks table for private saved posts
+CREATE TABLE IF NOT EXISTS bookmarks (
+    user_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    post_id UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_uid, post_id)
+);
+
+-- Index for listing a user's bookmarks (most recent first)
+CREATE INDEX IF NOT EXISTS idx_bookmarks_user ON bookmarks(user_uid, created_at DESC);
@@ -0,0 +1,23 @@
+-- Create follows table (follow graph)
+CREATE TABLE IF NOT EXISTS follows (
+    follower_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    followee_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (follower_uid, followee_uid),
+    CHECK (follower_uid <> followee_uid)
+);
+
+-- Index for listing followers of a user
+CREATE INDEX IF NOT EXISTS idx_follows_followee ON follows(followee_uid);
+
+-- Create blocks table
+CREATE TABLE IF NOT EXISTS blocks (
+    blocker_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    blocked_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (blocker_uid, blocked_uid),
+    CHECK (blocker_uid <> blocked_uid)
+);
+
+-- Index for checking blocks in the reverse direction
+CREATE INDEX IF NOT EXISTS idx_blocks_blocked ON blocks(blocked_uid);
@@ -0,0 +1,46 @@
+-- Track the root post of each thread (NULL for top-level posts)
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS root_id UUID REFERENCES posts(id) ON DELETE SET NULL;
+
+-- Backfill root_id for existing replies
+WITH RECURSIVE thread AS (
+    SELECT id, id AS root FROM posts WHERE parent_id IS NULL
+    UNION ALL
+    SELECT p.id, t.root FROM posts p JOIN thread t ON p.parent_id = t.id
+)
+UPDATE posts SET root_id = thread.root
+FROM thread
+WHERE posts.id = thread.id AND posts.parent_id IS NOT NULL AND posts.root_id IS NULL;
+
+-- Index for thread lookups
+CREATE INDEX IF NOT EXISTS idx_posts_root ON posts(root_id) WHERE root_id IS NOT NULL;
+
+-- Create muted_users table (account mutes)
+CREATE TABLE IF NOT EXISTS muted_users (
+    user_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    muted_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    expires_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_uid, muted_uid),
+    CHECK (user_uid <> muted_uid)
+);
+
+-- Create muted_threads table (conversation mutes, keyed by root post)
+CREATE TABLE IF NOT EXISTS muted_threads (
+    user_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    root_post_id UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    expires_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_uid, root_post_id)
+);
+
+-- Create muted_words table (keyword and phrase filters)
+CREATE TABLE IF NOT EXISTS muted_words (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    phrase VARCHAR(100) NOT NULL,
+    expires_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One filter per phrase and user, case-insensitive
+CREATE UNIQUE INDEX IF NOT EXISTS idx_muted_words_user_phrase ON muted_words(user_uid, LOWER(phrase));
@@ -0,0 +1,16 @@
+-- Create bookmark_folders table (user-named folders for bookmarks)
+CREATE TABLE IF NOT EXISTS bookmark_folders (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    name VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One folder per name and user, case-insensitive
+CREATE UNIQUE INDEX IF NOT EXISTS idx_bookmark_folders_user_name ON bookmark_folders(user_uid, LOWER(name));
+
+-- Bookmarks can be filed into a folder (deleting the folder keeps the bookmarks)
+ALTER TABLE bookmarks ADD COLUMN IF NOT EXISTS folder_id UUID REFERENCES bookmark_folders(id) ON DELETE SET NULL;
+
+-- Index for listing a folder's bookmarks
+CREATE INDEX IF NOT EXISTS idx_bookmarks_folder ON bookmarks(folder_id, created_at DESC) WHERE folder_id IS NOT NULL;
@@ -0,0 +1,11 @@
+-- Create post_mentions table (resolved @username mentions)
+CREATE TABLE IF NOT EXISTS post_mentions (
+    post_id UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    mentioned_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    start_offset INTEGER NOT NULL,
+    end_offset INTEGER NOT NULL,
+    PRIMARY KEY (post_id, start_offset)
+);
+
+-- Index for the mentions feed of a user
+CREATE INDEX IF NOT EXISTS idx_post_mentions_user ON post_mentions(mentioned_uid);
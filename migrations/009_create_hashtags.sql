@@ -0,0 +1,23 @@
+-- Create post_hashtags table (normalized lowercase #tags)
+CREATE TABLE IF NOT EXISTS post_hashtags (
+    post_id UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    tag VARCHAR(100) NOT NULL,
+    start_offset INTEGER NOT NULL,
+    end_offset INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (post_id, start_offset)
+);
+
+-- Index for hashtag timelines
+CREATE INDEX IF NOT EXISTS idx_post_hashtags_tag ON post_hashtags(tag, created_at DESC);
+
+-- Index for trending computation over recent usage
+CREATE INDEX IF NOT EXISTS idx_post_hashtags_created_at ON post_hashtags(created_at DESC);
+
+-- Create trending_hashtags table (snapshot refreshed periodically)
+CREATE TABLE IF NOT EXISTS trending_hashtags (
+    tag VARCHAR(100) PRIMARY KEY,
+    recent_count BIGINT NOT NULL,
+    score DOUBLE PRECISION NOT NULL,
+    computed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
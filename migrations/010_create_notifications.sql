@@ -0,0 +1,28 @@
+-- Create notifications table (one row per group of similar events)
+CREATE TABLE IF NOT EXISTS notifications (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    recipient_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    kind VARCHAR(20) NOT NULL,
+    target_post_id UUID REFERENCES posts(id) ON DELETE CASCADE,
+    group_key VARCHAR(64) NOT NULL DEFAULT '',
+    actors_count BIGINT NOT NULL DEFAULT 0,
+    is_read BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Only one unread group per recipient, kind and target
+CREATE UNIQUE INDEX IF NOT EXISTS idx_notifications_unread_group
+    ON notifications(recipient_uid, kind, group_key) WHERE is_read = FALSE;
+
+-- Index for listing a user's notifications (most recent activity first)
+CREATE INDEX IF NOT EXISTS idx_notifications_recipient ON notifications(recipient_uid, updated_at DESC);
+
+-- Create notification_actors table (users behind a grouped notification)
+CREATE TABLE IF NOT EXISTS notification_actors (
+    notification_id UUID NOT NULL REFERENCES notifications(id) ON DELETE CASCADE,
+    actor_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    source_post_id UUID REFERENCES posts(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (notification_id, actor_uid)
+);
@@ -0,0 +1,45 @@
+-- Create user_settings table (account preferences, defaults apply when no row exists)
+CREATE TABLE IF NOT EXISTS user_settings (
+    user_uid VARCHAR(128) PRIMARY KEY REFERENCES users(uid) ON DELETE CASCADE,
+    dm_policy VARCHAR(16) NOT NULL DEFAULT 'everyone' CHECK (dm_policy IN ('everyone', 'followers', 'nobody')),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create conversations table (one-to-one and small groups)
+CREATE TABLE IF NOT EXISTS conversations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_by VARCHAR(128) REFERENCES users(uid) ON DELETE SET NULL,
+    is_group BOOLEAN NOT NULL DEFAULT FALSE,
+    direct_key VARCHAR(260),
+    last_message_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Only one one-to-one conversation per pair of users
+CREATE UNIQUE INDEX IF NOT EXISTS idx_conversations_direct_key
+    ON conversations(direct_key) WHERE direct_key IS NOT NULL;
+
+-- Create conversation_participants table (membership and read receipts)
+CREATE TABLE IF NOT EXISTS conversation_participants (
+    conversation_id UUID NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    user_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    last_read_at TIMESTAMPTZ,
+    joined_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (conversation_id, user_uid)
+);
+
+-- Index for listing a user's conversations
+CREATE INDEX IF NOT EXISTS idx_conversation_participants_user ON conversation_participants(user_uid);
+
+-- Create messages table
+CREATE TABLE IF NOT EXISTS messages (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    conversation_id UUID NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    sender_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    deleted_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Index for listing messages of a conversation (most recent first)
+CREATE INDEX IF NOT EXISTS idx_messages_conversation ON messages(conversation_id, created_at DESC);
@@ -0,0 +1,6 @@
+-- Full-text search vector, maintained by PostgreSQL ('simple' keeps words as typed, without stemming)
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS search_vector TSVECTOR
+    GENERATED ALWAYS AS (to_tsvector('simple', content)) STORED;
+
+-- GIN index for full-text queries
+CREATE INDEX IF NOT EXISTS idx_posts_search ON posts USING GIN(search_vector);
@@ -0,0 +1,16 @@
+-- Extensions for accent-insensitive and substring matching
+CREATE EXTENSION IF NOT EXISTS unaccent;
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- unaccent() is only STABLE: wrap it so it can be used in index expressions
+CREATE OR REPLACE FUNCTION immutable_unaccent(TEXT) RETURNS TEXT
+    LANGUAGE sql IMMUTABLE PARALLEL SAFE STRICT
+    AS $$ SELECT public.unaccent('public.unaccent'::regdictionary, $1) $$;
+
+-- Index for username prefix lookups (LIKE 'abc%')
+CREATE INDEX IF NOT EXISTS idx_users_username_prefix ON users(LOWER(username) text_pattern_ops);
+
+-- Trigram index for display name substring lookups
+CREATE INDEX IF NOT EXISTS idx_users_display_name_trgm
+    ON users USING GIN (LOWER(immutable_unaccent(display_name)) gin_trgm_ops);
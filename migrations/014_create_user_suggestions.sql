@@ -0,0 +1,22 @@
+-- Cached follow suggestions, recomputed periodically
+CREATE TABLE IF NOT EXISTS user_suggestions (
+    user_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    suggested_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    reason VARCHAR(32) NOT NULL,
+    mutual_count BIGINT NOT NULL DEFAULT 0,
+    score DOUBLE PRECISION NOT NULL,
+    PRIMARY KEY (user_uid, suggested_uid),
+    CHECK (user_uid <> suggested_uid)
+);
+
+-- Index for reading a user's suggestions by score
+CREATE INDEX IF NOT EXISTS idx_user_suggestions_score ON user_suggestions(user_uid, score DESC);
+
+-- Last computation per user (kept even when there is no suggestion)
+CREATE TABLE IF NOT EXISTS user_suggestion_runs (
+    user_uid VARCHAR(128) PRIMARY KEY REFERENCES users(uid) ON DELETE CASCADE,
+    computed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Index for finding stale caches
+CREATE INDEX IF NOT EXISTS idx_user_suggestion_runs_computed ON user_suggestion_runs(computed_at);
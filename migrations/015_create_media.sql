@@ -0,0 +1,22 @@
+-- Uploaded images, attached to at most one post
+CREATE TABLE IF NOT EXISTS media (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    owner_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    -- Deleting the post orphans the media, whose files are then purged by the cleanup job
+    post_id UUID REFERENCES posts(id) ON DELETE SET NULL,
+    storage_key TEXT NOT NULL UNIQUE,
+    url TEXT NOT NULL,
+    mime_type VARCHAR(50) NOT NULL,
+    width INTEGER NOT NULL,
+    height INTEGER NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    alt_text VARCHAR(1000),
+    position SMALLINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Index for loading the attachments of a post in order
+CREATE INDEX IF NOT EXISTS idx_media_post ON media(post_id, position) WHERE post_id IS NOT NULL;
+
+-- Index for finding orphaned uploads
+CREATE INDEX IF NOT EXISTS idx_media_orphans ON media(created_at) WHERE post_id IS NULL;
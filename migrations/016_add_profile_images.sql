@@ -0,0 +1,7 @@
+-- Profile banner
+ALTER TABLE users ADD COLUMN IF NOT EXISTS banner_url TEXT;
+
+-- Storage keys of uploaded profile images, to delete the previous files on replacement
+ALTER TABLE users ADD COLUMN IF NOT EXISTS avatar_key TEXT;
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS banner_key TEXT;
@@ -0,0 +1,33 @@
+-- Create polls table (at most one poll per post)
+CREATE TABLE IF NOT EXISTS polls (
+    post_id UUID PRIMARY KEY REFERENCES posts(id) ON DELETE CASCADE,
+    closes_at TIMESTAMPTZ NOT NULL,
+    hide_results BOOLEAN NOT NULL DEFAULT FALSE,
+    closed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Index for the periodic closing of expired polls
+CREATE INDEX IF NOT EXISTS idx_polls_open ON polls(closes_at) WHERE closed_at IS NULL;
+
+-- Create poll_options table (vote counters kept in sync with poll_votes)
+CREATE TABLE IF NOT EXISTS poll_options (
+    post_id UUID NOT NULL REFERENCES polls(post_id) ON DELETE CASCADE,
+    position SMALLINT NOT NULL,
+    label VARCHAR(100) NOT NULL,
+    votes_count BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (post_id, position)
+);
+
+-- Create poll_votes table (the primary key allows a single vote per user)
+CREATE TABLE IF NOT EXISTS poll_votes (
+    post_id UUID NOT NULL REFERENCES polls(post_id) ON DELETE CASCADE,
+    user_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    position SMALLINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (post_id, user_uid),
+    FOREIGN KEY (post_id, position) REFERENCES poll_options(post_id, position) ON DELETE CASCADE
+);
+
+-- Index for loading a user's votes
+CREATE INDEX IF NOT EXISTS idx_poll_votes_user ON poll_votes(user_uid);
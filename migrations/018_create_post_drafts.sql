@@ -0,0 +1,25 @@
+-- Create post_drafts table (drafts, and scheduled posts when publish_at is set)
+CREATE TABLE IF NOT EXISTS post_drafts (
+    id UUID PRIMARY KEY,
+    author_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    poll_options TEXT[],
+    poll_duration_minutes INTEGER,
+    poll_hide_results BOOLEAN NOT NULL DEFAULT FALSE,
+    publish_at TIMESTAMPTZ,
+    locked_until TIMESTAMPTZ,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Index for listing a user's drafts
+CREATE INDEX IF NOT EXISTS idx_post_drafts_author ON post_drafts(author_uid, updated_at DESC);
+
+-- Index for the scheduler
+CREATE INDEX IF NOT EXISTS idx_post_drafts_due ON post_drafts(publish_at) WHERE publish_at IS NOT NULL;
+
+-- Media reserved by a draft are kept until it is published or cancelled
+ALTER TABLE media ADD COLUMN IF NOT EXISTS draft_id UUID REFERENCES post_drafts(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_media_draft ON media(draft_id) WHERE draft_id IS NOT NULL
@@ -0,0 +1,8 @@
+-- Who may reply to a root post: everyone, following (people the author follows) or mentioned
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS reply_policy VARCHAR(16) NOT NULL DEFAULT 'everyone';
+
+-- Replies hidden by the author of the root post
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS is_hidden BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Reply policy of scheduled posts
+ALTER TABLE post_drafts ADD COLUMN IF NOT EXISTS reply_policy VARCHAR(16) NOT NULL DEFAULT 'everyone'
@@ -0,0 +1,2 @@
+-- Post pinned to the top of the profile, unpinned automatically when deleted
+ALTER TABLE users ADD COLUMN IF NOT EXISTS pinned_post_id UUID REFERENCES posts(id) ON DELETE SET NULL
@@ -0,0 +1,16 @@
+-- Who may see a post: public, followers (of the author) or close_friends (the author's list)
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS audience VARCHAR(16) NOT NULL DEFAULT 'public';
+
+-- Close friends list curated by each user
+CREATE TABLE IF NOT EXISTS close_friends (
+    user_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    friend_uid VARCHAR(128) NOT NULL REFERENCES users(uid) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_uid, friend_uid),
+    CHECK (user_uid <> friend_uid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_close_friends_friend ON close_friends(friend_uid);
+
+-- Audience of scheduled posts
+ALTER TABLE post_drafts ADD COLUMN IF NOT EXISTS audience VARCHAR(16) NOT NULL DEFAULT 'public'
@@ -0,0 +1,21 @@
+-- Staff accounts, allowed to moderate (granted directly in the database)
+ALTER TABLE users ADD COLUMN IF NOT EXISTS is_staff BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Content warnings: at most one label set by the author and one set by a moderator
+CREATE TABLE IF NOT EXISTS post_labels (
+    post_id UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    source VARCHAR(16) NOT NULL,
+    content_warning VARCHAR(100),
+    is_sensitive BOOLEAN NOT NULL DEFAULT FALSE,
+    labeled_by VARCHAR(128) REFERENCES users(uid) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (post_id, source),
+    CHECK (content_warning IS NOT NULL OR is_sensitive)
+);
+
+-- How labeled posts are shown: warn (collapsed), show (expanded) or hide
+ALTER TABLE user_settings ADD COLUMN IF NOT EXISTS sensitive_content VARCHAR(16) NOT NULL DEFAULT 'warn';
+
+-- Self-label of scheduled posts
+ALTER TABLE post_drafts ADD COLUMN IF NOT EXISTS content_warning VARCHAR(100);
+ALTER TABLE post_drafts ADD COLUMN IF NOT EXISTS is_sensitive BOOLEAN NOT NULL DEFAULT FALSE
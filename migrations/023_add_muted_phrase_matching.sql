@@ -0,0 +1,16 @@
+-- Keyword mutes match whole words or phrases, case-insensitively: muting "cat"
+-- hides "my cat!" but not "education". Regex metacharacters in the phrase are
+-- escaped and any run of whitespace matches any run of whitespace.
+CREATE OR REPLACE FUNCTION matches_muted_phrase(content TEXT, phrase TEXT)
+RETURNS BOOLEAN
+LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE
+AS $$
+    SELECT content ~* (
+        '(^|\W)'
+        || regexp_replace(
+            regexp_replace(trim(phrase), '([.^$*+?()\[\]{}|\\-])', '\\\1', 'g'),
+            '\s+', '\\s+', 'g'
+        )
+        || '($|\W)'
+    )
+$$;
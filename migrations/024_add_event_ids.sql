@@ -0,0 +1,3 @@
+-- Realtime events are numbered from one sequence shared by every instance, so a
+-- client can resume its stream with Last-Event-ID on any of them.
+CREATE SEQUENCE IF NOT EXISTS event_ids;
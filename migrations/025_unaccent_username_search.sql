@@ -0,0 +1,5 @@
+-- Username prefix lookups ignore accents on both sides: index the same expression
+DROP INDEX IF EXISTS idx_users_username_prefix;
+
+CREATE INDEX IF NOT EXISTS idx_users_username_unaccent_prefix
+    ON users(LOWER(immutable_unaccent(username)) text_pattern_ops);
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::bookmark::BookmarkFolder;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct BookmarkRequest {
+    /// Absent : bookmark hors dossier
+    pub folder_id: Option<Uuid>,
+}
+
+/// Filtre de `GET /bookmarks`, combiné avec `PaginationQuery`
+#[derive(Debug, Deserialize)]
+pub struct BookmarksFilter {
+    pub folder_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct BookmarkFolderRequest {
+    #[validate(length(min = 1, max = 50, message = "Le nom du dossier doit avoir entre 1 et 50 caractères"))]
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BookmarkFolderResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub bookmarks_count: i64,
+    pub created_at: String,
+}
+
+impl From<BookmarkFolder> for BookmarkFolderResponse {
+    fn from(folder: BookmarkFolder) -> Self {
+        Self {
+            id: folder.id,
+            name: folder.name,
+            bookmarks_count: folder.bookmarks_count,
+            created_at: folder.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BookmarkFoldersListResponse {
+    pub folders: Vec<BookmarkFolderResponse>,
+    pub count: usize,
+}
+
+impl BookmarkFoldersListResponse {
+    pub fn from_folders(folders: Vec<BookmarkFolder>) -> Self {
+        let count = folders.len();
+        Self {
+            folders: folders.into_iter().map(BookmarkFolderResponse::from).collect(),
+            count,
+        }
+    }
+}
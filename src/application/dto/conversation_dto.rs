@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use super::UserSummaryResponse;
+use crate::application::services::ConversationOverview;
+use crate::domain::conversation::{ConversationParticipant, Message};
+use crate::domain::user::User;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct StartConversationRequest {
+    /// Un destinataire pour une conversation à deux, plusieurs pour un groupe
+    #[validate(length(min = 1, max = 9, message = "Une conversation doit avoir entre 1 et 9 destinataires"))]
+    pub participant_uids: Vec<String>,
+    /// Premier message, optionnel
+    #[validate(length(min = 1, message = "Le contenu ne peut pas être vide"))]
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SendMessageRequest {
+    #[validate(length(min = 1, message = "Le contenu ne peut pas être vide"))]
+    pub content: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MessageResponse {
+    pub id: Uuid,
+    pub conversation_id: Uuid,
+    pub sender_uid: String,
+    /// Vide si le message a été supprimé
+    pub content: String,
+    pub is_deleted: bool,
+    pub created_at: String,
+}
+
+impl From<Message> for MessageResponse {
+    fn from(message: Message) -> Self {
+        Self {
+            id: message.id,
+            conversation_id: message.conversation_id,
+            sender_uid: message.sender_uid,
+            content: message.content,
+            is_deleted: message.deleted_at.is_some(),
+            created_at: message.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MessagesListResponse {
+    pub messages: Vec<MessageResponse>,
+    pub count: usize,
+}
+
+impl MessagesListResponse {
+    pub fn from_messages(messages: Vec<Message>) -> Self {
+        let count = messages.len();
+        Self {
+            messages: messages.into_iter().map(MessageResponse::from).collect(),
+            count,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ParticipantResponse {
+    pub user: UserSummaryResponse,
+    /// Accusé de lecture : messages lus jusqu'à cette date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read_at: Option<String>,
+}
+
+impl ParticipantResponse {
+    pub fn new(participant: ConversationParticipant, user: User) -> Self {
+        Self {
+            user: UserSummaryResponse::from(user),
+            last_read_at: participant.last_read_at.map(|d| d.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConversationResponse {
+    pub id: Uuid,
+    pub is_group: bool,
+    pub participants: Vec<ParticipantResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_message: Option<MessageResponse>,
+    pub unread_count: i64,
+    pub created_at: String,
+}
+
+impl From<ConversationOverview> for ConversationResponse {
+    fn from(overview: ConversationOverview) -> Self {
+        Self {
+            id: overview.conversation.id,
+            is_group: overview.conversation.is_group,
+            participants: overview
+                .participants
+                .into_iter()
+                .map(|(participant, user)| ParticipantResponse::new(participant, user))
+                .collect(),
+            last_message: overview.last_message.map(MessageResponse::from),
+            unread_count: overview.unread_count,
+            created_at: overview.conversation.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConversationsListResponse {
+    pub conversations: Vec<ConversationResponse>,
+    pub count: usize,
+}
+
+impl ConversationsListResponse {
+    pub fn from_overviews(overviews: Vec<ConversationOverview>) -> Self {
+        let count = overviews.len();
+        Self {
+            conversations: overviews.into_iter().map(ConversationResponse::from).collect(),
+            count,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use super::MediaResponse;
+use crate::domain::draft::Draft;
+use crate::domain::media::{Media, MediaAttachment};
+use crate::domain::poll::NewPoll;
+use crate::domain::post::{Audience, NewLabel, ReplyPolicy};
+
+/// Création ou remplacement complet d'un brouillon
+#[derive(Debug, Deserialize, Validate)]
+pub struct SaveDraftRequest {
+    #[validate(length(min = 1, message = "Le contenu ne peut pas être vide"))]
+    pub content: String,
+    #[serde(default)]
+    pub media: Vec<MediaAttachment>,
+    pub poll: Option<NewPoll>,
+    #[serde(default)]
+    pub reply_policy: ReplyPolicy,
+    #[serde(default)]
+    pub audience: Audience,
+    pub label: Option<NewLabel>,
+    /// Date de publication automatique ; absent pour un simple brouillon
+    pub publish_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScheduleDraftRequest {
+    /// `null` pour repasser en brouillon
+    pub publish_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DraftResponse {
+    pub id: Uuid,
+    /// `draft`, `scheduled` ou `publishing`
+    pub status: &'static str,
+    pub content: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub media: Vec<MediaResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<NewPoll>,
+    pub reply_policy: ReplyPolicy,
+    pub audience: Audience,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<NewLabel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publish_at: Option<String>,
+    /// Raison de l'échec de la dernière publication planifiée
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl DraftResponse {
+    pub fn new(draft: Draft, media: Vec<Media>) -> Self {
+        let status = if draft.is_locked() {
+            "publishing"
+        } else if draft.publish_at.is_some() {
+            "scheduled"
+        } else {
+            "draft"
+        };
+        let poll = draft.poll();
+        let reply_policy = draft.reply_policy();
+        let audience = draft.audience();
+        let label = draft.label();
+
+        Self {
+            id: draft.id,
+            status,
+            content: draft.content,
+            media: media.into_iter().map(MediaResponse::from).collect(),
+            poll,
+            reply_policy,
+            audience,
+            label,
+            publish_at: draft.publish_at.map(|at| at.to_rfc3339()),
+            last_error: draft.last_error,
+            created_at: draft.created_at.to_rfc3339(),
+            updated_at: draft.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DraftsListResponse {
+    pub drafts: Vec<DraftResponse>,
+    pub count: usize,
+}
+
+impl DraftsListResponse {
+    pub fn new(drafts: Vec<DraftResponse>) -> Self {
+        let count = drafts.len();
+        Self { drafts, count }
+    }
+}
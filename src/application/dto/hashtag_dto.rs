@@ -0,0 +1,41 @@
+use serde::Serialize;
+
+use crate::domain::hashtag::TrendingHashtag;
+
+#[derive(Debug, Serialize)]
+pub struct TrendingHashtagResponse {
+    pub tag: String,
+    pub recent_count: i64,
+    pub score: f64,
+}
+
+impl From<TrendingHashtag> for TrendingHashtagResponse {
+    fn from(hashtag: TrendingHashtag) -> Self {
+        Self {
+            tag: hashtag.tag,
+            recent_count: hashtag.recent_count,
+            score: hashtag.score,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrendingListResponse {
+    pub hashtags: Vec<TrendingHashtagResponse>,
+    pub count: usize,
+    /// Date du dernier calcul des tendances
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub computed_at: Option<String>,
+}
+
+impl TrendingListResponse {
+    pub fn from_hashtags(hashtags: Vec<TrendingHashtag>) -> Self {
+        let count = hashtags.len();
+        let computed_at = hashtags.first().map(|h| h.computed_at.to_rfc3339());
+        Self {
+            hashtags: hashtags.into_iter().map(TrendingHashtagResponse::from).collect(),
+            count,
+            computed_at,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::media::Media;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MediaResponse {
+    pub id: Uuid,
+    pub url: String,
+    pub mime_type: String,
+    pub width: i32,
+    pub height: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt_text: Option<String>,
+}
+
+impl From<Media> for MediaResponse {
+    fn from(media: Media) -> Self {
+        Self {
+            id: media.id,
+            url: media.url,
+            mime_type: media.mime_type,
+            width: media.width,
+            height: media.height,
+            alt_text: media.alt_text,
+        }
+    }
+}
@@ -1,5 +1,23 @@
+mod bookmark_dto;
+mod conversation_dto;
+mod draft_dto;
+mod hashtag_dto;
+mod media_dto;
+mod mute_dto;
+mod notification_dto;
+mod poll_dto;
 mod post_dto;
+mod search_dto;
 mod user_dto;
 
+pub use bookmark_dto::*;
+pub use conversation_dto::*;
+pub use draft_dto::*;
+pub use hashtag_dto::*;
+pub use media_dto::*;
+pub use mute_dto::*;
+pub use notification_dto::*;
+pub use poll_dto::*;
 pub use post_dto::*;
+pub use search_dto::*;
 pub use user_dto::*;
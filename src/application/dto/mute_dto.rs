@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use super::UserSummaryResponse;
+use crate::domain::mute::{MutedThread, MutedUser, MutedWord};
+use crate::domain::user::User;
+
+#[derive(Debug, Deserialize)]
+pub struct MuteUserRequest {
+    pub uid: String,
+    /// Absent : masquage permanent
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MuteThreadRequest {
+    /// N'importe quel post du fil : c'est le fil entier qui est masqué
+    pub post_id: Uuid,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct MuteWordRequest {
+    #[validate(length(min = 1, max = 100, message = "Le mot masqué doit avoir entre 1 et 100 caractères"))]
+    pub phrase: String,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MutedUserResponse {
+    pub user: UserSummaryResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    pub created_at: String,
+}
+
+impl MutedUserResponse {
+    pub fn new(mute: MutedUser, user: User) -> Self {
+        Self {
+            user: UserSummaryResponse::from(user),
+            expires_at: mute.expires_at.map(|d| d.to_rfc3339()),
+            created_at: mute.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MutedThreadResponse {
+    pub root_post_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<MutedThread> for MutedThreadResponse {
+    fn from(mute: MutedThread) -> Self {
+        Self {
+            root_post_id: mute.root_post_id,
+            expires_at: mute.expires_at.map(|d| d.to_rfc3339()),
+            created_at: mute.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MutedWordResponse {
+    pub id: Uuid,
+    pub phrase: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<MutedWord> for MutedWordResponse {
+    fn from(word: MutedWord) -> Self {
+        Self {
+            id: word.id,
+            phrase: word.phrase,
+            expires_at: word.expires_at.map(|d| d.to_rfc3339()),
+            created_at: word.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MutesListResponse<T> {
+    pub mutes: Vec<T>,
+    pub count: usize,
+}
+
+impl<T> MutesListResponse<T> {
+    pub fn new(mutes: Vec<T>) -> Self {
+        let count = mutes.len();
+        Self { mutes, count }
+    }
+}
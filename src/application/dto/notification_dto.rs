@@ -0,0 +1,59 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::UserSummaryResponse;
+use crate::domain::notification::Notification;
+use crate::domain::user::User;
+
+#[derive(Debug, Serialize)]
+pub struct NotificationResponse {
+    pub id: Uuid,
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_post_id: Option<Uuid>,
+    /// Derniers acteurs du groupe, du plus récent au plus ancien
+    pub actors: Vec<UserSummaryResponse>,
+    pub actors_count: i64,
+    pub is_read: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl NotificationResponse {
+    pub fn new(notification: Notification, actors: Vec<User>) -> Self {
+        Self {
+            id: notification.id,
+            kind: notification.kind,
+            target_post_id: notification.target_post_id,
+            actors: actors.into_iter().map(UserSummaryResponse::from).collect(),
+            actors_count: notification.actors_count,
+            is_read: notification.is_read,
+            created_at: notification.created_at.to_rfc3339(),
+            updated_at: notification.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NotificationsListResponse {
+    pub notifications: Vec<NotificationResponse>,
+    pub count: usize,
+}
+
+impl NotificationsListResponse {
+    pub fn from_notifications(notifications: Vec<(Notification, Vec<User>)>) -> Self {
+        let count = notifications.len();
+        Self {
+            notifications: notifications
+                .into_iter()
+                .map(|(notification, actors)| NotificationResponse::new(notification, actors))
+                .collect(),
+            count,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnreadCountResponse {
+    pub count: i64,
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::poll::{Poll, PollOption};
+
+#[derive(Debug, Deserialize)]
+pub struct PollVoteRequest {
+    /// Position de l'option choisie, à partir de 0
+    pub option: i16,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PollOptionResponse {
+    pub position: i16,
+    pub label: String,
+    /// Absent tant que les résultats sont cachés au lecteur
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub votes_count: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PollResponse {
+    pub options: Vec<PollOptionResponse>,
+    pub total_votes: i64,
+    pub closes_at: String,
+    pub closed: bool,
+    pub results_hidden: bool,
+    /// Option choisie par l'utilisateur connecté
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voted_option: Option<i16>,
+}
+
+impl PollResponse {
+    pub fn new(poll: Poll, options: Vec<PollOption>, voted_option: Option<i16>, results_hidden: bool) -> Self {
+        let total_votes = options.iter().map(|option| option.votes_count).sum();
+        Self {
+            options: options
+                .into_iter()
+                .map(|option| PollOptionResponse {
+                    position: option.position,
+                    label: option.label,
+                    votes_count: (!results_hidden).then_some(option.votes_count),
+                })
+                .collect(),
+            total_votes,
+            closes_at: poll.closes_at.to_rfc3339(),
+            closed: poll.is_closed(),
+            results_hidden,
+            voted_option,
+        }
+    }
+}
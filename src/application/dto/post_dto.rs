@@ -2,17 +2,38 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::domain::post::Post;
+use super::{MediaResponse, PollResponse, UserSummaryResponse};
+use crate::domain::media::MediaAttachment;
+use crate::domain::poll::NewPoll;
+use crate::domain::post::{
+    Audience, LabelSource, NewLabel, Post, PostHashtag, PostLabel, PostMention, PostViewerState,
+    ReplyPolicy, UrlCandidate,
+};
 
 #[derive(Debug, Deserialize, Validate)]
 pub struct CreatePostRequest {
-    #[validate(length(min = 1, max = 280, message = "Le contenu doit avoir entre 1 et 280 caractères"))]
+    /// Longueur maximum selon la politique de contenu du compte, vérifiée par le service
+    #[validate(length(min = 1, message = "Le contenu ne peut pas être vide"))]
     pub content: String,
+    /// Médias envoyés au préalable via `POST /api/v1/media`, dans l'ordre d'affichage
+    #[serde(default)]
+    pub media: Vec<MediaAttachment>,
+    /// Sondage de 2 à 4 options
+    pub poll: Option<NewPoll>,
+    /// Qui peut répondre ; ignoré pour une réponse, qui suit le réglage du post racine
+    #[serde(default)]
+    pub reply_policy: ReplyPolicy,
+    /// Qui peut voir le post : `public`, `followers` ou `close_friends`
+    #[serde(default)]
+    pub audience: Audience,
+    /// Avertissement de contenu posé par l'auteur
+    pub label: Option<NewLabel>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
 pub struct CreateReplyRequest {
-    #[validate(length(min = 1, max = 280, message = "Le contenu doit avoir entre 1 et 280 caractères"))]
+    /// Longueur maximum selon la politique de contenu du compte, vérifiée par le service
+    #[validate(length(min = 1, message = "Le contenu ne peut pas être vide"))]
     pub content: String,
     pub parent_id: Uuid,
 }
@@ -29,30 +50,175 @@ fn default_limit() -> i64 {
     20
 }
 
-#[derive(Debug, Serialize)]
+/// Indicateurs propres à l'utilisateur connecté
+#[derive(Debug, Clone, Serialize)]
+pub struct PostViewerResponse {
+    pub liked_by_me: bool,
+    pub reposted_by_me: bool,
+    pub bookmarked_by_me: bool,
+}
+
+impl From<PostViewerState> for PostViewerResponse {
+    fn from(state: PostViewerState) -> Self {
+        Self {
+            liked_by_me: state.liked,
+            reposted_by_me: state.reposted,
+            bookmarked_by_me: state.bookmarked,
+        }
+    }
+}
+
+/// Mention d'un utilisateur, positions en caractères (fin exclue)
+#[derive(Debug, Clone, Serialize)]
+pub struct MentionEntityResponse {
+    pub uid: String,
+    pub username: String,
+    pub start: i32,
+    pub end: i32,
+}
+
+impl From<PostMention> for MentionEntityResponse {
+    fn from(mention: PostMention) -> Self {
+        Self {
+            uid: mention.mentioned_uid,
+            username: mention.username,
+            start: mention.start_offset,
+            end: mention.end_offset,
+        }
+    }
+}
+
+/// Hashtag normalisé en minuscules, positions en caractères (fin exclue)
+#[derive(Debug, Clone, Serialize)]
+pub struct HashtagEntityResponse {
+    pub tag: String,
+    pub start: i32,
+    pub end: i32,
+}
+
+impl From<PostHashtag> for HashtagEntityResponse {
+    fn from(hashtag: PostHashtag) -> Self {
+        Self {
+            tag: hashtag.tag,
+            start: hashtag.start_offset,
+            end: hashtag.end_offset,
+        }
+    }
+}
+
+/// URL `http(s)://`, positions en caractères (fin exclue)
+#[derive(Debug, Clone, Serialize)]
+pub struct UrlEntityResponse {
+    pub url: String,
+    pub start: i32,
+    pub end: i32,
+}
+
+impl From<UrlCandidate> for UrlEntityResponse {
+    fn from(url: UrlCandidate) -> Self {
+        Self {
+            url: url.url,
+            start: url.start as i32,
+            end: url.end as i32,
+        }
+    }
+}
+
+/// Entités extraites du contenu d'un post
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct PostEntitiesResponse {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mentions: Vec<MentionEntityResponse>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hashtags: Vec<HashtagEntityResponse>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<UrlEntityResponse>,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct PostResponse {
     pub id: Uuid,
     pub author_uid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserSummaryResponse>,
     pub content: String,
+    pub entities: PostEntitiesResponse,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub media: Vec<MediaResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<PollResponse>,
     pub likes_count: i64,
     pub replies_count: i64,
     pub reposts_count: i64,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root_id: Option<Uuid>,
+    /// Réglage des réponses, pour un post racine
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_policy: Option<ReplyPolicy>,
+    pub audience: Audience,
+    /// Avertissements de l'auteur et de la modération
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<PostLabelResponse>,
+    /// Post à replier derrière ses avertissements, selon les réglages du lecteur
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub collapsed: bool,
+    /// Réponse masquée par l'auteur de la conversation, à replier côté client
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub hidden: bool,
+    /// Post épinglé en tête du profil de son auteur
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
     pub created_at: String,
+    #[serde(flatten)]
+    pub viewer: Option<PostViewerResponse>,
 }
 
 impl From<Post> for PostResponse {
     fn from(post: Post) -> Self {
+        let reply_policy = post.parent_id.is_none().then(|| post.reply_policy());
+        let audience = post.audience();
         Self {
             id: post.id,
             author_uid: post.author_uid,
+            author: None,
             content: post.content,
+            entities: PostEntitiesResponse::default(),
+            media: Vec::new(),
+            poll: None,
             likes_count: post.likes_count,
             replies_count: post.replies_count,
             reposts_count: post.reposts_count,
             parent_id: post.parent_id,
+            root_id: post.root_id,
+            reply_policy,
+            audience,
+            labels: Vec::new(),
+            collapsed: false,
+            hidden: post.is_hidden,
+            pinned: false,
             created_at: post.created_at.to_rfc3339(),
+            viewer: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PostLabelResponse {
+    /// `author` ou `moderator`
+    pub source: LabelSource,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_warning: Option<String>,
+    pub sensitive: bool,
+}
+
+impl From<PostLabel> for PostLabelResponse {
+    fn from(label: PostLabel) -> Self {
+        Self {
+            source: label.source(),
+            content_warning: label.content_warning,
+            sensitive: label.is_sensitive,
         }
     }
 }
@@ -64,11 +230,8 @@ pub struct PostsListResponse {
 }
 
 impl PostsListResponse {
-    pub fn from_posts(posts: Vec<Post>) -> Self {
+    pub fn new(posts: Vec<PostResponse>) -> Self {
         let count = posts.len();
-        Self {
-            posts: posts.into_iter().map(PostResponse::from).collect(),
-            count,
-        }
+        Self { posts, count }
     }
 }
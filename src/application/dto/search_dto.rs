@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use super::PostResponse;
+use crate::domain::post::SearchSort;
+
+#[derive(Debug, Deserialize)]
+pub struct SearchPostsQuery {
+    pub q: String,
+    /// `relevance` (par défaut) ou `recent`
+    #[serde(default)]
+    pub sort: SearchSort,
+    /// Curseur `next_cursor` de la page précédente
+    pub cursor: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+fn default_limit() -> i64 {
+    20
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchUsersQuery {
+    pub q: String,
+    #[serde(default = "default_users_limit")]
+    pub limit: i64,
+}
+
+fn default_users_limit() -> i64 {
+    10
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchPostsResponse {
+    pub posts: Vec<PostResponse>,
+    pub count: usize,
+    /// Absent quand il n'y a plus de résultats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+impl SearchPostsResponse {
+    pub fn new(posts: Vec<PostResponse>, next_cursor: Option<String>) -> Self {
+        let count = posts.len();
+        Self {
+            posts,
+            count,
+            next_cursor,
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 use validator::Validate;
 
-use crate::domain::user::User;
+use crate::domain::post::ContentPolicy;
+use crate::domain::suggestion::FollowSuggestion;
+use crate::domain::user::{AccountTier, DmPolicy, SensitiveContent, User, UserSettings};
 
 fn validate_username(username: &str) -> Result<(), validator::ValidationError> {
     let is_valid = username
@@ -38,11 +41,18 @@ pub struct UserResponse {
     pub bio: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_post_id: Option<Uuid>,
     pub followers_count: i64,
     pub following_count: i64,
     pub posts_count: i64,
     pub is_verified: bool,
     pub is_private: bool,
+    /// Compte de l'équipe de modération
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub is_staff: bool,
     pub created_at: String,
 }
 
@@ -55,16 +65,133 @@ impl From<User> for UserResponse {
             display_name: user.display_name,
             bio: user.bio,
             avatar_url: user.avatar_url,
+            banner_url: user.banner_url,
+            pinned_post_id: user.pinned_post_id,
             followers_count: user.followers_count,
             following_count: user.following_count,
             posts_count: user.posts_count,
             is_verified: user.is_verified,
             is_private: user.is_private,
+            is_staff: user.is_staff,
             created_at: user.created_at.to_rfc3339(),
         }
     }
 }
 
+/// Profil public : comme `UserResponse`, sans l'email
+#[derive(Debug, Serialize)]
+pub struct PublicUserResponse {
+    pub uid: String,
+    pub username: String,
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_post_id: Option<Uuid>,
+    pub followers_count: i64,
+    pub following_count: i64,
+    pub posts_count: i64,
+    pub is_verified: bool,
+    pub is_private: bool,
+    pub created_at: String,
+}
+
+impl From<User> for PublicUserResponse {
+    fn from(user: User) -> Self {
+        Self {
+            uid: user.uid,
+            username: user.username,
+            display_name: user.display_name,
+            bio: user.bio,
+            avatar_url: user.avatar_url,
+            banner_url: user.banner_url,
+            pinned_post_id: user.pinned_post_id,
+            followers_count: user.followers_count,
+            following_count: user.following_count,
+            posts_count: user.posts_count,
+            is_verified: user.is_verified,
+            is_private: user.is_private,
+            created_at: user.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Profil compact, utilisé pour les auteurs de posts et les listes d'utilisateurs
+#[derive(Debug, Clone, Serialize)]
+pub struct UserSummaryResponse {
+    pub uid: String,
+    pub username: String,
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    pub is_verified: bool,
+}
+
+impl From<User> for UserSummaryResponse {
+    fn from(user: User) -> Self {
+        Self {
+            uid: user.uid,
+            username: user.username,
+            display_name: user.display_name,
+            avatar_url: user.avatar_url,
+            is_verified: user.is_verified,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UsersListResponse {
+    pub users: Vec<UserSummaryResponse>,
+    pub count: usize,
+}
+
+impl UsersListResponse {
+    pub fn from_users(users: Vec<User>) -> Self {
+        let count = users.len();
+        Self {
+            users: users.into_iter().map(UserSummaryResponse::from).collect(),
+            count,
+        }
+    }
+}
+
+/// Compte suggéré, avec le signal principal qui l'a fait remonter
+#[derive(Debug, Serialize)]
+pub struct SuggestionResponse {
+    pub user: UserSummaryResponse,
+    /// `followed_by_following`, `popular_in_network` ou `verified`
+    pub reason: String,
+    /// Nombre de vos abonnements qui suivent ce compte
+    pub mutual_count: i64,
+}
+
+impl SuggestionResponse {
+    pub fn new(suggestion: FollowSuggestion, user: User) -> Self {
+        Self {
+            user: UserSummaryResponse::from(user),
+            reason: suggestion.reason,
+            mutual_count: suggestion.mutual_count,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SuggestionsListResponse {
+    pub suggestions: Vec<SuggestionResponse>,
+    pub count: usize,
+}
+
+impl SuggestionsListResponse {
+    pub fn new(suggestions: Vec<SuggestionResponse>) -> Self {
+        let count = suggestions.len();
+        Self { suggestions, count }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct ApiResponse<T> {
     pub success: bool,
@@ -90,3 +217,43 @@ impl<T> ApiResponse<T> {
         }
     }
 }
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSettingsRequest {
+    /// Qui peut vous envoyer un message privé : `everyone`, `followers` ou `nobody`
+    pub dm_policy: Option<DmPolicy>,
+    /// Affichage des posts avec avertissement : `warn`, `show` ou `hide`
+    pub sensitive_content: Option<SensitiveContent>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SettingsResponse {
+    pub dm_policy: String,
+    pub sensitive_content: String,
+    pub updated_at: String,
+}
+
+impl From<UserSettings> for SettingsResponse {
+    fn from(settings: UserSettings) -> Self {
+        Self {
+            dm_policy: settings.dm_policy,
+            sensitive_content: settings.sensitive_content,
+            updated_at: settings.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Limites de publication du compte connecté
+#[derive(Debug, Serialize)]
+pub struct ContentPolicyResponse {
+    /// `standard`, `verified` ou `staff`
+    pub tier: AccountTier,
+    #[serde(flatten)]
+    pub policy: ContentPolicy,
+}
+
+impl From<(AccountTier, ContentPolicy)> for ContentPolicyResponse {
+    fn from((tier, policy): (AccountTier, ContentPolicy)) -> Self {
+        Self { tier, policy }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod dto;
+pub mod realtime;
 pub mod services;
@@ -0,0 +1,241 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{broadcast, OnceCell};
+
+use crate::application::dto::PostResponse;
+use crate::domain::event::{DomainEvent, EventBus, PublishedEvent};
+
+/// Nombre d'événements conservés pour la reprise via `Last-Event-ID`
+const HISTORY_CAPACITY: usize = 1024;
+
+/// Délai avant une nouvelle tentative d'abonnement au bus
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+
+/// Événement numéroté. L'identifiant, attribué par le bus, est le même sur
+/// toutes les instances : un client peut reprendre sur n'importe laquelle.
+#[derive(Debug, Clone)]
+pub struct EventEnvelope {
+    pub id: String,
+    pub sequence: u64,
+    pub event: DomainEvent,
+    /// Réponse du post publié, construite par la première connexion WebSocket
+    /// qui en a besoin puis partagée par toutes les autres
+    pub post_response: OnceCell<Option<PostResponse>>,
+}
+
+/// Résultat d'un abonnement : les événements manqués puis le flux en direct
+pub struct Subscription {
+    /// Événements postérieurs au `Last-Event-ID` fourni
+    pub missed: Vec<Arc<EventEnvelope>>,
+    /// Vrai si des événements manqués ne sont plus disponibles : le client
+    /// doit recharger son état
+    pub gap: bool,
+    pub receiver: broadcast::Receiver<Arc<EventEnvelope>>,
+}
+
+/// Diffusion locale des événements reçus du bus, avec un historique borné
+pub struct EventHub {
+    bus: Arc<dyn EventBus>,
+    sender: broadcast::Sender<Arc<EventEnvelope>>,
+    history: Mutex<VecDeque<Arc<EventEnvelope>>>,
+}
+
+impl EventHub {
+    pub fn new(bus: Arc<dyn EventBus>) -> Self {
+        let (sender, _) = broadcast::channel(HISTORY_CAPACITY);
+        Self {
+            bus,
+            sender,
+            history: Mutex::new(VecDeque::with_capacity(HISTORY_CAPACITY)),
+        }
+    }
+
+    /// Publie l'événement sur le bus. Les abonnés locaux le reçoivent une fois
+    /// qu'il revient du bus, comme ceux des autres instances.
+    pub async fn publish(&self, event: DomainEvent) {
+        if let Err(e) = self.bus.publish(&event).await {
+            tracing::warn!("Événement {} non publié: {}", event.name(), e);
+        }
+    }
+
+    /// Relaie en continu les événements du bus aux abonnés de cette instance
+    pub async fn run(&self) {
+        loop {
+            match self.bus.subscribe().await {
+                Ok(mut events) => {
+                    while let Some(event) = events.recv().await {
+                        self.dispatch(event);
+                    }
+                    tracing::warn!("Flux du bus d'événements interrompu, reconnexion...");
+                }
+                Err(e) => tracing::warn!("Abonnement au bus d'événements impossible: {}", e),
+            }
+            tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+        }
+    }
+
+    fn dispatch(&self, published: PublishedEvent) {
+        let mut history = self.history.lock().expect("historique des événements corrompu");
+
+        let envelope = Arc::new(EventEnvelope {
+            id: published.id.to_string(),
+            sequence: published.id,
+            event: published.event,
+            post_response: OnceCell::new(),
+        });
+
+        if history.len() == HISTORY_CAPACITY {
+            history.pop_front();
+        }
+        history.push_back(envelope.clone());
+
+        // Aucun abonné n'est pas une erreur
+        let _ = self.sender.send(envelope);
+    }
+
+    /// S'abonne au flux en reprenant après `last_event_id` s'il est fourni
+    pub fn subscribe(&self, last_event_id: Option<&str>) -> Subscription {
+        // Le verrou garantit qu'aucun événement n'est publié entre la copie
+        // de l'historique et l'abonnement
+        let history = self.history.lock().expect("historique des événements corrompu");
+        let receiver = self.sender.subscribe();
+
+        let Some(last_event_id) = last_event_id else {
+            return Subscription {
+                missed: Vec::new(),
+                gap: false,
+                receiver,
+            };
+        };
+
+        // Les séquences attribuées en parallèle peuvent arriver dans le
+        // désordre, mais toutes les instances les reçoivent dans le même : la
+        // reprise se fait donc à partir de la position de l'événement, pas de
+        // son numéro
+        let position = last_event_id
+            .parse::<u64>()
+            .ok()
+            .and_then(|sequence| history.iter().position(|e| e.sequence == sequence));
+
+        let Some(position) = position else {
+            return Subscription {
+                missed: Vec::new(),
+                gap: true,
+                receiver,
+            };
+        };
+
+        Subscription {
+            missed: history.iter().skip(position + 1).cloned().collect(),
+            gap: false,
+            receiver,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::events::InProcessEventBus;
+
+    fn unread_count(count: i64) -> DomainEvent {
+        DomainEvent::UnreadCount {
+            recipient_uid: "alice".into(),
+            count,
+        }
+    }
+
+    fn hub_with_events(ids: &[u64]) -> EventHub {
+        let hub = EventHub::new(Arc::new(InProcessEventBus::new()));
+        for &id in ids {
+            hub.dispatch(PublishedEvent {
+                id,
+                event: unread_count(id as i64),
+            });
+        }
+        hub
+    }
+
+    fn ids(events: &[Arc<EventEnvelope>]) -> Vec<&str> {
+        events.iter().map(|e| e.id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn publie_via_le_bus_puis_diffuse_aux_abonnes() {
+        let hub = Arc::new(EventHub::new(Arc::new(InProcessEventBus::new())));
+        tokio::spawn({
+            let hub = hub.clone();
+            async move { hub.run().await }
+        });
+        let mut receiver = hub.subscribe(None).receiver;
+
+        // `run` s'abonne au bus en tâche de fond : republier tant qu'il n'a
+        // pas relayé l'événement
+        let mut received = None;
+        for _ in 0..50 {
+            hub.publish(unread_count(3)).await;
+            if let Ok(Ok(envelope)) =
+                tokio::time::timeout(Duration::from_millis(20), receiver.recv()).await
+            {
+                received = Some(envelope);
+                break;
+            }
+        }
+
+        let envelope = received.expect("événement non relayé par le bus");
+        assert!(matches!(envelope.event, DomainEvent::UnreadCount { count: 3, .. }));
+        assert_eq!(envelope.id, envelope.sequence.to_string());
+    }
+
+    #[test]
+    fn rejoue_les_evenements_apres_last_event_id() {
+        let hub = hub_with_events(&[1, 2, 3]);
+
+        let subscription = hub.subscribe(Some("1"));
+        assert!(!subscription.gap);
+        assert_eq!(ids(&subscription.missed), ["2", "3"]);
+
+        let subscription = hub.subscribe(Some("3"));
+        assert!(!subscription.gap);
+        assert!(subscription.missed.is_empty());
+
+        let subscription = hub.subscribe(None);
+        assert!(!subscription.gap);
+        assert!(subscription.missed.is_empty());
+    }
+
+    #[test]
+    fn reprend_selon_l_ordre_de_reception() {
+        // Deux séquences attribuées en parallèle peuvent arriver inversées
+        let hub = hub_with_events(&[5, 4, 6]);
+
+        let subscription = hub.subscribe(Some("5"));
+        assert!(!subscription.gap);
+        assert_eq!(ids(&subscription.missed), ["4", "6"]);
+    }
+
+    #[test]
+    fn signale_un_trou_si_l_identifiant_est_inconnu() {
+        let hub = hub_with_events(&[1, 2]);
+
+        for last_event_id in ["999", "abc", "1700000000000-1"] {
+            let subscription = hub.subscribe(Some(last_event_id));
+            assert!(subscription.gap, "{}", last_event_id);
+            assert!(subscription.missed.is_empty());
+        }
+    }
+
+    #[test]
+    fn signale_un_trou_si_l_historique_est_depasse() {
+        let ids: Vec<u64> = (1..=HISTORY_CAPACITY as u64 + 1).collect();
+        let hub = hub_with_events(&ids);
+
+        assert!(hub.subscribe(Some("1")).gap);
+
+        let subscription = hub.subscribe(Some("2"));
+        assert!(!subscription.gap);
+        assert_eq!(subscription.missed.len(), HISTORY_CAPACITY - 1);
+    }
+}
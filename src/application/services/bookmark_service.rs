@@ -0,0 +1,112 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::block::BlockRepository;
+use crate::domain::bookmark::{Bookmark, BookmarkFolder, BookmarkRepository};
+use crate::domain::post::{Post, PostRepository};
+use crate::error::AppError;
+
+pub struct BookmarkService {
+    bookmark_repository: Arc<dyn BookmarkRepository>,
+    post_repository: Arc<dyn PostRepository>,
+    block_repository: Arc<dyn BlockRepository>,
+}
+
+impl BookmarkService {
+    pub fn new(
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        post_repository: Arc<dyn PostRepository>,
+        block_repository: Arc<dyn BlockRepository>,
+    ) -> Self {
+        Self {
+            bookmark_repository,
+            post_repository,
+            block_repository,
+        }
+    }
+
+    pub async fn bookmark(
+        &self,
+        user_uid: &str,
+        post_id: Uuid,
+        folder_id: Option<Uuid>,
+    ) -> Result<Bookmark, AppError> {
+        let post = self
+            .post_repository
+            .find_by_id(post_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Post non trouvé".into()))?;
+
+        let blocked = post.author_uid != user_uid
+            && self
+                .block_repository
+                .is_blocked_between(&post.author_uid, user_uid)
+                .await?;
+        if blocked || !self.post_repository.is_visible_to(post.id, Some(user_uid)).await? {
+            return Err(AppError::NotFound("Post non trouvé".into()));
+        }
+
+        if let Some(folder_id) = folder_id {
+            self.get_folder(user_uid, folder_id).await?;
+        }
+
+        self.bookmark_repository.save(user_uid, post_id, folder_id).await
+    }
+
+    pub async fn unbookmark(&self, user_uid: &str, post_id: Uuid) -> Result<(), AppError> {
+        self.bookmark_repository.remove(user_uid, post_id).await
+    }
+
+    pub async fn get_bookmarks(
+        &self,
+        user_uid: &str,
+        folder_id: Option<Uuid>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Post>, AppError> {
+        if let Some(folder_id) = folder_id {
+            self.get_folder(user_uid, folder_id).await?;
+        }
+
+        let limit = limit.clamp(1, 50);
+        let offset = offset.max(0);
+        self.post_repository
+            .find_bookmarked(user_uid, folder_id, limit, offset)
+            .await
+    }
+
+    pub async fn create_folder(&self, user_uid: String, name: String) -> Result<BookmarkFolder, AppError> {
+        let name = Self::normalize_name(name)?;
+        let folder = BookmarkFolder::new(user_uid, name);
+        self.bookmark_repository.create_folder(&folder).await
+    }
+
+    pub async fn get_folder(&self, user_uid: &str, id: Uuid) -> Result<BookmarkFolder, AppError> {
+        self.bookmark_repository
+            .find_folder(user_uid, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Dossier non trouvé".into()))
+    }
+
+    pub async fn get_folders(&self, user_uid: &str) -> Result<Vec<BookmarkFolder>, AppError> {
+        self.bookmark_repository.find_folders(user_uid).await
+    }
+
+    pub async fn rename_folder(&self, user_uid: &str, id: Uuid, name: String) -> Result<BookmarkFolder, AppError> {
+        let name = Self::normalize_name(name)?;
+        self.bookmark_repository.rename_folder(user_uid, id, &name).await
+    }
+
+    /// Les bookmarks du dossier sont conservés, hors dossier
+    pub async fn delete_folder(&self, user_uid: &str, id: Uuid) -> Result<(), AppError> {
+        self.bookmark_repository.delete_folder(user_uid, id).await
+    }
+
+    fn normalize_name(name: String) -> Result<String, AppError> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err(AppError::Validation("Le nom du dossier ne peut pas être vide".into()));
+        }
+        Ok(name)
+    }
+}
@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::PostService;
+use crate::domain::block::BlockRepository;
+use crate::domain::conversation::{
+    Conversation, ConversationParticipant, ConversationRepository, Message,
+};
+use crate::domain::follow::FollowRepository;
+use crate::domain::post::ContentPolicies;
+use crate::domain::user::{DmPolicy, User, UserRepository, UserSettingsRepository};
+use crate::error::AppError;
+
+/// Taille maximum d'une conversation de groupe, créateur compris
+const MAX_PARTICIPANTS: usize = 10;
+
+/// Conversation avec ses participants, son dernier message et le nombre de
+/// messages non lus par l'utilisateur qui la consulte
+pub struct ConversationOverview {
+    pub conversation: Conversation,
+    pub participants: Vec<(ConversationParticipant, User)>,
+    pub last_message: Option<Message>,
+    pub unread_count: i64,
+}
+
+pub struct ConversationService {
+    conversation_repository: Arc<dyn ConversationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    settings_repository: Arc<dyn UserSettingsRepository>,
+    follow_repository: Arc<dyn FollowRepository>,
+    block_repository: Arc<dyn BlockRepository>,
+    content_policies: ContentPolicies,
+}
+
+impl ConversationService {
+    pub fn new(
+        conversation_repository: Arc<dyn ConversationRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        settings_repository: Arc<dyn UserSettingsRepository>,
+        follow_repository: Arc<dyn FollowRepository>,
+        block_repository: Arc<dyn BlockRepository>,
+        content_policies: ContentPolicies,
+    ) -> Self {
+        Self {
+            conversation_repository,
+            user_repository,
+            settings_repository,
+            follow_repository,
+            block_repository,
+            content_policies,
+        }
+    }
+
+    /// Valide un message selon la limite du niveau de compte de l'expéditeur
+    async fn validate_message(&self, sender_uid: &str, content: &str) -> Result<String, AppError> {
+        let sender = self
+            .user_repository
+            .find_by_uid(sender_uid)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Utilisateur non trouvé".into()))?;
+        let policy = self.content_policies.for_tier(sender.tier());
+
+        PostService::validate_content(content, policy.max_message_length)
+    }
+
+    /// Vérifie que `sender_uid` peut écrire à `recipient_uid` : pas de blocage
+    /// entre eux, et réglage des messages privés du destinataire respecté
+    async fn ensure_can_message(&self, sender_uid: &str, recipient_uid: &str) -> Result<(), AppError> {
+        if self
+            .block_repository
+            .is_blocked_between(sender_uid, recipient_uid)
+            .await?
+        {
+            return Err(AppError::Forbidden(
+                "Vous ne pouvez pas envoyer de message à cet utilisateur".into(),
+            ));
+        }
+
+        let allowed = match self.settings_repository.find(recipient_uid).await?.dm_policy() {
+            DmPolicy::Everyone => true,
+            DmPolicy::Followers => {
+                self.follow_repository
+                    .is_following(sender_uid, recipient_uid)
+                    .await?
+            }
+            DmPolicy::Nobody => false,
+        };
+
+        if !allowed {
+            return Err(AppError::Forbidden(
+                "Cet utilisateur n'accepte pas vos messages privés".into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Démarre une conversation, avec un premier message optionnel. Une
+    /// conversation à deux est réutilisée si elle existe déjà.
+    pub async fn start_conversation(
+        &self,
+        creator_uid: &str,
+        participant_uids: Vec<String>,
+        content: Option<String>,
+    ) -> Result<ConversationOverview, AppError> {
+        let mut recipients: Vec<String> = participant_uids
+            .into_iter()
+            .filter(|uid| uid != creator_uid)
+            .collect();
+        recipients.sort();
+        recipients.dedup();
+
+        if recipients.is_empty() {
+            return Err(AppError::Validation("Au moins un destinataire est requis".into()));
+        }
+        if recipients.len() + 1 > MAX_PARTICIPANTS {
+            return Err(AppError::Validation(format!(
+                "Une conversation ne peut pas dépasser {} participants",
+                MAX_PARTICIPANTS
+            )));
+        }
+
+        let content = match content {
+            Some(content) => Some(self.validate_message(creator_uid, &content).await?),
+            None => None,
+        };
+
+        let users = self.user_repository.find_by_uids(&recipients).await?;
+        if users.len() != recipients.len() {
+            return Err(AppError::NotFound("Utilisateur non trouvé".into()));
+        }
+
+        for recipient_uid in &recipients {
+            self.ensure_can_message(creator_uid, recipient_uid).await?;
+        }
+
+        let conversation = if let [recipient_uid] = recipients.as_slice() {
+            self.find_or_create_direct(creator_uid, recipient_uid).await?
+        } else {
+            let mut participants = recipients.clone();
+            participants.push(creator_uid.to_string());
+            self.conversation_repository
+                .create(&Conversation::group(creator_uid.to_string()), &participants)
+                .await?
+        };
+
+        if let Some(content) = content {
+            let message = Message::new(conversation.id, creator_uid.to_string(), content);
+            self.conversation_repository.create_message(&message).await?;
+        }
+
+        self.get_conversation(creator_uid, conversation.id).await
+    }
+
+    async fn find_or_create_direct(&self, creator_uid: &str, recipient_uid: &str) -> Result<Conversation, AppError> {
+        let direct_key = Conversation::direct_key(creator_uid, recipient_uid);
+
+        if let Some(conversation) = self.conversation_repository.find_direct(&direct_key).await? {
+            // Un participant qui avait quitté la conversation la retrouve
+            self.conversation_repository
+                .add_participant(conversation.id, creator_uid)
+                .await?;
+            self.conversation_repository
+                .add_participant(conversation.id, recipient_uid)
+                .await?;
+            return Ok(conversation);
+        }
+
+        let participants = [creator_uid.to_string(), recipient_uid.to_string()];
+        match self
+            .conversation_repository
+            .create(
+                &Conversation::direct(creator_uid.to_string(), recipient_uid),
+                &participants,
+            )
+            .await
+        {
+            Ok(conversation) => Ok(conversation),
+            // Créée en parallèle par l'autre participant
+            Err(AppError::Conflict(_)) => self
+                .conversation_repository
+                .find_direct(&direct_key)
+                .await?
+                .ok_or_else(|| AppError::Internal("Conversation introuvable".into())),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn get_conversations(
+        &self,
+        user_uid: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<ConversationOverview>, AppError> {
+        let limit = limit.clamp(1, 50);
+        let offset = offset.max(0);
+        let conversations = self
+            .conversation_repository
+            .find_for_user(user_uid, limit, offset)
+            .await?;
+
+        self.build_overviews(user_uid, conversations).await
+    }
+
+    pub async fn get_conversation(&self, user_uid: &str, id: Uuid) -> Result<ConversationOverview, AppError> {
+        let conversation = self.find_joined(user_uid, id).await?;
+
+        self.build_overviews(user_uid, vec![conversation])
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::Internal("Conversation manquante".into()))
+    }
+
+    /// Conversation à laquelle l'utilisateur participe, introuvable sinon
+    async fn find_joined(&self, user_uid: &str, id: Uuid) -> Result<Conversation, AppError> {
+        let conversation = self
+            .conversation_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Conversation non trouvée".into()))?;
+
+        if !self.conversation_repository.is_participant(id, user_uid).await? {
+            return Err(AppError::NotFound("Conversation non trouvée".into()));
+        }
+
+        Ok(conversation)
+    }
+
+    /// Quitter une conversation la retire de la liste de l'utilisateur
+    pub async fn leave_conversation(&self, user_uid: &str, id: Uuid) -> Result<(), AppError> {
+        if !self.conversation_repository.remove_participant(id, user_uid).await? {
+            return Err(AppError::NotFound("Conversation non trouvée".into()));
+        }
+        Ok(())
+    }
+
+    pub async fn get_messages(
+        &self,
+        user_uid: &str,
+        conversation_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Message>, AppError> {
+        self.find_joined(user_uid, conversation_id).await?;
+
+        let limit = limit.clamp(1, 50);
+        let offset = offset.max(0);
+        self.conversation_repository
+            .find_messages(conversation_id, limit, offset)
+            .await
+    }
+
+    pub async fn send_message(
+        &self,
+        sender_uid: &str,
+        conversation_id: Uuid,
+        content: String,
+    ) -> Result<Message, AppError> {
+        let conversation = self.find_joined(sender_uid, conversation_id).await?;
+        let content = self.validate_message(sender_uid, &content).await?;
+
+        // Dans une conversation à deux, les blocages et le réglage du
+        // destinataire s'appliquent à chaque message
+        if let Some(direct_key) = &conversation.direct_key {
+            let recipient_uid = direct_key
+                .split(':')
+                .find(|uid| *uid != sender_uid)
+                .unwrap_or_default();
+            self.ensure_can_message(sender_uid, recipient_uid).await?;
+            self.conversation_repository
+                .add_participant(conversation.id, recipient_uid)
+                .await?;
+        } else {
+            // Dans un groupe, un blocage posé après sa création empêche d'écrire
+            // à un participant bloqué (ou qui a bloqué l'expéditeur)
+            for participant in self
+                .conversation_repository
+                .find_participants(&[conversation.id])
+                .await?
+            {
+                if participant.user_uid != sender_uid
+                    && self
+                        .block_repository
+                        .is_blocked_between(sender_uid, &participant.user_uid)
+                        .await?
+                {
+                    return Err(AppError::Forbidden(
+                        "Vous ne pouvez pas écrire dans cette conversation".into(),
+                    ));
+                }
+            }
+        }
+
+        let message = Message::new(conversation.id, sender_uid.to_string(), content);
+        self.conversation_repository.create_message(&message).await
+    }
+
+    pub async fn delete_message(
+        &self,
+        user_uid: &str,
+        conversation_id: Uuid,
+        message_id: Uuid,
+    ) -> Result<(), AppError> {
+        self.find_joined(user_uid, conversation_id).await?;
+
+        let message = self
+            .conversation_repository
+            .find_message(message_id)
+            .await?
+            .filter(|message| message.conversation_id == conversation_id)
+            .ok_or_else(|| AppError::NotFound("Message non trouvé".into()))?;
+
+        if message.sender_uid != user_uid {
+            return Err(AppError::Forbidden("Vous ne pouvez pas supprimer ce message".into()));
+        }
+
+        self.conversation_repository.delete_message(message_id).await
+    }
+
+    /// Accusé de lecture : tous les messages actuels sont marqués comme lus
+    pub async fn mark_read(&self, user_uid: &str, conversation_id: Uuid) -> Result<(), AppError> {
+        if !self
+            .conversation_repository
+            .mark_read(conversation_id, user_uid)
+            .await?
+        {
+            return Err(AppError::NotFound("Conversation non trouvée".into()));
+        }
+        Ok(())
+    }
+
+    async fn build_overviews(
+        &self,
+        user_uid: &str,
+        conversations: Vec<Conversation>,
+    ) -> Result<Vec<ConversationOverview>, AppError> {
+        let ids: Vec<Uuid> = conversations.iter().map(|c| c.id).collect();
+
+        let participants = self.conversation_repository.find_participants(&ids).await?;
+        let mut uids: Vec<String> = participants.iter().map(|p| p.user_uid.clone()).collect();
+        uids.sort();
+        uids.dedup();
+        let users: HashMap<String, User> = self
+            .user_repository
+            .find_by_uids(&uids)
+            .await?
+            .into_iter()
+            .map(|user| (user.uid.clone(), user))
+            .collect();
+
+        let mut participants_by_conversation: HashMap<Uuid, Vec<(ConversationParticipant, User)>> =
+            HashMap::new();
+        for participant in participants {
+            if let Some(user) = users.get(&participant.user_uid) {
+                participants_by_conversation
+                    .entry(participant.conversation_id)
+                    .or_default()
+                    .push((participant, user.clone()));
+            }
+        }
+
+        let mut last_messages: HashMap<Uuid, Message> = self
+            .conversation_repository
+            .find_last_messages(&ids)
+            .await?
+            .into_iter()
+            .map(|message| (message.conversation_id, message))
+            .collect();
+
+        let unread: HashMap<Uuid, i64> = self
+            .conversation_repository
+            .count_unread(user_uid, &ids)
+            .await?
+            .into_iter()
+            .map(|u| (u.conversation_id, u.unread_count))
+            .collect();
+
+        Ok(conversations
+            .into_iter()
+            .map(|conversation| ConversationOverview {
+                participants: participants_by_conversation
+                    .remove(&conversation.id)
+                    .unwrap_or_default(),
+                last_message: last_messages.remove(&conversation.id),
+                unread_count: unread.get(&conversation.id).copied().unwrap_or(0),
+                conversation,
+            })
+            .collect())
+    }
+}
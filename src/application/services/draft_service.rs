@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::{MediaService, PollService, PostService};
+use crate::application::dto::SaveDraftRequest;
+use crate::domain::draft::{Draft, DraftRepository};
+use crate::domain::media::{Media, MediaAttachment};
+use crate::error::AppError;
+
+/// Durée pendant laquelle une publication planifiée est réservée par le planificateur
+const PUBLISH_LEASE: Duration = Duration::from_secs(300);
+/// Nombre de publications traitées à chaque passage du planificateur
+const PUBLISH_BATCH: i64 = 50;
+
+pub struct DraftService {
+    draft_repository: Arc<dyn DraftRepository>,
+    post_service: Arc<PostService>,
+    media_service: Arc<MediaService>,
+    poll_service: Arc<PollService>,
+}
+
+impl DraftService {
+    pub fn new(
+        draft_repository: Arc<dyn DraftRepository>,
+        post_service: Arc<PostService>,
+        media_service: Arc<MediaService>,
+        poll_service: Arc<PollService>,
+    ) -> Self {
+        Self {
+            draft_repository,
+            post_service,
+            media_service,
+            poll_service,
+        }
+    }
+
+    /// Crée un brouillon, planifié si `publish_at` est renseigné. Le contenu est
+    /// validé comme celui d'un post, pour signaler les erreurs dès l'enregistrement.
+    pub async fn create(
+        &self,
+        author_uid: &str,
+        request: SaveDraftRequest,
+    ) -> Result<(Draft, Vec<Media>), AppError> {
+        let mut draft = Draft::new(author_uid.to_string());
+        let attachments = self.prepare(&mut draft, request).await?;
+
+        let created = self.draft_repository.create(&draft, &attachments).await?;
+        self.with_media(created).await
+    }
+
+    /// Remplace le contenu, les médias, le sondage, le réglage des réponses
+    /// et la date de publication
+    pub async fn update(
+        &self,
+        id: Uuid,
+        author_uid: &str,
+        request: SaveDraftRequest,
+    ) -> Result<(Draft, Vec<Media>), AppError> {
+        let mut draft = self.get_editable(id, author_uid).await?;
+        let attachments = self.prepare(&mut draft, request).await?;
+
+        let updated = self.save(&draft, Some(&attachments)).await?;
+
+        self.with_media(updated).await
+    }
+
+    /// Planifie, déplace ou annule la publication (`None` : retour en brouillon)
+    pub async fn reschedule(
+        &self,
+        id: Uuid,
+        author_uid: &str,
+        publish_at: Option<DateTime<Utc>>,
+    ) -> Result<(Draft, Vec<Media>), AppError> {
+        let mut draft = self.get_editable(id, author_uid).await?;
+        draft.publish_at = Self::validate_publish_at(publish_at)?;
+        draft.last_error = None;
+
+        let updated = self.save(&draft, None).await?;
+        self.with_media(updated).await
+    }
+
+    /// Supprime le brouillon. Ses médias redeviennent orphelins et seront purgés.
+    pub async fn cancel(&self, id: Uuid, author_uid: &str) -> Result<(), AppError> {
+        let draft = self.get_editable(id, author_uid).await?;
+        self.draft_repository.delete(draft.id).await
+    }
+
+    pub async fn get(&self, id: Uuid, author_uid: &str) -> Result<(Draft, Vec<Media>), AppError> {
+        let draft = self.get_owned(id, author_uid).await?;
+        self.with_media(draft).await
+    }
+
+    pub async fn list(
+        &self,
+        author_uid: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<(Draft, Vec<Media>)>, AppError> {
+        let drafts = self
+            .draft_repository
+            .find_by_author(author_uid, limit.clamp(1, 50), offset.max(0))
+            .await?;
+
+        let ids: Vec<Uuid> = drafts.iter().map(|draft| draft.id).collect();
+        let mut media: HashMap<Uuid, Vec<Media>> = HashMap::new();
+        for item in self.media_service.get_for_drafts(&ids).await? {
+            if let Some(draft_id) = item.draft_id {
+                media.entry(draft_id).or_default().push(item);
+            }
+        }
+
+        Ok(drafts
+            .into_iter()
+            .map(|draft| {
+                let draft_media = media.remove(&draft.id).unwrap_or_default();
+                (draft, draft_media)
+            })
+            .collect())
+    }
+
+    /// Publie les posts planifiés arrivés à échéance. Appelé périodiquement.
+    /// Le brouillon est supprimé dans la transaction qui crée le post. Une
+    /// publication refusée (contenu, média ou sondage devenu invalide) repasse
+    /// en brouillon avec la raison de l'échec ; une erreur interne ou la limite
+    /// de posts par jour est retentée à l'expiration de la réservation.
+    pub async fn publish_due(&self) -> Result<(), AppError> {
+        let drafts = self
+            .draft_repository
+            .claim_due(PUBLISH_LEASE, PUBLISH_BATCH)
+            .await?;
+
+        for draft in drafts {
+            let attachments = match self.media_service.get_for_drafts(&[draft.id]).await {
+                Ok(media) => media
+                    .into_iter()
+                    .map(|media| MediaAttachment {
+                        id: media.id,
+                        alt_text: media.alt_text,
+                    })
+                    .collect(),
+                Err(e) => {
+                    tracing::warn!("Publication planifiée {} reportée: {}", draft.id, e);
+                    continue;
+                }
+            };
+
+            match self.post_service.publish_draft(&draft, attachments).await {
+                Ok(_) => {}
+                // Erreur interne ou limite quotidienne atteinte : rien n'a été publié, retentée plus tard
+                Err(AppError::Internal(e)) | Err(AppError::TooManyRequests(e)) => {
+                    tracing::warn!("Publication planifiée {} reportée: {}", draft.id, e);
+                }
+                Err(e) => {
+                    tracing::warn!("Publication planifiée {} refusée: {}", draft.id, e);
+                    if let Err(e) = self.draft_repository.mark_failed(draft.id, &e.to_string()).await {
+                        tracing::warn!("Échec de la publication {} non enregistré: {}", draft.id, e);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Valide le contenu, les médias, le sondage et la date, et les applique au brouillon.
+    /// Retourne les médias normalisés, à réserver pour le brouillon.
+    async fn prepare(
+        &self,
+        draft: &mut Draft,
+        request: SaveDraftRequest,
+    ) -> Result<Vec<MediaAttachment>, AppError> {
+        let (_, policy) = self.post_service.content_policy(&draft.author_uid).await?;
+        draft.content = PostService::validate_content(&request.content, policy.max_post_length)?;
+        let attachments = self
+            .media_service
+            .validate_attachments(&draft.author_uid, request.media, policy.max_attachments, Some(draft.id))
+            .await?;
+        draft.set_poll(
+            request
+                .poll
+                .map(|poll| self.poll_service.validate(poll))
+                .transpose()?,
+        );
+        draft.reply_policy = request.reply_policy.as_str().to_string();
+        draft.audience = request.audience.as_str().to_string();
+        draft.set_label(PostService::validate_label(request.label)?);
+        draft.publish_at = Self::validate_publish_at(request.publish_at)?;
+        draft.last_error = None;
+
+        Ok(attachments)
+    }
+
+    fn validate_publish_at(publish_at: Option<DateTime<Utc>>) -> Result<Option<DateTime<Utc>>, AppError> {
+        if publish_at.is_some_and(|at| at <= Utc::now()) {
+            return Err(AppError::Validation(
+                "La date de publication doit être dans le futur".into(),
+            ));
+        }
+        Ok(publish_at)
+    }
+
+    /// Les brouillons des autres utilisateurs sont traités comme inexistants
+    async fn get_owned(&self, id: Uuid, author_uid: &str) -> Result<Draft, AppError> {
+        self.draft_repository
+            .find_by_id(id)
+            .await?
+            .filter(|draft| draft.author_uid == author_uid)
+            .ok_or_else(|| AppError::NotFound("Brouillon non trouvé".into()))
+    }
+
+    async fn get_editable(&self, id: Uuid, author_uid: &str) -> Result<Draft, AppError> {
+        let draft = self.get_owned(id, author_uid).await?;
+        if draft.is_locked() {
+            return Err(AppError::Conflict("Ce post est en cours de publication".into()));
+        }
+        Ok(draft)
+    }
+
+    async fn save(
+        &self,
+        draft: &Draft,
+        attachments: Option<&[MediaAttachment]>,
+    ) -> Result<Draft, AppError> {
+        self.draft_repository
+            .update(draft, attachments)
+            .await?
+            .ok_or_else(|| AppError::Conflict("Ce post est en cours de publication".into()))
+    }
+
+    async fn with_media(&self, draft: Draft) -> Result<(Draft, Vec<Media>), AppError> {
+        let media = self.media_service.get_for_drafts(&[draft.id]).await?;
+        Ok((draft, media))
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::domain::hashtag::{HashtagRepository, TrendingHashtag};
+use crate::error::AppError;
+
+/// Nombre de fenêtres précédentes servant de référence pour la vélocité
+const BASELINE_WINDOWS: i64 = 24;
+/// Nombre de tendances conservées à chaque rafraîchissement
+const MAX_TRENDING: i64 = 50;
+
+pub struct HashtagService {
+    hashtag_repository: Arc<dyn HashtagRepository>,
+    window: Duration,
+}
+
+impl HashtagService {
+    pub fn new(hashtag_repository: Arc<dyn HashtagRepository>, window: Duration) -> Self {
+        Self {
+            hashtag_repository,
+            window,
+        }
+    }
+
+    /// Recalcule les tendances. Appelé périodiquement, jamais à la requête.
+    pub async fn refresh_trending(&self) -> Result<(), AppError> {
+        self.hashtag_repository
+            .refresh_trending(self.window, BASELINE_WINDOWS, MAX_TRENDING)
+            .await?;
+
+        tracing::debug!("Tendances recalculées");
+
+        Ok(())
+    }
+
+    pub async fn get_trending(&self, limit: i64) -> Result<Vec<TrendingHashtag>, AppError> {
+        let limit = limit.clamp(1, MAX_TRENDING);
+        self.hashtag_repository.find_trending(limit).await
+    }
+}
@@ -0,0 +1,188 @@
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::media::{
+    process_image, process_profile_image, Media, MediaAttachment, MediaRepository, MediaStorage, MAX_ALT_TEXT_CHARS,
+};
+use crate::domain::user::ProfileImageKind;
+use crate::error::AppError;
+
+/// Délai avant purge d'un média jamais attaché (ou dont le post a été supprimé)
+const ORPHAN_MAX_AGE: Duration = Duration::from_secs(24 * 3600);
+/// Nombre de médias purgés à chaque passage de la tâche périodique
+const CLEANUP_BATCH: i64 = 100;
+
+pub struct MediaService {
+    media_repository: Arc<dyn MediaRepository>,
+    storage: Arc<dyn MediaStorage>,
+    max_upload_bytes: usize,
+}
+
+impl MediaService {
+    pub fn new(
+        media_repository: Arc<dyn MediaRepository>,
+        storage: Arc<dyn MediaStorage>,
+        max_upload_bytes: usize,
+    ) -> Self {
+        Self {
+            media_repository,
+            storage,
+            max_upload_bytes,
+        }
+    }
+
+    pub fn max_upload_bytes(&self) -> usize {
+        self.max_upload_bytes
+    }
+
+    /// Valide et nettoie l'image, puis la stocke. Le média reste orphelin
+    /// jusqu'à ce qu'il soit attaché à un post.
+    pub async fn upload(&self, owner_uid: &str, bytes: Vec<u8>) -> Result<Media, AppError> {
+        let max_bytes = self.max_upload_bytes;
+        // Décodage et ré-encodage sont coûteux : hors des threads de l'exécuteur
+        let image = tokio::task::spawn_blocking(move || process_image(&bytes, max_bytes))
+            .await
+            .map_err(|e| AppError::Internal(format!("Traitement de l'image interrompu: {}", e)))?
+            .map_err(AppError::Validation)?;
+
+        let key = format!("posts/{}/{}.{}", owner_uid, Uuid::new_v4().simple(), image.extension);
+        let media = Media::new(
+            owner_uid.to_string(),
+            key.clone(),
+            self.storage.public_url(&key),
+            &image,
+        );
+
+        self.storage.put(&key, image.data, image.mime_type).await?;
+
+        match self.media_repository.create(&media).await {
+            Ok(created) => Ok(created),
+            Err(e) => {
+                if let Err(delete_error) = self.storage.delete(&key).await {
+                    tracing::warn!("Fichier {} non supprimé: {}", key, delete_error);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Recadre et stocke un avatar ou une bannière. Retourne l'URL publique
+    /// et la clé de stockage, à conserver pour supprimer le fichier plus tard.
+    pub async fn upload_profile_image(
+        &self,
+        owner_uid: &str,
+        kind: ProfileImageKind,
+        bytes: Vec<u8>,
+    ) -> Result<(String, String), AppError> {
+        let max_bytes = self.max_upload_bytes;
+        let (width, height) = kind.dimensions();
+        let image = tokio::task::spawn_blocking(move || {
+            process_profile_image(&bytes, max_bytes, width, height)
+        })
+        .await
+        .map_err(|e| AppError::Internal(format!("Traitement de l'image interrompu: {}", e)))?
+        .map_err(AppError::Validation)?;
+
+        let key = format!(
+            "{}s/{}/{}.{}",
+            kind.as_str(),
+            owner_uid,
+            Uuid::new_v4().simple(),
+            image.extension
+        );
+        self.storage.put(&key, image.data, image.mime_type).await?;
+
+        Ok((self.storage.public_url(&key), key))
+    }
+
+    /// Supprime un fichier qui n'est plus référencé (erreur seulement journalisée)
+    pub async fn delete_file(&self, key: &str) {
+        if let Err(e) = self.storage.delete(key).await {
+            tracing::warn!("Fichier {} non supprimé: {}", key, e);
+        }
+    }
+
+    /// Vérifie les médias à attacher à un nouveau post : nombre (au plus
+    /// `max_attachments`), doublons, texte alternatif, et appartenance à
+    /// `owner_uid`. Les médias réservés par un brouillon ne sont acceptés que
+    /// pour ce brouillon (`draft_id`). Retourne les pièces jointes normalisées
+    /// (texte alternatif vide retiré).
+    pub async fn validate_attachments(
+        &self,
+        owner_uid: &str,
+        attachments: Vec<MediaAttachment>,
+        max_attachments: usize,
+        draft_id: Option<Uuid>,
+    ) -> Result<Vec<MediaAttachment>, AppError> {
+        if attachments.len() > max_attachments {
+            return Err(AppError::Validation(format!(
+                "Un post ne peut pas avoir plus de {} médias",
+                max_attachments
+            )));
+        }
+
+        let mut ids: Vec<Uuid> = Vec::with_capacity(attachments.len());
+        let mut normalized = Vec::with_capacity(attachments.len());
+        for attachment in attachments {
+            if ids.contains(&attachment.id) {
+                return Err(AppError::Validation("Un média ne peut être attaché qu'une fois".into()));
+            }
+            ids.push(attachment.id);
+
+            let alt_text = attachment
+                .alt_text
+                .map(|alt| alt.trim().to_string())
+                .filter(|alt| !alt.is_empty());
+            if alt_text.as_ref().is_some_and(|alt| alt.chars().count() > MAX_ALT_TEXT_CHARS) {
+                return Err(AppError::Validation(format!(
+                    "Le texte alternatif ne peut pas dépasser {} caractères",
+                    MAX_ALT_TEXT_CHARS
+                )));
+            }
+
+            normalized.push(MediaAttachment {
+                id: attachment.id,
+                alt_text,
+            });
+        }
+
+        let found = self.media_repository.find_unattached(owner_uid, &ids, draft_id).await?;
+        if found.len() != ids.len() {
+            return Err(AppError::Validation("Média introuvable ou déjà utilisé".into()));
+        }
+
+        Ok(normalized)
+    }
+
+    pub async fn get_for_posts(&self, post_ids: &[Uuid]) -> Result<Vec<Media>, AppError> {
+        self.media_repository.find_by_posts(post_ids).await
+    }
+
+    pub async fn get_for_drafts(&self, draft_ids: &[Uuid]) -> Result<Vec<Media>, AppError> {
+        self.media_repository.find_by_drafts(draft_ids).await
+    }
+
+    /// Supprime les médias orphelins et leurs fichiers. Appelé périodiquement.
+    pub async fn cleanup_orphans(&self) -> Result<(), AppError> {
+        let orphans = self
+            .media_repository
+            .find_orphans(ORPHAN_MAX_AGE, CLEANUP_BATCH)
+            .await?;
+
+        for media in &orphans {
+            // Le fichier d'abord : si la ligne n'est pas supprimée, elle sera retentée
+            if let Err(e) = self.storage.delete(&media.storage_key).await {
+                tracing::warn!("Fichier {} non supprimé: {}", media.storage_key, e);
+                continue;
+            }
+            self.media_repository.delete(media.id).await?;
+        }
+
+        if !orphans.is_empty() {
+            tracing::debug!("{} médias orphelins purgés", orphans.len());
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,23 @@
+mod bookmark_service;
+mod conversation_service;
+mod draft_service;
+mod hashtag_service;
+mod media_service;
+mod mute_service;
+mod notification_service;
+mod poll_service;
 mod post_service;
+mod suggestion_service;
 mod user_service;
 
+pub use bookmark_service::BookmarkService;
+pub use conversation_service::{ConversationOverview, ConversationService};
+pub use draft_service::DraftService;
+pub use hashtag_service::HashtagService;
+pub use media_service::MediaService;
+pub use mute_service::MuteService;
+pub use notification_service::NotificationService;
+pub use poll_service::PollService;
 pub use post_service::PostService;
+pub use suggestion_service::SuggestionService;
 pub use user_service::UserService;
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::mute::{MuteRepository, MutedThread, MutedUser, MutedWord};
+use crate::domain::post::PostRepository;
+use crate::domain::user::{User, UserRepository};
+use crate::error::AppError;
+
+/// Nombre maximum de mots masqués actifs par utilisateur
+const MAX_MUTED_WORDS: i64 = 100;
+
+pub struct MuteService {
+    mute_repository: Arc<dyn MuteRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    post_repository: Arc<dyn PostRepository>,
+}
+
+impl MuteService {
+    pub fn new(
+        mute_repository: Arc<dyn MuteRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        post_repository: Arc<dyn PostRepository>,
+    ) -> Self {
+        Self {
+            mute_repository,
+            user_repository,
+            post_repository,
+        }
+    }
+
+    fn validate_expiry(expires_at: Option<DateTime<Utc>>) -> Result<(), AppError> {
+        match expires_at {
+            Some(expires_at) if expires_at <= Utc::now() => Err(AppError::Validation(
+                "La date d'expiration doit être dans le futur".into(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn mute_user(
+        &self,
+        user_uid: &str,
+        muted_uid: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<(MutedUser, User), AppError> {
+        if user_uid == muted_uid {
+            return Err(AppError::Validation("Vous ne pouvez pas vous masquer vous-même".into()));
+        }
+        Self::validate_expiry(expires_at)?;
+
+        let user = self
+            .user_repository
+            .find_by_uid(muted_uid)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Utilisateur non trouvé".into()))?;
+
+        let mute = self
+            .mute_repository
+            .mute_user(user_uid, muted_uid, expires_at)
+            .await?;
+
+        Ok((mute, user))
+    }
+
+    pub async fn unmute_user(&self, user_uid: &str, muted_uid: &str) -> Result<(), AppError> {
+        self.mute_repository.unmute_user(user_uid, muted_uid).await
+    }
+
+    pub async fn get_muted_users(
+        &self,
+        user_uid: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<(MutedUser, User)>, AppError> {
+        let limit = limit.clamp(1, 50);
+        let offset = offset.max(0);
+
+        let mutes = self
+            .mute_repository
+            .find_muted_users(user_uid, limit, offset)
+            .await?;
+
+        let uids: Vec<String> = mutes.iter().map(|m| m.muted_uid.clone()).collect();
+        let mut users: HashMap<String, User> = self
+            .user_repository
+            .find_by_uids(&uids)
+            .await?
+            .into_iter()
+            .map(|user| (user.uid.clone(), user))
+            .collect();
+
+        Ok(mutes
+            .into_iter()
+            .filter_map(|mute| users.remove(&mute.muted_uid).map(|user| (mute, user)))
+            .collect())
+    }
+
+    /// Masque le fil entier auquel appartient `post_id`
+    pub async fn mute_thread(
+        &self,
+        user_uid: &str,
+        post_id: Uuid,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<MutedThread, AppError> {
+        Self::validate_expiry(expires_at)?;
+
+        let post = self
+            .post_repository
+            .find_by_id(post_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Post non trouvé".into()))?;
+        if !self.post_repository.is_visible_to(post.id, Some(user_uid)).await? {
+            return Err(AppError::NotFound("Post non trouvé".into()));
+        }
+
+        self.mute_repository
+            .mute_thread(user_uid, post.thread_id(), expires_at)
+            .await
+    }
+
+    pub async fn unmute_thread(&self, user_uid: &str, post_id: Uuid) -> Result<(), AppError> {
+        // Accepte aussi bien la racine qu'un post du fil
+        let root_post_id = match self.post_repository.find_by_id(post_id).await? {
+            Some(post) => post.thread_id(),
+            None => post_id,
+        };
+
+        self.mute_repository.unmute_thread(user_uid, root_post_id).await
+    }
+
+    pub async fn get_muted_threads(
+        &self,
+        user_uid: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<MutedThread>, AppError> {
+        let limit = limit.clamp(1, 50);
+        let offset = offset.max(0);
+        self.mute_repository
+            .find_muted_threads(user_uid, limit, offset)
+            .await
+    }
+
+    pub async fn add_word(
+        &self,
+        user_uid: String,
+        phrase: String,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<MutedWord, AppError> {
+        let phrase = Self::normalize_phrase(phrase)?;
+        Self::validate_expiry(expires_at)?;
+
+        // Chaque mot masqué est comparé à chaque post lu : la liste est bornée
+        if self.mute_repository.count_words(&user_uid).await? >= MAX_MUTED_WORDS {
+            return Err(AppError::Validation(format!(
+                "Vous ne pouvez pas masquer plus de {} mots",
+                MAX_MUTED_WORDS
+            )));
+        }
+
+        let word = MutedWord::new(user_uid, phrase, expires_at);
+        self.mute_repository.create_word(&word).await
+    }
+
+    pub async fn update_word(
+        &self,
+        user_uid: &str,
+        id: Uuid,
+        phrase: String,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<MutedWord, AppError> {
+        let phrase = Self::normalize_phrase(phrase)?;
+        Self::validate_expiry(expires_at)?;
+
+        let mut word = self
+            .mute_repository
+            .find_word(user_uid, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Mot masqué non trouvé".into()))?;
+
+        word.phrase = phrase;
+        word.expires_at = expires_at;
+
+        self.mute_repository.update_word(&word).await
+    }
+
+    pub async fn delete_word(&self, user_uid: &str, id: Uuid) -> Result<(), AppError> {
+        self.mute_repository.delete_word(user_uid, id).await
+    }
+
+    pub async fn get_words(&self, user_uid: &str) -> Result<Vec<MutedWord>, AppError> {
+        self.mute_repository.find_words(user_uid).await
+    }
+
+    fn normalize_phrase(phrase: String) -> Result<String, AppError> {
+        let phrase = phrase.trim().to_string();
+        if phrase.is_empty() {
+            return Err(AppError::Validation("Le mot masqué ne peut pas être vide".into()));
+        }
+        Ok(phrase)
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::realtime::EventHub;
+use crate::domain::block::BlockRepository;
+use crate::domain::event::DomainEvent;
+use crate::domain::mute::MuteRepository;
+use crate::domain::notification::{Notification, NotificationEvent, NotificationRepository};
+use crate::domain::user::{User, UserRepository};
+use crate::error::AppError;
+
+/// Nombre d'acteurs embarqués par notification groupée
+const ACTORS_PER_NOTIFICATION: i64 = 3;
+
+pub struct NotificationService {
+    notification_repository: Arc<dyn NotificationRepository>,
+    mute_repository: Arc<dyn MuteRepository>,
+    block_repository: Arc<dyn BlockRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    event_hub: Arc<EventHub>,
+}
+
+impl NotificationService {
+    pub fn new(
+        notification_repository: Arc<dyn NotificationRepository>,
+        mute_repository: Arc<dyn MuteRepository>,
+        block_repository: Arc<dyn BlockRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        event_hub: Arc<EventHub>,
+    ) -> Self {
+        Self {
+            notification_repository,
+            mute_repository,
+            block_repository,
+            user_repository,
+            event_hub,
+        }
+    }
+
+    /// Enregistre l'événement sauf s'il vient du destinataire lui-même, d'un
+    /// utilisateur bloqué (dans un sens ou l'autre) ou d'un contenu masqué
+    /// (compte, fil `thread_id` ou mot-clé présent dans `content`).
+    /// Retourne `None` si l'événement a été ignoré.
+    pub async fn notify(
+        &self,
+        event: NotificationEvent,
+        thread_id: Option<Uuid>,
+        content: Option<&str>,
+    ) -> Result<Option<Notification>, AppError> {
+        if event.recipient_uid == event.actor_uid {
+            return Ok(None);
+        }
+
+        if self
+            .block_repository
+            .is_blocked_between(&event.recipient_uid, &event.actor_uid)
+            .await?
+        {
+            return Ok(None);
+        }
+
+        if self
+            .mute_repository
+            .is_muted_for(&event.recipient_uid, &event.actor_uid, thread_id, content)
+            .await?
+        {
+            return Ok(None);
+        }
+
+        let notification = self.notification_repository.record(&event).await?;
+
+        self.event_hub
+            .publish(DomainEvent::Notification {
+                notification: notification.clone(),
+            })
+            .await;
+        self.publish_unread_count(&notification.recipient_uid).await?;
+
+        Ok(Some(notification))
+    }
+
+    /// Notifications du plus récent au plus ancien, chacune avec ses derniers acteurs
+    pub async fn get_notifications(
+        &self,
+        recipient_uid: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<(Notification, Vec<User>)>, AppError> {
+        let limit = limit.clamp(1, 50);
+        let offset = offset.max(0);
+        let notifications = self
+            .notification_repository
+            .find_by_recipient(recipient_uid, limit, offset)
+            .await?;
+        self.with_actors(notifications).await
+    }
+
+    /// Associe à chaque notification ses derniers acteurs
+    pub async fn with_actors(
+        &self,
+        notifications: Vec<Notification>,
+    ) -> Result<Vec<(Notification, Vec<User>)>, AppError> {
+        let ids: Vec<Uuid> = notifications.iter().map(|n| n.id).collect();
+        let actors = self
+            .notification_repository
+            .find_actors(&ids, ACTORS_PER_NOTIFICATION)
+            .await?;
+
+        let mut actor_uids: Vec<String> = actors.iter().map(|a| a.actor_uid.clone()).collect();
+        actor_uids.sort();
+        actor_uids.dedup();
+        let users: HashMap<String, User> = self
+            .user_repository
+            .find_by_uids(&actor_uids)
+            .await?
+            .into_iter()
+            .map(|user| (user.uid.clone(), user))
+            .collect();
+
+        let mut actors_by_notification: HashMap<Uuid, Vec<User>> = HashMap::new();
+        for actor in actors {
+            if let Some(user) = users.get(&actor.actor_uid) {
+                actors_by_notification
+                    .entry(actor.notification_id)
+                    .or_default()
+                    .push(user.clone());
+            }
+        }
+
+        Ok(notifications
+            .into_iter()
+            .map(|notification| {
+                let actors = actors_by_notification
+                    .remove(&notification.id)
+                    .unwrap_or_default();
+                (notification, actors)
+            })
+            .collect())
+    }
+
+    pub async fn unread_count(&self, recipient_uid: &str) -> Result<i64, AppError> {
+        self.notification_repository.count_unread(recipient_uid).await
+    }
+
+    pub async fn mark_read(&self, recipient_uid: &str, id: Uuid) -> Result<(), AppError> {
+        if !self.notification_repository.mark_read(recipient_uid, id).await? {
+            return Err(AppError::NotFound("Notification non trouvée".into()));
+        }
+        self.publish_unread_count(recipient_uid).await
+    }
+
+    pub async fn mark_all_read(&self, recipient_uid: &str) -> Result<(), AppError> {
+        self.notification_repository.mark_all_read(recipient_uid).await?;
+        self.publish_unread_count(recipient_uid).await
+    }
+
+    async fn publish_unread_count(&self, recipient_uid: &str) -> Result<(), AppError> {
+        let count = self.notification_repository.count_unread(recipient_uid).await?;
+        self.event_hub
+            .publish(DomainEvent::UnreadCount {
+                recipient_uid: recipient_uid.to_string(),
+                count,
+            })
+            .await;
+        Ok(())
+    }
+}
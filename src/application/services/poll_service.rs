@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::dto::PollResponse;
+use crate::domain::poll::{
+    NewPoll, PollOption, PollRepository, MAX_POLL_DURATION_MINUTES, MAX_POLL_OPTIONS,
+    MAX_POLL_OPTION_CHARS, MIN_POLL_DURATION_MINUTES, MIN_POLL_OPTIONS,
+};
+use crate::domain::post::Post;
+use crate::error::AppError;
+
+/// Nombre de sondages clôturés à chaque passage de la tâche périodique
+const CLOSE_BATCH: i64 = 100;
+
+pub struct PollService {
+    poll_repository: Arc<dyn PollRepository>,
+}
+
+impl PollService {
+    pub fn new(poll_repository: Arc<dyn PollRepository>) -> Self {
+        Self { poll_repository }
+    }
+
+    /// Vérifie le nombre d'options, leurs libellés et la durée.
+    /// Retourne le sondage avec des libellés nettoyés.
+    pub fn validate(&self, poll: NewPoll) -> Result<NewPoll, AppError> {
+        if !(MIN_POLL_OPTIONS..=MAX_POLL_OPTIONS).contains(&poll.options.len()) {
+            return Err(AppError::Validation(format!(
+                "Un sondage doit avoir entre {} et {} options",
+                MIN_POLL_OPTIONS, MAX_POLL_OPTIONS
+            )));
+        }
+
+        let mut options: Vec<String> = Vec::with_capacity(poll.options.len());
+        for label in poll.options {
+            let label = label.trim().to_string();
+            if label.is_empty() {
+                return Err(AppError::Validation("Une option ne peut pas être vide".into()));
+            }
+            if label.chars().count() > MAX_POLL_OPTION_CHARS {
+                return Err(AppError::Validation(format!(
+                    "Une option ne peut pas dépasser {} caractères",
+                    MAX_POLL_OPTION_CHARS
+                )));
+            }
+            if options.iter().any(|other| other.to_lowercase() == label.to_lowercase()) {
+                return Err(AppError::Validation("Les options doivent être différentes".into()));
+            }
+            options.push(label);
+        }
+
+        if !(MIN_POLL_DURATION_MINUTES..=MAX_POLL_DURATION_MINUTES).contains(&poll.duration_minutes) {
+            return Err(AppError::Validation(format!(
+                "La durée d'un sondage doit être comprise entre {} minutes et {} jours",
+                MIN_POLL_DURATION_MINUTES,
+                MAX_POLL_DURATION_MINUTES / (24 * 60)
+            )));
+        }
+
+        Ok(NewPoll { options, ..poll })
+    }
+
+    pub async fn vote(&self, post_id: Uuid, user_uid: &str, position: i16) -> Result<(), AppError> {
+        let poll = self
+            .poll_repository
+            .find_by_posts(&[post_id])
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::NotFound("Ce post n'a pas de sondage".into()))?;
+        if poll.is_closed() {
+            return Err(AppError::Validation("Ce sondage est terminé".into()));
+        }
+
+        let options = self.poll_repository.find_options(&[post_id]).await?;
+        if !options.iter().any(|option| option.position == position) {
+            return Err(AppError::Validation("Option de sondage invalide".into()));
+        }
+
+        if !self.poll_repository.vote(post_id, user_uid, position).await? {
+            // Le sondage a pu se terminer entre-temps
+            if poll.closes_at <= chrono::Utc::now() {
+                return Err(AppError::Validation("Ce sondage est terminé".into()));
+            }
+            return Err(AppError::Conflict("Vous avez déjà voté à ce sondage".into()));
+        }
+
+        Ok(())
+    }
+
+    /// Sondages des posts avec leurs résultats à jour. Si l'auteur l'a demandé,
+    /// les résultats par option sont cachés aux non-votants jusqu'à la fin.
+    pub async fn get_for_posts(
+        &self,
+        posts: &[Post],
+        viewer_uid: Option<&str>,
+    ) -> Result<HashMap<Uuid, PollResponse>, AppError> {
+        let post_ids: Vec<Uuid> = posts.iter().map(|p| p.id).collect();
+        let polls = self.poll_repository.find_by_posts(&post_ids).await?;
+        if polls.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let poll_ids: Vec<Uuid> = polls.iter().map(|poll| poll.post_id).collect();
+        let mut options: HashMap<Uuid, Vec<PollOption>> = HashMap::new();
+        for option in self.poll_repository.find_options(&poll_ids).await? {
+            options.entry(option.post_id).or_default().push(option);
+        }
+
+        let mut votes = HashMap::new();
+        if let Some(viewer_uid) = viewer_uid {
+            for vote in self.poll_repository.find_votes(viewer_uid, &poll_ids).await? {
+                votes.insert(vote.post_id, vote.position);
+            }
+        }
+
+        let authors: HashMap<Uuid, &str> = posts
+            .iter()
+            .map(|post| (post.id, post.author_uid.as_str()))
+            .collect();
+
+        Ok(polls
+            .into_iter()
+            .map(|poll| {
+                let post_id = poll.post_id;
+                let voted_option = votes.get(&post_id).copied();
+                let is_author = viewer_uid.is_some() && authors.get(&post_id).copied() == viewer_uid;
+                let results_hidden =
+                    poll.hide_results && !poll.is_closed() && voted_option.is_none() && !is_author;
+                let poll_options = options.remove(&post_id).unwrap_or_default();
+                (
+                    post_id,
+                    PollResponse::new(poll, poll_options, voted_option, results_hidden),
+                )
+            })
+            .collect())
+    }
+
+    /// Clôture les sondages arrivés à expiration
+    pub async fn close_expired(&self) -> Result<(), AppError> {
+        let closed = self.poll_repository.close_expired(CLOSE_BATCH).await?;
+        if !closed.is_empty() {
+            tracing::info!("{} sondage(s) clôturé(s)", closed.len());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::poll::{Poll, PollVote};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// Dépôt en mémoire : un vote par (sondage, utilisateur), comme la clé primaire
+    #[derive(Default)]
+    struct MemoryPolls {
+        polls: Mutex<Vec<Poll>>,
+        options: Mutex<Vec<PollOption>>,
+        votes: Mutex<Vec<(Uuid, String, i16)>>,
+    }
+
+    #[async_trait]
+    impl PollRepository for MemoryPolls {
+        async fn find_by_posts(&self, post_ids: &[Uuid]) -> Result<Vec<Poll>, AppError> {
+            let polls = self.polls.lock().unwrap();
+            Ok(polls.iter().filter(|p| post_ids.contains(&p.post_id)).cloned().collect())
+        }
+
+        async fn find_options(&self, post_ids: &[Uuid]) -> Result<Vec<PollOption>, AppError> {
+            let options = self.options.lock().unwrap();
+            Ok(options.iter().filter(|o| post_ids.contains(&o.post_id)).cloned().collect())
+        }
+
+        async fn find_votes(&self, user_uid: &str, post_ids: &[Uuid]) -> Result<Vec<PollVote>, AppError> {
+            let votes = self.votes.lock().unwrap();
+            Ok(votes
+                .iter()
+                .filter(|(post_id, uid, _)| uid == user_uid && post_ids.contains(post_id))
+                .map(|(post_id, _, position)| PollVote {
+                    post_id: *post_id,
+                    position: *position,
+                })
+                .collect())
+        }
+
+        async fn vote(&self, post_id: Uuid, user_uid: &str, position: i16) -> Result<bool, AppError> {
+            let open = self
+                .polls
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|p| p.post_id == post_id && !p.is_closed());
+            let mut votes = self.votes.lock().unwrap();
+            if !open || votes.iter().any(|(id, uid, _)| *id == post_id && uid == user_uid) {
+                return Ok(false);
+            }
+            votes.push((post_id, user_uid.to_string(), position));
+            Ok(true)
+        }
+
+        async fn close_expired(&self, _limit: i64) -> Result<Vec<Uuid>, AppError> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn new_poll(options: &[&str], duration_minutes: i64) -> NewPoll {
+        NewPoll {
+            options: options.iter().map(|o| o.to_string()).collect(),
+            duration_minutes,
+            hide_results: false,
+        }
+    }
+
+    fn service_with_poll(duration_minutes: i64) -> (PollService, Uuid) {
+        let post_id = Uuid::new_v4();
+        let (poll, options) = new_poll(&["oui", "non"], duration_minutes).build(post_id);
+        let repository = MemoryPolls::default();
+        repository.polls.lock().unwrap().push(poll);
+        repository.options.lock().unwrap().extend(options);
+        (PollService::new(Arc::new(repository)), post_id)
+    }
+
+    fn service() -> PollService {
+        PollService::new(Arc::new(MemoryPolls::default()))
+    }
+
+    #[test]
+    fn borne_le_nombre_d_options() {
+        let service = service();
+        let labels = ["a", "b", "c", "d", "e"];
+
+        assert!(service.validate(new_poll(&labels[..MIN_POLL_OPTIONS - 1], 60)).is_err());
+        assert!(service.validate(new_poll(&labels[..MIN_POLL_OPTIONS], 60)).is_ok());
+        assert!(service.validate(new_poll(&labels[..MAX_POLL_OPTIONS], 60)).is_ok());
+        assert!(service.validate(new_poll(&labels[..MAX_POLL_OPTIONS + 1], 60)).is_err());
+    }
+
+    #[test]
+    fn borne_la_longueur_des_options_en_caracteres() {
+        let service = service();
+        let longest = "é".repeat(MAX_POLL_OPTION_CHARS);
+        let too_long = "é".repeat(MAX_POLL_OPTION_CHARS + 1);
+
+        assert!(service.validate(new_poll(&[&longest, "non"], 60)).is_ok());
+        assert!(service.validate(new_poll(&[&too_long, "non"], 60)).is_err());
+        assert!(service.validate(new_poll(&["   ", "non"], 60)).is_err());
+    }
+
+    #[test]
+    fn nettoie_les_options_et_refuse_les_doublons() {
+        let service = service();
+
+        let poll = service.validate(new_poll(&["  oui ", "non"], 60)).unwrap();
+        assert_eq!(poll.options, ["oui", "non"]);
+        assert!(service.validate(new_poll(&["Oui", " oui"], 60)).is_err());
+    }
+
+    #[test]
+    fn borne_la_duree() {
+        let service = service();
+        let poll = |minutes| service.validate(new_poll(&["oui", "non"], minutes));
+
+        assert!(poll(MIN_POLL_DURATION_MINUTES - 1).is_err());
+        assert!(poll(MIN_POLL_DURATION_MINUTES).is_ok());
+        assert!(poll(MAX_POLL_DURATION_MINUTES).is_ok());
+        assert!(poll(MAX_POLL_DURATION_MINUTES + 1).is_err());
+    }
+
+    #[tokio::test]
+    async fn un_seul_vote_par_utilisateur() {
+        let (service, post_id) = service_with_poll(60);
+
+        service.vote(post_id, "alice", 0).await.unwrap();
+        assert!(matches!(
+            service.vote(post_id, "alice", 1).await,
+            Err(AppError::Conflict(_))
+        ));
+        service.vote(post_id, "bob", 1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn refuse_une_option_inconnue() {
+        let (service, post_id) = service_with_poll(60);
+
+        assert!(matches!(
+            service.vote(post_id, "alice", 2).await,
+            Err(AppError::Validation(_))
+        ));
+        assert!(matches!(
+            service.vote(Uuid::new_v4(), "alice", 0).await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn refuse_un_vote_apres_la_fin() {
+        let (service, post_id) = service_with_poll(0);
+
+        assert!(matches!(
+            service.vote(post_id, "alice", 0).await,
+            Err(AppError::Validation(_))
+        ));
+    }
+}
@@ -1,82 +1,824 @@
+use std::collections::HashMap;
 use std::sync::Arc;
+
+use chrono::{Duration, Utc};
 use uuid::Uuid;
 
-use crate::domain::post::{Post, PostRepository};
+use super::{MediaService, NotificationService, PollService};
+use crate::application::dto::{
+    HashtagEntityResponse, MediaResponse, MentionEntityResponse, PostLabelResponse, PostResponse,
+    PostViewerResponse, UrlEntityResponse, UserSummaryResponse,
+};
+use crate::application::realtime::EventHub;
+use crate::domain::block::BlockRepository;
+use crate::domain::draft::Draft;
+use crate::domain::event::DomainEvent;
+use crate::domain::follow::FollowRepository;
+use crate::domain::media::MediaAttachment;
+use crate::domain::notification::{NotificationEvent, NotificationKind};
+use crate::domain::poll::NewPoll;
+use crate::domain::post::{
+    extract_urls, normalize_hashtag, Audience, ContentPolicies, ContentPolicy, LabelSource,
+    MentionCandidate, NewLabel, Post, PostHashtag, PostLabel, PostMention, PostRepository,
+    PostSearchQuery, PostText, Publication, ReplyPolicy, SearchCursor, SearchSort,
+    MAX_CONTENT_BYTES,
+};
+use crate::domain::user::{AccountTier, SensitiveContent, UserRepository, UserSettingsRepository};
 use crate::error::AppError;
 
+/// Au-delà, les mentions suivantes restent du texte brut
+const MAX_MENTIONS_PER_POST: usize = 10;
+
+/// Longueur maximum d'une requête de recherche, opérateurs compris
+const MAX_SEARCH_QUERY_CHARS: usize = 256;
+
 pub struct PostService {
     post_repository: Arc<dyn PostRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    block_repository: Arc<dyn BlockRepository>,
+    follow_repository: Arc<dyn FollowRepository>,
+    settings_repository: Arc<dyn UserSettingsRepository>,
+    notification_service: Arc<NotificationService>,
+    media_service: Arc<MediaService>,
+    poll_service: Arc<PollService>,
+    event_hub: Arc<EventHub>,
+    content_policies: ContentPolicies,
 }
 
 impl PostService {
-    pub fn new(post_repository: Arc<dyn PostRepository>) -> Self {
-        Self { post_repository }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        post_repository: Arc<dyn PostRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        block_repository: Arc<dyn BlockRepository>,
+        follow_repository: Arc<dyn FollowRepository>,
+        settings_repository: Arc<dyn UserSettingsRepository>,
+        notification_service: Arc<NotificationService>,
+        media_service: Arc<MediaService>,
+        poll_service: Arc<PollService>,
+        event_hub: Arc<EventHub>,
+        content_policies: ContentPolicies,
+    ) -> Self {
+        Self {
+            post_repository,
+            user_repository,
+            block_repository,
+            follow_repository,
+            settings_repository,
+            notification_service,
+            media_service,
+            poll_service,
+            event_hub,
+            content_policies,
+        }
+    }
+
+    /// Niveau du compte et limites de publication qui s'y appliquent
+    pub async fn content_policy(&self, uid: &str) -> Result<(AccountTier, ContentPolicy), AppError> {
+        let tier = self
+            .user_repository
+            .find_by_uid(uid)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Utilisateur non trouvé".into()))?
+            .tier();
+        Ok((tier, *self.content_policies.for_tier(tier)))
     }
 
-    pub async fn create_post(&self, author_uid: String, content: String) -> Result<Post, AppError> {
-        // Valider le contenu
-        let content = content.trim().to_string();
-        if content.is_empty() {
+    /// Normalise le contenu d'un post, vérifie qu'il ne dépasse ni `max_length`
+    /// ni `MAX_CONTENT_BYTES`, et en extrait les entités
+    pub fn validate_text(content: &str, max_length: usize) -> Result<PostText, AppError> {
+        // Vérifié avant l'analyse, pour ne pas normaliser un texte démesuré
+        if content.trim().len() > MAX_CONTENT_BYTES {
+            return Err(AppError::Validation("Le contenu est trop volumineux".into()));
+        }
+
+        let text = PostText::parse(content);
+        if text.is_empty() {
             return Err(AppError::Validation("Le contenu ne peut pas être vide".into()));
         }
-        if content.len() > 280 {
-            return Err(AppError::Validation("Le contenu ne peut pas dépasser 280 caractères".into()));
+        if text.weighted_length > max_length {
+            return Err(AppError::Validation(format!(
+                "Le contenu ne peut pas dépasser {} caractères",
+                max_length
+            )));
+        }
+        Ok(text)
+    }
+
+    /// Valide et normalise le contenu d'un post. Partagé avec les messages privés.
+    pub fn validate_content(content: &str, max_length: usize) -> Result<String, AppError> {
+        Self::validate_text(content, max_length).map(|text| text.content)
+    }
+
+    /// Posts et réponses comptent ensemble, sur 24 heures glissantes
+    async fn check_daily_limit(&self, author_uid: &str, policy: &ContentPolicy) -> Result<(), AppError> {
+        let published = self
+            .post_repository
+            .count_by_author_since(author_uid, Utc::now() - Duration::hours(24))
+            .await?;
+        if published >= policy.max_posts_per_day {
+            return Err(AppError::TooManyRequests(format!(
+                "Vous ne pouvez pas publier plus de {} posts par jour",
+                policy.max_posts_per_day
+            )));
+        }
+        Ok(())
+    }
+
+    /// Normalise l'avertissement de contenu, absent s'il est vide
+    pub fn validate_label(label: Option<NewLabel>) -> Result<Option<NewLabel>, AppError> {
+        match label {
+            Some(label) => label.normalize().map_err(AppError::Validation),
+            None => Ok(None),
         }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_post(
+        &self,
+        author_uid: String,
+        content: String,
+        attachments: Vec<MediaAttachment>,
+        poll: Option<NewPoll>,
+        reply_policy: ReplyPolicy,
+        audience: Audience,
+        label: Option<NewLabel>,
+    ) -> Result<Post, AppError> {
+        self.create_root_post(author_uid, content, attachments, poll, reply_policy, audience, label, None)
+            .await
+    }
 
-        let post = Post::new(author_uid, content);
-        self.post_repository.create(&post).await
+    /// Publie un brouillon planifié avec ses médias réservés. Le brouillon est
+    /// supprimé dans la transaction de publication : il ne peut être publié deux fois.
+    pub async fn publish_draft(
+        &self,
+        draft: &Draft,
+        attachments: Vec<MediaAttachment>,
+    ) -> Result<Post, AppError> {
+        self.create_root_post(
+            draft.author_uid.clone(),
+            draft.content.clone(),
+            attachments,
+            draft.poll(),
+            draft.reply_policy(),
+            draft.audience(),
+            draft.label(),
+            Some(draft.id),
+        )
+        .await
     }
 
+    #[allow(clippy::too_many_arguments)]
+    async fn create_root_post(
+        &self,
+        author_uid: String,
+        content: String,
+        attachments: Vec<MediaAttachment>,
+        poll: Option<NewPoll>,
+        reply_policy: ReplyPolicy,
+        audience: Audience,
+        label: Option<NewLabel>,
+        draft_id: Option<Uuid>,
+    ) -> Result<Post, AppError> {
+        let (_, policy) = self.content_policy(&author_uid).await?;
+        let text = Self::validate_text(&content, policy.max_post_length)?;
+        let label = Self::validate_label(label)?;
+        let attachments = self
+            .media_service
+            .validate_attachments(&author_uid, attachments, policy.max_attachments, draft_id)
+            .await?;
+        let poll = poll.map(|poll| self.poll_service.validate(poll)).transpose()?;
+        self.check_daily_limit(&author_uid, &policy).await?;
+
+        let mut post = Post::new(author_uid, text.content.clone());
+        post.reply_policy = reply_policy.as_str().to_string();
+        post.audience = audience.as_str().to_string();
+        self.publish(post, &text, None, attachments, poll, label, draft_id).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_reply(
         &self,
         author_uid: String,
         content: String,
         parent_id: Uuid,
+        attachments: Vec<MediaAttachment>,
+        poll: Option<NewPoll>,
+        audience: Audience,
+        label: Option<NewLabel>,
     ) -> Result<Post, AppError> {
-        // Vérifier que le post parent existe
-        self.post_repository
-            .find_by_id(parent_id)
+        // Vérifier que le post parent existe et n'est masqué ni par un blocage ni par son audience
+        let parent = self
+            .get_post(parent_id, Some(&author_uid))
             .await?
             .ok_or_else(|| AppError::NotFound("Post parent non trouvé".into()))?;
+        self.check_reply_allowed(&parent, &author_uid).await?;
 
-        // Valider le contenu
-        let content = content.trim().to_string();
-        if content.is_empty() {
-            return Err(AppError::Validation("Le contenu ne peut pas être vide".into()));
+        let (_, policy) = self.content_policy(&author_uid).await?;
+        let text = Self::validate_text(&content, policy.max_post_length)?;
+        let label = Self::validate_label(label)?;
+        let attachments = self
+            .media_service
+            .validate_attachments(&author_uid, attachments, policy.max_attachments, None)
+            .await?;
+        let poll = poll.map(|poll| self.poll_service.validate(poll)).transpose()?;
+        self.check_daily_limit(&author_uid, &policy).await?;
+
+        let mut post = Post::reply(author_uid, text.content.clone(), &parent);
+        post.audience = audience.as_str().to_string();
+        let reply = self
+            .publish(post, &text, Some(&parent), attachments, poll, label, None)
+            .await?;
+        self.publish_counters(parent.id).await;
+        Ok(reply)
+    }
+
+    /// Applique le réglage de réponses du post racine du fil
+    async fn check_reply_allowed(&self, parent: &Post, author_uid: &str) -> Result<(), AppError> {
+        let root = match parent.root_id {
+            Some(root_id) => match self.post_repository.find_by_id(root_id).await? {
+                Some(root) => root,
+                // Racine supprimée : plus de restriction
+                None => return Ok(()),
+            },
+            None => parent.clone(),
+        };
+        if root.author_uid == author_uid {
+            return Ok(());
         }
-        if content.len() > 280 {
-            return Err(AppError::Validation("Le contenu ne peut pas dépasser 280 caractères".into()));
+
+        let allowed = match root.reply_policy() {
+            ReplyPolicy::Everyone => true,
+            ReplyPolicy::Following => {
+                self.follow_repository
+                    .is_following(&root.author_uid, author_uid)
+                    .await?
+            }
+            ReplyPolicy::Mentioned => self
+                .post_repository
+                .find_mentions(&[root.id])
+                .await?
+                .iter()
+                .any(|mention| mention.mentioned_uid == author_uid),
+        };
+
+        if !allowed {
+            return Err(AppError::Forbidden(
+                "L'auteur a limité les réponses à cette conversation".into(),
+            ));
         }
+        Ok(())
+    }
 
-        let post = Post::reply(author_uid, content, parent_id);
-        self.post_repository.create(&post).await
+    /// Masque ou réaffiche une réponse. Réservé à l'auteur du post racine du fil.
+    pub async fn set_reply_hidden(&self, reply_id: Uuid, requester_uid: &str, hidden: bool) -> Result<(), AppError> {
+        let reply = self
+            .post_repository
+            .find_by_id(reply_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Post non trouvé".into()))?;
+        let Some(root_id) = reply.root_id else {
+            return Err(AppError::Validation("Seule une réponse peut être masquée".into()));
+        };
+
+        let root = self.post_repository.find_by_id(root_id).await?;
+        if root.is_none_or(|root| root.author_uid != requester_uid) {
+            return Err(AppError::Forbidden(
+                "Seul l'auteur de la conversation peut masquer ses réponses".into(),
+            ));
+        }
+
+        self.post_repository.set_hidden(reply.id, hidden).await
     }
 
-    pub async fn get_post(&self, id: Uuid) -> Result<Option<Post>, AppError> {
-        self.post_repository.find_by_id(id).await
+    /// Origine de l'avertissement que `requester_uid` peut poser sur le post :
+    /// l'auteur sur le sien, un membre de l'équipe sur celui des autres
+    async fn label_source(&self, post: &Post, requester_uid: &str) -> Result<LabelSource, AppError> {
+        if post.author_uid == requester_uid {
+            return Ok(LabelSource::Author);
+        }
+
+        let is_staff = self
+            .user_repository
+            .find_by_uid(requester_uid)
+            .await?
+            .is_some_and(|user| user.is_staff);
+        if !is_staff {
+            return Err(AppError::Forbidden(
+                "Seule la modération peut ajouter un avertissement au post d'un autre".into(),
+            ));
+        }
+        Ok(LabelSource::Moderator)
     }
 
-    pub async fn get_timeline(&self, limit: i64, offset: i64) -> Result<Vec<Post>, AppError> {
-        let limit = limit.min(50).max(1);
+    /// Pose ou remplace un avertissement de contenu
+    pub async fn label_post(&self, post_id: Uuid, requester_uid: &str, label: NewLabel) -> Result<Post, AppError> {
+        let post = self
+            .get_post(post_id, Some(requester_uid))
+            .await?
+            .ok_or_else(|| AppError::NotFound("Post non trouvé".into()))?;
+        let source = self.label_source(&post, requester_uid).await?;
+
+        let label = Self::validate_label(Some(label))?.ok_or_else(|| {
+            AppError::Validation("Indiquez un avertissement ou marquez le post comme sensible".into())
+        })?;
+        self.post_repository
+            .set_label(&PostLabel::new(post.id, source, label, requester_uid.to_string()))
+            .await?;
+
+        if source == LabelSource::Moderator {
+            tracing::info!("Avertissement posé sur le post {} par {}", post.id, requester_uid);
+        }
+        Ok(post)
+    }
+
+    /// Retire l'avertissement de même origine : l'auteur ne peut pas retirer celui de la modération
+    pub async fn unlabel_post(&self, post_id: Uuid, requester_uid: &str) -> Result<(), AppError> {
+        let post = self
+            .get_post(post_id, Some(requester_uid))
+            .await?
+            .ok_or_else(|| AppError::NotFound("Post non trouvé".into()))?;
+        let source = self.label_source(&post, requester_uid).await?;
+
+        self.post_repository.remove_label(post.id, source).await?;
+        Ok(())
+    }
+
+    /// Réponse d'un post diffusé en direct, construite une fois pour toutes les
+    /// connexions : sans indicateurs du lecteur et repliée s'il porte un avertissement
+    pub async fn build_live_response(&self, post_id: Uuid) -> Result<Option<PostResponse>, AppError> {
+        let Some(post) = self.post_repository.find_by_id(post_id).await? else {
+            return Ok(None);
+        };
+        self.build_response(post, None).await.map(Some)
+    }
+
+    /// Adapte la réponse partagée d'un post diffusé en direct à un lecteur, ou `None`
+    /// si le post ne lui est pas destiné (audience, blocage, masquage, avertissement caché)
+    pub async fn live_response_for(
+        &self,
+        shared: &PostResponse,
+        viewer_uid: &str,
+    ) -> Result<Option<PostResponse>, AppError> {
+        if !self.post_repository.is_deliverable_to(shared.id, viewer_uid).await? {
+            return Ok(None);
+        }
+
+        let mut response = shared.clone();
+        if response.collapsed
+            && (response.author_uid == viewer_uid
+                || self.settings_repository.find(viewer_uid).await?.sensitive_content()
+                    == SensitiveContent::Show)
+        {
+            response.collapsed = false;
+        }
+        // Un post qui vient d'être publié n'a encore ni like, ni repost, ni bookmark
+        response.viewer = Some(PostViewerResponse {
+            liked_by_me: false,
+            reposted_by_me: false,
+            bookmarked_by_me: false,
+        });
+        Ok(Some(response))
+    }
+
+    /// Enregistre en une transaction le post avec ses médias, son sondage, son avertissement,
+    /// ses mentions résolues et ses hashtags, puis notifie l'auteur du post parent et les
+    /// utilisateurs mentionnés
+    #[allow(clippy::too_many_arguments)]
+    async fn publish(
+        &self,
+        post: Post,
+        text: &PostText,
+        parent: Option<&Post>,
+        attachments: Vec<MediaAttachment>,
+        poll: Option<NewPoll>,
+        label: Option<NewLabel>,
+        draft_id: Option<Uuid>,
+    ) -> Result<Post, AppError> {
+        let mentions = self.resolve_mentions(&post, text.mentions()).await?;
+        let hashtags: Vec<PostHashtag> = text
+            .hashtags()
+            .map(|hashtag| PostHashtag {
+                post_id: post.id,
+                tag: hashtag.tag.clone(),
+                start_offset: hashtag.start as i32,
+                end_offset: hashtag.end as i32,
+            })
+            .collect();
+        let label = label
+            .map(|label| PostLabel::new(post.id, LabelSource::Author, label, post.author_uid.clone()));
+        let publication = Publication {
+            attachments,
+            poll: poll.map(|poll| poll.build(post.id)),
+            label,
+            mentions,
+            hashtags,
+            post,
+            draft_id,
+        };
+        let created = self.post_repository.create(&publication).await?;
+
+        self.notify_publication(&created, parent, &publication.mentions).await;
+        self.event_hub
+            .publish(DomainEvent::PostCreated {
+                post_id: created.id,
+                author_uid: created.author_uid.clone(),
+                parent_id: created.parent_id,
+                root_id: created.root_id,
+                hashtags: publication.hashtags.into_iter().map(|hashtag| hashtag.tag).collect(),
+            })
+            .await;
+
+        Ok(created)
+    }
+
+    /// L'auteur du parent reçoit une notification de réponse plutôt que de mention
+    async fn notify_publication(&self, post: &Post, parent: Option<&Post>, mentions: &[PostMention]) {
+        let mut events = Vec::new();
+
+        if let Some(parent) = parent {
+            events.push(NotificationEvent {
+                recipient_uid: parent.author_uid.clone(),
+                actor_uid: post.author_uid.clone(),
+                kind: NotificationKind::Reply,
+                target_post_id: Some(parent.id),
+                source_post_id: Some(post.id),
+            });
+        }
+
+        let mut notified: Vec<&str> = parent.map(|p| p.author_uid.as_str()).into_iter().collect();
+        for mention in mentions {
+            if notified.contains(&mention.mentioned_uid.as_str()) {
+                continue;
+            }
+            notified.push(&mention.mentioned_uid);
+            events.push(NotificationEvent {
+                recipient_uid: mention.mentioned_uid.clone(),
+                actor_uid: post.author_uid.clone(),
+                kind: NotificationKind::Mention,
+                target_post_id: Some(post.id),
+                source_post_id: Some(post.id),
+            });
+        }
+
+        for event in events {
+            // Pas de notification pour qui ne peut pas voir le post
+            match self.post_repository.is_visible_to(post.id, Some(&event.recipient_uid)).await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(e) => {
+                    tracing::warn!("Notification du post {} non envoyée: {}", post.id, e);
+                    continue;
+                }
+            }
+            if let Err(e) = self
+                .notification_service
+                .notify(event, Some(post.thread_id()), Some(&post.content))
+                .await
+            {
+                tracing::warn!("Notification du post {} non envoyée: {}", post.id, e);
+            }
+        }
+    }
+
+    /// Résout les `@username` du contenu en utilisateurs. Les usernames inconnus
+    /// et les utilisateurs bloqués par (ou bloquant) l'auteur sont ignorés.
+    async fn resolve_mentions(
+        &self,
+        post: &Post,
+        candidates: impl Iterator<Item = &MentionCandidate>,
+    ) -> Result<Vec<PostMention>, AppError> {
+        let mut resolved = HashMap::new();
+        let mut mentions = Vec::new();
+
+        for candidate in candidates {
+            let key = candidate.username.to_lowercase();
+            if !resolved.contains_key(&key) {
+                if resolved.len() >= MAX_MENTIONS_PER_POST {
+                    continue;
+                }
+
+                let user = match self.user_repository.find_by_username(&candidate.username).await? {
+                    Some(user)
+                        if user.uid == post.author_uid
+                            || !self
+                                .block_repository
+                                .is_blocked_between(&user.uid, &post.author_uid)
+                                .await? =>
+                    {
+                        Some(user)
+                    }
+                    _ => None,
+                };
+                resolved.insert(key.clone(), user);
+            }
+
+            if let Some(Some(user)) = resolved.get(&key) {
+                mentions.push(PostMention {
+                    post_id: post.id,
+                    mentioned_uid: user.uid.clone(),
+                    username: user.username.clone(),
+                    start_offset: candidate.start as i32,
+                    end_offset: candidate.end as i32,
+                });
+            }
+        }
+
+        Ok(mentions)
+    }
+
+    /// Un post dont l'auteur a bloqué le lecteur (ou l'inverse), dont l'audience
+    /// n'inclut pas le lecteur, ou d'un compte privé qu'il ne suit pas, est traité
+    /// comme inexistant
+    pub async fn get_post(&self, id: Uuid, viewer_uid: Option<&str>) -> Result<Option<Post>, AppError> {
+        let Some(post) = self.post_repository.find_by_id(id).await? else {
+            return Ok(None);
+        };
+
+        if !self.post_repository.is_visible_to(post.id, viewer_uid).await? {
+            return Ok(None);
+        }
+
+        if let Some(viewer_uid) = viewer_uid {
+            if post.author_uid != viewer_uid
+                && self
+                    .block_repository
+                    .is_blocked_between(&post.author_uid, viewer_uid)
+                    .await?
+            {
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(post))
+    }
+
+    pub async fn get_timeline(
+        &self,
+        viewer_uid: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Post>, AppError> {
+        let limit = limit.clamp(1, 50);
         let offset = offset.max(0);
-        self.post_repository.get_timeline(limit, offset).await
+        self.post_repository.get_timeline(viewer_uid, limit, offset).await
     }
 
+    /// Posts de l'auteur, plus récents d'abord. Le post épinglé, s'il est visible
+    /// du lecteur, occupe la première position de la liste (son identifiant est
+    /// aussi retourné) et n'apparaît pas ailleurs : les pages gardent leur taille
+    /// et les offsets restent continus.
     pub async fn get_user_posts(
         &self,
         author_uid: &str,
+        viewer_uid: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<Post>, Option<Uuid>), AppError> {
+        let limit = limit.clamp(1, 50);
+        let offset = offset.max(0);
+
+        let pinned_id = self
+            .user_repository
+            .find_by_uid(author_uid)
+            .await?
+            .and_then(|user| user.pinned_post_id);
+        let pinned = match pinned_id {
+            Some(id) => self.get_post(id, viewer_uid).await?,
+            None => None,
+        };
+        // Comme le reste du profil, le post épinglé respecte le choix de cacher
+        // les posts avec avertissement
+        let pinned = match (pinned, viewer_uid) {
+            (Some(pinned), Some(viewer_uid)) if pinned.author_uid != viewer_uid => {
+                let settings = self.settings_repository.find(viewer_uid).await?;
+                let hides_labeled = settings.sensitive_content() == SensitiveContent::Hide
+                    && !self.post_repository.find_labels(&[pinned.id]).await?.is_empty();
+                (!hides_labeled).then_some(pinned)
+            }
+            (pinned, _) => pinned,
+        };
+
+        let Some(pinned) = pinned else {
+            let posts = self
+                .post_repository
+                .find_by_author(author_uid, viewer_uid, None, limit, offset)
+                .await?;
+            return Ok((posts, None));
+        };
+
+        let pinned_id = pinned.id;
+        if offset > 0 {
+            let posts = self
+                .post_repository
+                .find_by_author(author_uid, viewer_uid, Some(pinned_id), limit, offset - 1)
+                .await?;
+            return Ok((posts, None));
+        }
+
+        let mut posts = vec![pinned];
+        if limit > 1 {
+            posts.extend(
+                self.post_repository
+                    .find_by_author(author_uid, viewer_uid, Some(pinned_id), limit - 1, 0)
+                    .await?,
+            );
+        }
+        Ok((posts, Some(pinned_id)))
+    }
+
+    /// Épingle un de ses posts en tête de son profil, à la place du précédent
+    pub async fn pin_post(&self, post_id: Uuid, user_uid: &str) -> Result<(), AppError> {
+        let post = self
+            .post_repository
+            .find_by_id(post_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Post non trouvé".into()))?;
+        if post.author_uid != user_uid {
+            return Err(AppError::Forbidden("Vous ne pouvez épingler que vos propres posts".into()));
+        }
+
+        self.user_repository.set_pinned_post(user_uid, Some(post.id)).await
+    }
+
+    /// Désépingle le post s'il est épinglé
+    pub async fn unpin_post(&self, post_id: Uuid, user_uid: &str) -> Result<(), AppError> {
+        let user = self
+            .user_repository
+            .find_by_uid(user_uid)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Utilisateur non trouvé".into()))?;
+        if user.pinned_post_id == Some(post_id) {
+            self.user_repository.set_pinned_post(user_uid, None).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn get_replies(
+        &self,
+        post_id: Uuid,
+        viewer_uid: Option<&str>,
         limit: i64,
         offset: i64,
     ) -> Result<Vec<Post>, AppError> {
-        let limit = limit.min(50).max(1);
+        self.get_post(post_id, viewer_uid)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Post non trouvé".into()))?;
+
+        let limit = limit.clamp(1, 50);
         let offset = offset.max(0);
-        self.post_repository.find_by_author(author_uid, limit, offset).await
+        self.post_repository
+            .get_replies(post_id, viewer_uid, limit, offset)
+            .await
     }
 
-    pub async fn get_replies(&self, post_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Post>, AppError> {
-        let limit = limit.min(50).max(1);
+    /// Posts mentionnant `uid`. Vide si l'utilisateur est bloqué par le lecteur (ou l'inverse).
+    pub async fn get_mentions(
+        &self,
+        uid: &str,
+        viewer_uid: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Post>, AppError> {
+        if let Some(viewer_uid) = viewer_uid {
+            if viewer_uid != uid && self.block_repository.is_blocked_between(uid, viewer_uid).await? {
+                return Ok(Vec::new());
+            }
+        }
+
+        let limit = limit.clamp(1, 50);
         let offset = offset.max(0);
-        self.post_repository.get_replies(post_id, limit, offset).await
+        self.post_repository
+            .find_mentioning(uid, viewer_uid, limit, offset)
+            .await
+    }
+
+    /// Timeline d'un hashtag, insensible à la casse (`#Rust` et `rust` sont équivalents)
+    pub async fn get_hashtag_posts(
+        &self,
+        tag: &str,
+        viewer_uid: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Post>, AppError> {
+        let tag = normalize_hashtag(tag);
+        if tag.is_empty() {
+            return Err(AppError::Validation("Hashtag invalide".into()));
+        }
+
+        let limit = limit.clamp(1, 50);
+        let offset = offset.max(0);
+        self.post_repository
+            .find_by_hashtag(&tag, viewer_uid, limit, offset)
+            .await
+    }
+
+    /// Recherche plein texte. Retourne la page de posts et le curseur de la
+    /// page suivante, absent s'il n'y a plus de résultats.
+    pub async fn search_posts(
+        &self,
+        query: &str,
+        sort: SearchSort,
+        cursor: Option<&str>,
+        viewer_uid: Option<&str>,
+        limit: i64,
+    ) -> Result<(Vec<Post>, Option<String>), AppError> {
+        if query.chars().count() > MAX_SEARCH_QUERY_CHARS {
+            return Err(AppError::Validation(format!(
+                "La recherche ne peut pas dépasser {} caractères",
+                MAX_SEARCH_QUERY_CHARS
+            )));
+        }
+
+        let query = PostSearchQuery::parse(query).map_err(AppError::Validation)?;
+
+        // Sans texte, tous les scores sont nuls : seul l'ordre chronologique a un sens
+        let sort = if query.text.is_some() { sort } else { SearchSort::Recent };
+
+        let cursor = cursor
+            .map(|cursor| {
+                SearchCursor::decode(cursor, sort)
+                    .ok_or_else(|| AppError::Validation("Curseur invalide".into()))
+            })
+            .transpose()?;
+
+        let limit = limit.clamp(1, 50);
+        let hits = self
+            .post_repository
+            .search(&query, sort, cursor.as_ref(), viewer_uid, limit)
+            .await?;
+
+        let next_cursor = match hits.last() {
+            Some(last) if hits.len() as i64 == limit => {
+                Some(SearchCursor::after(last, sort).encode())
+            }
+            _ => None,
+        };
+
+        Ok((hits.into_iter().map(|hit| hit.post).collect(), next_cursor))
+    }
+
+    pub async fn like_post(&self, post_id: Uuid, user_uid: &str) -> Result<(), AppError> {
+        // Un post masqué par un blocage ne peut pas être aimé
+        let post = self
+            .get_post(post_id, Some(user_uid))
+            .await?
+            .ok_or_else(|| AppError::NotFound("Post non trouvé".into()))?;
+
+        if self.post_repository.add_like(user_uid, post_id).await? {
+            let event = NotificationEvent {
+                recipient_uid: post.author_uid.clone(),
+                actor_uid: user_uid.to_string(),
+                kind: NotificationKind::Like,
+                target_post_id: Some(post.id),
+                source_post_id: None,
+            };
+            if let Err(e) = self
+                .notification_service
+                .notify(event, Some(post.thread_id()), None)
+                .await
+            {
+                tracing::warn!("Notification de like non envoyée: {}", e);
+            }
+            self.publish_counters(post.id).await;
+        }
+        Ok(())
+    }
+
+    pub async fn unlike_post(&self, post_id: Uuid, user_uid: &str) -> Result<(), AppError> {
+        if self.post_repository.remove_like(user_uid, post_id).await? {
+            self.publish_counters(post_id).await;
+        }
+        Ok(())
+    }
+
+    /// Vote à un sondage, si le post est visible par l'utilisateur
+    pub async fn vote_in_poll(&self, post_id: Uuid, user_uid: &str, option: i16) -> Result<Post, AppError> {
+        let post = self
+            .get_post(post_id, Some(user_uid))
+            .await?
+            .ok_or_else(|| AppError::NotFound("Post non trouvé".into()))?;
+
+        self.poll_service.vote(post.id, user_uid, option).await?;
+        Ok(post)
+    }
+
+    /// Diffuse les compteurs à jour d'un post aux clients abonnés
+    async fn publish_counters(&self, post_id: Uuid) {
+        match self.post_repository.find_by_id(post_id).await {
+            Ok(Some(post)) => {
+                self.event_hub
+                    .publish(DomainEvent::PostCounters {
+                        post_id: post.id,
+                        likes_count: post.likes_count,
+                        replies_count: post.replies_count,
+                        reposts_count: post.reposts_count,
+                    })
+                    .await
+            }
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Compteurs du post {} non diffusés: {}", post_id, e),
+        }
     }
 
     pub async fn delete_post(&self, id: Uuid, requester_uid: &str) -> Result<(), AppError> {
@@ -93,4 +835,154 @@ impl PostService {
 
         self.post_repository.delete(id).await
     }
+
+    /// Construit les réponses en embarquant les auteurs, les entités, les médias, les sondages,
+    /// les avertissements et, si un utilisateur est connecté, ses indicateurs (like, repost, bookmark).
+    /// Les posts avec avertissement sont repliés, sauf pour leur auteur ou si le lecteur les déplie d'office.
+    /// Une requête groupée par type de donnée, quel que soit le nombre de posts.
+    pub async fn build_responses(
+        &self,
+        posts: Vec<Post>,
+        viewer_uid: Option<&str>,
+    ) -> Result<Vec<PostResponse>, AppError> {
+        let mut author_uids: Vec<String> = posts.iter().map(|p| p.author_uid.clone()).collect();
+        author_uids.sort();
+        author_uids.dedup();
+
+        let authors: HashMap<String, UserSummaryResponse> = self
+            .user_repository
+            .find_by_uids(&author_uids)
+            .await?
+            .into_iter()
+            .map(|user| (user.uid.clone(), UserSummaryResponse::from(user)))
+            .collect();
+
+        let post_ids: Vec<Uuid> = posts.iter().map(|p| p.id).collect();
+
+        let mut mentions: HashMap<Uuid, Vec<MentionEntityResponse>> = HashMap::new();
+        for mention in self.post_repository.find_mentions(&post_ids).await? {
+            mentions
+                .entry(mention.post_id)
+                .or_default()
+                .push(MentionEntityResponse::from(mention));
+        }
+
+        let mut hashtags: HashMap<Uuid, Vec<HashtagEntityResponse>> = HashMap::new();
+        for hashtag in self.post_repository.find_hashtags(&post_ids).await? {
+            hashtags
+                .entry(hashtag.post_id)
+                .or_default()
+                .push(HashtagEntityResponse::from(hashtag));
+        }
+
+        let mut media: HashMap<Uuid, Vec<MediaResponse>> = HashMap::new();
+        for item in self.media_service.get_for_posts(&post_ids).await? {
+            if let Some(post_id) = item.post_id {
+                media.entry(post_id).or_default().push(MediaResponse::from(item));
+            }
+        }
+
+        let mut polls = self.poll_service.get_for_posts(&posts, viewer_uid).await?;
+
+        let mut labels: HashMap<Uuid, Vec<PostLabelResponse>> = HashMap::new();
+        for label in self.post_repository.find_labels(&post_ids).await? {
+            labels
+                .entry(label.post_id)
+                .or_default()
+                .push(PostLabelResponse::from(label));
+        }
+
+        let expand_labeled = match viewer_uid {
+            Some(viewer_uid) if !labels.is_empty() => {
+                self.settings_repository.find(viewer_uid).await?.sensitive_content()
+                    == SensitiveContent::Show
+            }
+            _ => false,
+        };
+
+        let mut viewer_states = HashMap::new();
+        if let Some(viewer_uid) = viewer_uid {
+            for state in self
+                .post_repository
+                .get_viewer_states(viewer_uid, &post_ids)
+                .await?
+            {
+                viewer_states.insert(state.post_id, PostViewerResponse::from(state));
+            }
+        }
+
+        Ok(posts
+            .into_iter()
+            .map(|post| {
+                let author = authors.get(&post.author_uid).cloned();
+                let viewer = viewer_states.remove(&post.id);
+                let post_mentions = mentions.remove(&post.id).unwrap_or_default();
+                let post_hashtags = hashtags.remove(&post.id).unwrap_or_default();
+                let post_media = media.remove(&post.id).unwrap_or_default();
+                let poll = polls.remove(&post.id);
+                let post_labels = labels.remove(&post.id).unwrap_or_default();
+                let collapsed = !post_labels.is_empty()
+                    && !expand_labeled
+                    && viewer_uid != Some(post.author_uid.as_str());
+                let mut response = PostResponse::from(post);
+                response.author = author;
+                response.entities.mentions = post_mentions;
+                response.entities.hashtags = post_hashtags;
+                response.entities.urls = extract_urls(&response.content)
+                    .into_iter()
+                    .map(UrlEntityResponse::from)
+                    .collect();
+                response.media = post_media;
+                response.poll = poll;
+                response.labels = post_labels;
+                response.collapsed = collapsed;
+                response.viewer = viewer;
+                response
+            })
+            .collect())
+    }
+
+    pub async fn build_response(
+        &self,
+        post: Post,
+        viewer_uid: Option<&str>,
+    ) -> Result<PostResponse, AppError> {
+        self.build_responses(vec![post], viewer_uid)
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::Internal("Réponse de post manquante".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::post::URL_WEIGHT;
+
+    #[test]
+    fn une_url_compte_pour_son_poids() {
+        let url = format!("https://example.com/{}", "a".repeat(500));
+        let text = PostService::validate_text(&format!("voir {}", url), 280).unwrap();
+
+        assert_eq!(text.weighted_length, "voir ".len() + URL_WEIGHT);
+    }
+
+    #[test]
+    fn refuse_une_url_demesuree() {
+        let url = format!("https://example.com/{}", "a".repeat(MAX_CONTENT_BYTES));
+
+        assert!(matches!(
+            PostService::validate_text(&url, 280),
+            Err(AppError::Validation(_))
+        ));
+        assert!(PostService::validate_content(&format!("voir {}", url), 280).is_err());
+    }
+
+    #[test]
+    fn accepte_un_post_au_plus_pres_de_la_limite() {
+        let family = "👩\u{200d}👩\u{200d}👧".repeat(280);
+        assert!(family.len() <= MAX_CONTENT_BYTES);
+        assert!(PostService::validate_text(&family, 280).is_ok());
+        assert!(PostService::validate_text(&format!("{}!", family), 280).is_err());
+    }
 }
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::domain::suggestion::{FollowSuggestion, SuggestionRepository};
+use crate::domain::user::{User, UserRepository};
+use crate::error::AppError;
+
+/// Nombre de suggestions conservées en cache par utilisateur
+const MAX_SUGGESTIONS: i64 = 100;
+/// Nombre de caches recalculés à chaque passage de la tâche périodique
+const REFRESH_BATCH: i64 = 100;
+
+pub struct SuggestionService {
+    suggestion_repository: Arc<dyn SuggestionRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    /// Durée de validité d'un cache de suggestions
+    max_age: Duration,
+}
+
+impl SuggestionService {
+    pub fn new(
+        suggestion_repository: Arc<dyn SuggestionRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        max_age: Duration,
+    ) -> Self {
+        Self {
+            suggestion_repository,
+            user_repository,
+            max_age,
+        }
+    }
+
+    /// Comptes à suivre, calculés à la première demande puis servis depuis le cache
+    pub async fn get_suggestions(
+        &self,
+        user_uid: &str,
+        limit: i64,
+    ) -> Result<Vec<(FollowSuggestion, User)>, AppError> {
+        let limit = limit.clamp(1, 50);
+
+        let suggestions = match self
+            .suggestion_repository
+            .find_fresh(user_uid, self.max_age, limit)
+            .await?
+        {
+            Some(suggestions) => suggestions,
+            None => {
+                self.suggestion_repository.refresh(user_uid, MAX_SUGGESTIONS).await?;
+                self.suggestion_repository
+                    .find_fresh(user_uid, self.max_age, limit)
+                    .await?
+                    .unwrap_or_default()
+            }
+        };
+
+        let uids: Vec<String> = suggestions.iter().map(|s| s.suggested_uid.clone()).collect();
+        let users = self.user_repository.find_by_uids(&uids).await?;
+
+        Ok(suggestions
+            .into_iter()
+            .filter_map(|suggestion| {
+                let user = users.iter().find(|u| u.uid == suggestion.suggested_uid)?.clone();
+                Some((suggestion, user))
+            })
+            .collect())
+    }
+
+    /// Recalcule les caches expirés. Appelé périodiquement.
+    pub async fn refresh_stale(&self) -> Result<(), AppError> {
+        let uids = self
+            .suggestion_repository
+            .find_stale_users(self.max_age, REFRESH_BATCH)
+            .await?;
+
+        for uid in &uids {
+            if let Err(e) = self.suggestion_repository.refresh(uid, MAX_SUGGESTIONS).await {
+                tracing::warn!("Suggestions non recalculées pour {}: {}", uid, e);
+            }
+        }
+
+        if !uids.is_empty() {
+            tracing::debug!("{} caches de suggestions recalculés", uids.len());
+        }
+
+        Ok(())
+    }
+}
@@ -1,16 +1,48 @@
 use std::sync::Arc;
 
-use crate::application::dto::RegisterRequest;
-use crate::domain::user::{User, UserRepository};
+use super::{MediaService, NotificationService};
+use crate::application::dto::{RegisterRequest, UpdateSettingsRequest};
+use crate::domain::block::BlockRepository;
+use crate::domain::close_friend::CloseFriendRepository;
+use crate::domain::follow::FollowRepository;
+use crate::domain::notification::{NotificationEvent, NotificationKind};
+use crate::domain::user::{
+    ProfileImageKind, User, UserRepository, UserSettings, UserSettingsRepository,
+};
 use crate::error::AppError;
 
+/// Taille maximum d'une liste d'amis proches
+const MAX_CLOSE_FRIENDS: i64 = 500;
+
 pub struct UserService {
     user_repository: Arc<dyn UserRepository>,
+    follow_repository: Arc<dyn FollowRepository>,
+    block_repository: Arc<dyn BlockRepository>,
+    close_friend_repository: Arc<dyn CloseFriendRepository>,
+    settings_repository: Arc<dyn UserSettingsRepository>,
+    notification_service: Arc<NotificationService>,
+    media_service: Arc<MediaService>,
 }
 
 impl UserService {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        follow_repository: Arc<dyn FollowRepository>,
+        block_repository: Arc<dyn BlockRepository>,
+        close_friend_repository: Arc<dyn CloseFriendRepository>,
+        settings_repository: Arc<dyn UserSettingsRepository>,
+        notification_service: Arc<NotificationService>,
+        media_service: Arc<MediaService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            follow_repository,
+            block_repository,
+            close_friend_repository,
+            settings_repository,
+            notification_service,
+            media_service,
+        }
     }
 
     pub async fn register(
@@ -68,4 +100,210 @@ impl UserService {
 
         self.user_repository.update(&user).await
     }
+
+    /// Remplace l'avatar ou la bannière, puis supprime l'ancien fichier
+    pub async fn update_profile_image(
+        &self,
+        uid: &str,
+        kind: ProfileImageKind,
+        bytes: Vec<u8>,
+    ) -> Result<User, AppError> {
+        let (url, key) = self.media_service.upload_profile_image(uid, kind, bytes).await?;
+
+        let previous_key = match self.user_repository.set_profile_image(uid, kind, &url, &key).await {
+            Ok(previous_key) => previous_key,
+            Err(e) => {
+                self.media_service.delete_file(&key).await;
+                return Err(e);
+            }
+        };
+        if let Some(previous_key) = previous_key {
+            self.media_service.delete_file(&previous_key).await;
+        }
+
+        self.user_repository
+            .find_by_uid(uid)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Utilisateur non trouvé".into()))
+    }
+
+    pub async fn get_settings(&self, uid: &str) -> Result<UserSettings, AppError> {
+        self.settings_repository.find(uid).await
+    }
+
+    pub async fn update_settings(
+        &self,
+        uid: &str,
+        request: UpdateSettingsRequest,
+    ) -> Result<UserSettings, AppError> {
+        let mut settings = self.settings_repository.find(uid).await?;
+
+        if let Some(dm_policy) = request.dm_policy {
+            settings.dm_policy = dm_policy.as_str().to_string();
+        }
+        if let Some(sensitive_content) = request.sensitive_content {
+            settings.sensitive_content = sensitive_content.as_str().to_string();
+        }
+
+        self.settings_repository.save(&settings).await
+    }
+
+    /// Profil public. Invisible pour un utilisateur bloqué (et pour celui qui bloque).
+    pub async fn get_profile(&self, uid: &str, viewer_uid: Option<&str>) -> Result<User, AppError> {
+        if let Some(viewer_uid) = viewer_uid {
+            if viewer_uid != uid && self.block_repository.is_blocked_between(uid, viewer_uid).await? {
+                return Err(AppError::NotFound("Utilisateur non trouvé".into()));
+            }
+        }
+
+        self.user_repository
+            .find_by_uid(uid)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Utilisateur non trouvé".into()))
+    }
+
+    /// Recherche d'utilisateurs pour l'autocomplétion des mentions
+    pub async fn search_users(
+        &self,
+        query: &str,
+        viewer_uid: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<User>, AppError> {
+        let term = query.trim().trim_start_matches('@').to_lowercase();
+        if term.is_empty() {
+            return Err(AppError::Validation("La recherche ne peut pas être vide".into()));
+        }
+        if term.chars().count() > 100 {
+            return Err(AppError::Validation(
+                "La recherche ne peut pas dépasser 100 caractères".into(),
+            ));
+        }
+
+        self.user_repository
+            .search(&term, viewer_uid, limit.clamp(1, 50))
+            .await
+    }
+
+    pub async fn follow(&self, follower_uid: &str, followee_uid: &str) -> Result<(), AppError> {
+        if follower_uid == followee_uid {
+            return Err(AppError::Validation("Vous ne pouvez pas vous suivre vous-même".into()));
+        }
+
+        // get_profile masque les utilisateurs bloqués
+        self.get_profile(followee_uid, Some(follower_uid)).await?;
+
+        if self.follow_repository.follow(follower_uid, followee_uid).await? {
+            let event = NotificationEvent {
+                recipient_uid: followee_uid.to_string(),
+                actor_uid: follower_uid.to_string(),
+                kind: NotificationKind::Follow,
+                target_post_id: None,
+                source_post_id: None,
+            };
+            if let Err(e) = self.notification_service.notify(event, None, None).await {
+                tracing::warn!("Notification d'abonnement non envoyée: {}", e);
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn unfollow(&self, follower_uid: &str, followee_uid: &str) -> Result<(), AppError> {
+        self.follow_repository.unfollow(follower_uid, followee_uid).await?;
+        Ok(())
+    }
+
+    pub async fn block(&self, blocker_uid: &str, blocked_uid: &str) -> Result<(), AppError> {
+        if blocker_uid == blocked_uid {
+            return Err(AppError::Validation("Vous ne pouvez pas vous bloquer vous-même".into()));
+        }
+
+        self.user_repository
+            .find_by_uid(blocked_uid)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Utilisateur non trouvé".into()))?;
+
+        self.block_repository.block(blocker_uid, blocked_uid).await
+    }
+
+    pub async fn unblock(&self, blocker_uid: &str, blocked_uid: &str) -> Result<(), AppError> {
+        self.block_repository.unblock(blocker_uid, blocked_uid).await
+    }
+
+    /// Utilisateurs bloqués par `blocker_uid`, du plus récent au plus ancien
+    pub async fn get_blocked_users(
+        &self,
+        blocker_uid: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<User>, AppError> {
+        let limit = limit.clamp(1, 50);
+        let offset = offset.max(0);
+
+        let blocked_uids: Vec<String> = self
+            .block_repository
+            .find_by_blocker(blocker_uid, limit, offset)
+            .await?
+            .into_iter()
+            .map(|block| block.blocked_uid)
+            .collect();
+
+        let mut users = self.user_repository.find_by_uids(&blocked_uids).await?;
+        users.sort_by_key(|user| blocked_uids.iter().position(|uid| *uid == user.uid));
+
+        Ok(users)
+    }
+
+    /// Ajoute un utilisateur à sa liste d'amis proches
+    pub async fn add_close_friend(&self, user_uid: &str, friend_uid: &str) -> Result<(), AppError> {
+        if user_uid == friend_uid {
+            return Err(AppError::Validation(
+                "Vous ne pouvez pas vous ajouter à vos amis proches".into(),
+            ));
+        }
+
+        // get_profile masque les utilisateurs bloqués
+        self.get_profile(friend_uid, Some(user_uid)).await?;
+
+        if self.close_friend_repository.is_close_friend(user_uid, friend_uid).await? {
+            return Ok(());
+        }
+        if self.close_friend_repository.count(user_uid).await? >= MAX_CLOSE_FRIENDS {
+            return Err(AppError::Validation(format!(
+                "Votre liste d'amis proches ne peut pas dépasser {} membres",
+                MAX_CLOSE_FRIENDS
+            )));
+        }
+
+        self.close_friend_repository.add(user_uid, friend_uid).await?;
+        Ok(())
+    }
+
+    pub async fn remove_close_friend(&self, user_uid: &str, friend_uid: &str) -> Result<(), AppError> {
+        self.close_friend_repository.remove(user_uid, friend_uid).await?;
+        Ok(())
+    }
+
+    /// Membres de la liste d'amis proches, ajouts les plus récents d'abord
+    pub async fn get_close_friends(
+        &self,
+        user_uid: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<User>, AppError> {
+        let limit = limit.clamp(1, 50);
+        let offset = offset.max(0);
+
+        let friend_uids: Vec<String> = self
+            .close_friend_repository
+            .find_by_user(user_uid, limit, offset)
+            .await?
+            .into_iter()
+            .map(|friend| friend.friend_uid)
+            .collect();
+
+        let mut users = self.user_repository.find_by_uids(&friend_uids).await?;
+        users.sort_by_key(|user| friend_uids.iter().position(|uid| *uid == user.uid));
+
+        Ok(users)
+    }
 }
@@ -1,4 +1,7 @@
 use std::env;
+use std::str::FromStr;
+
+use crate::domain::post::{ContentPolicies, ContentPolicy};
 
 #[derive(Debug, Clone)]
 pub struct AppConfig {
@@ -6,6 +9,34 @@ pub struct AppConfig {
     pub allowed_origins: Vec<String>,
     pub database_url: String,
     pub firebase_project_id: String,
+    /// Fenêtre glissante utilisée pour calculer les tendances
+    pub trending_window_minutes: u64,
+    /// Intervalle entre deux recalculs des tendances
+    pub trending_refresh_seconds: u64,
+    /// Durée de validité des suggestions d'abonnement en cache
+    pub suggestions_ttl_minutes: u64,
+    /// Intervalle entre deux passages de recalcul des suggestions expirées
+    pub suggestions_refresh_seconds: u64,
+    /// Intervalle entre deux heartbeats du flux SSE
+    pub sse_heartbeat_seconds: u64,
+    /// Bus d'événements : "postgres" (multi-instances) ou "memory" (instance unique)
+    pub event_bus: String,
+    /// Stockage des médias : "local" (servi sous `/media`) ou "s3"
+    pub media_storage: String,
+    /// Répertoire du stockage local
+    pub media_local_dir: String,
+    /// URL publique de base des médias (par défaut selon le stockage)
+    pub media_public_url: Option<String>,
+    /// Taille maximum d'une image envoyée, en octets
+    pub media_max_bytes: usize,
+    pub s3_bucket: Option<String>,
+    pub s3_region: String,
+    /// Endpoint d'un service compatible S3 (MinIO...), absent pour AWS
+    pub s3_endpoint: Option<String>,
+    pub s3_access_key_id: Option<String>,
+    pub s3_secret_access_key: Option<String>,
+    /// Limites de publication par niveau de compte
+    pub content_policies: ContentPolicies,
 }
 
 impl AppConfig {
@@ -21,6 +52,49 @@ impl AppConfig {
             .map(|s| s.trim().to_string())
             .collect();
 
+        let trending_window_minutes = env::var("TRENDING_WINDOW_MINUTES")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()
+            .expect("TRENDING_WINDOW_MINUTES doit être un nombre valide");
+
+        let trending_refresh_seconds = env::var("TRENDING_REFRESH_SECONDS")
+            .unwrap_or_else(|_| "300".to_string())
+            .parse()
+            .expect("TRENDING_REFRESH_SECONDS doit être un nombre valide");
+
+        let suggestions_ttl_minutes = env::var("SUGGESTIONS_TTL_MINUTES")
+            .unwrap_or_else(|_| "360".to_string())
+            .parse()
+            .expect("SUGGESTIONS_TTL_MINUTES doit être un nombre valide");
+
+        let suggestions_refresh_seconds = env::var("SUGGESTIONS_REFRESH_SECONDS")
+            .unwrap_or_else(|_| "300".to_string())
+            .parse()
+            .expect("SUGGESTIONS_REFRESH_SECONDS doit être un nombre valide");
+
+        let sse_heartbeat_seconds = env::var("SSE_HEARTBEAT_SECONDS")
+            .unwrap_or_else(|_| "15".to_string())
+            .parse()
+            .expect("SSE_HEARTBEAT_SECONDS doit être un nombre valide");
+
+        let event_bus = env::var("EVENT_BUS").unwrap_or_else(|_| "postgres".to_string());
+
+        let media_storage = env::var("MEDIA_STORAGE").unwrap_or_else(|_| "local".to_string());
+        let media_local_dir = env::var("MEDIA_LOCAL_DIR").unwrap_or_else(|_| "./media".to_string());
+        let media_public_url = env::var("MEDIA_PUBLIC_URL").ok();
+        let media_max_bytes = env::var("MEDIA_MAX_BYTES")
+            .unwrap_or_else(|_| "5242880".to_string())
+            .parse()
+            .expect("MEDIA_MAX_BYTES doit être un nombre valide");
+
+        let s3_bucket = env::var("S3_BUCKET").ok();
+        let s3_region = env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string());
+        let s3_endpoint = env::var("S3_ENDPOINT").ok();
+        let s3_access_key_id = env::var("S3_ACCESS_KEY_ID").ok();
+        let s3_secret_access_key = env::var("S3_SECRET_ACCESS_KEY").ok();
+
+        let content_policies = content_policies_from_env();
+
         let database_url = env::var("DATABASE_URL")?;
         let firebase_project_id = env::var("FIREBASE_PROJECT_ID")?;
 
@@ -29,6 +103,64 @@ impl AppConfig {
             allowed_origins,
             database_url,
             firebase_project_id,
+            trending_window_minutes,
+            trending_refresh_seconds,
+            suggestions_ttl_minutes,
+            suggestions_refresh_seconds,
+            sse_heartbeat_seconds,
+            event_bus,
+            media_storage,
+            media_local_dir,
+            media_public_url,
+            media_max_bytes,
+            s3_bucket,
+            s3_region,
+            s3_endpoint,
+            s3_access_key_id,
+            s3_secret_access_key,
+            content_policies,
         })
     }
 }
+
+/// Limites standard (`POST_MAX_LENGTH`, `POST_MAX_ATTACHMENTS`, `POST_MAX_PER_DAY`,
+/// `MESSAGE_MAX_LENGTH`), que les variables préfixées par `VERIFIED_` ou
+/// `STAFF_` remplacent pour les comptes vérifiés ou de l'équipe
+fn content_policies_from_env() -> ContentPolicies {
+    let standard = content_policy_from_env("", ContentPolicy::default());
+    ContentPolicies {
+        standard,
+        verified: content_policy_from_env("VERIFIED_", standard),
+        staff: content_policy_from_env("STAFF_", standard),
+    }
+}
+
+fn content_policy_from_env(prefix: &str, defaults: ContentPolicy) -> ContentPolicy {
+    let policy = ContentPolicy {
+        max_post_length: env_number(&format!("{prefix}POST_MAX_LENGTH"), defaults.max_post_length),
+        max_attachments: env_number(&format!("{prefix}POST_MAX_ATTACHMENTS"), defaults.max_attachments),
+        max_posts_per_day: env_number(&format!("{prefix}POST_MAX_PER_DAY"), defaults.max_posts_per_day),
+        max_message_length: env_number(
+            &format!("{prefix}MESSAGE_MAX_LENGTH"),
+            defaults.max_message_length,
+        ),
+    };
+    assert!(
+        policy.max_post_length > 0,
+        "{prefix}POST_MAX_LENGTH doit être supérieur à 0"
+    );
+    assert!(
+        policy.max_message_length > 0,
+        "{prefix}MESSAGE_MAX_LENGTH doit être supérieur à 0"
+    );
+    policy
+}
+
+fn env_number<T: FromStr>(name: &str, default: T) -> T {
+    match env::var(name) {
+        Ok(value) => value
+            .parse()
+            .unwrap_or_else(|_| panic!("{} doit être un nombre valide", name)),
+        Err(_) => default,
+    }
+}
@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Block {
+    pub blocker_uid: String,
+    pub blocked_uid: String,
+    pub created_at: DateTime<Utc>,
+}
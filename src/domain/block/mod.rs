@@ -0,0 +1,5 @@
+mod entity;
+mod repository;
+
+pub use entity::Block;
+pub use repository::BlockRepository;
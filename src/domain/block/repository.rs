@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use super::Block;
+use crate::error::AppError;
+
+#[async_trait]
+pub trait BlockRepository: Send + Sync {
+    /// Bloque un utilisateur et supprime les abonnements et amis proches dans les deux sens
+    async fn block(&self, blocker_uid: &str, blocked_uid: &str) -> Result<(), AppError>;
+    async fn unblock(&self, blocker_uid: &str, blocked_uid: &str) -> Result<(), AppError>;
+    /// Vrai si l'un des deux utilisateurs a bloqué l'autre
+    async fn is_blocked_between(&self, uid_a: &str, uid_b: &str) -> Result<bool, AppError>;
+    async fn find_by_blocker(&self, blocker_uid: &str, limit: i64, offset: i64) -> Result<Vec<Block>, AppError>;
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Bookmark {
+    pub user_uid: String,
+    pub post_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BookmarkFolder {
+    pub id: Uuid,
+    pub user_uid: String,
+    pub name: String,
+    pub bookmarks_count: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl BookmarkFolder {
+    pub fn new(user_uid: String, name: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_uid,
+            name,
+            bookmarks_count: 0,
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod entity;
+mod repository;
+
+pub use entity::{Bookmark, BookmarkFolder};
+pub use repository::BookmarkRepository;
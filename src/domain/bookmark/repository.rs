@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{Bookmark, BookmarkFolder};
+use crate::error::AppError;
+
+#[async_trait]
+pub trait BookmarkRepository: Send + Sync {
+    /// Crée le bookmark ou le déplace dans `folder_id`
+    async fn save(&self, user_uid: &str, post_id: Uuid, folder_id: Option<Uuid>) -> Result<Bookmark, AppError>;
+    async fn remove(&self, user_uid: &str, post_id: Uuid) -> Result<(), AppError>;
+
+    async fn create_folder(&self, folder: &BookmarkFolder) -> Result<BookmarkFolder, AppError>;
+    async fn find_folder(&self, user_uid: &str, id: Uuid) -> Result<Option<BookmarkFolder>, AppError>;
+    async fn find_folders(&self, user_uid: &str) -> Result<Vec<BookmarkFolder>, AppError>;
+    async fn rename_folder(&self, user_uid: &str, id: Uuid, name: &str) -> Result<BookmarkFolder, AppError>;
+    async fn delete_folder(&self, user_uid: &str, id: Uuid) -> Result<(), AppError>;
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Membre de la liste d'amis proches de `user_uid`, seul à voir ses posts
+/// réservés à cette liste
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CloseFriend {
+    pub user_uid: String,
+    pub friend_uid: String,
+    pub created_at: DateTime<Utc>,
+}
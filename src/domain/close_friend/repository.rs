@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use super::CloseFriend;
+use crate::error::AppError;
+
+#[async_trait]
+pub trait CloseFriendRepository: Send + Sync {
+    /// Retourne `false` si l'utilisateur était déjà dans la liste
+    async fn add(&self, user_uid: &str, friend_uid: &str) -> Result<bool, AppError>;
+    /// Retourne `false` si l'utilisateur n'était pas dans la liste
+    async fn remove(&self, user_uid: &str, friend_uid: &str) -> Result<bool, AppError>;
+    async fn is_close_friend(&self, user_uid: &str, friend_uid: &str) -> Result<bool, AppError>;
+    async fn count(&self, user_uid: &str) -> Result<i64, AppError>;
+    /// Membres de la liste de `user_uid`, ajouts les plus récents d'abord
+    async fn find_by_user(&self, user_uid: &str, limit: i64, offset: i64) -> Result<Vec<CloseFriend>, AppError>;
+}
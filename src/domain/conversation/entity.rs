@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Conversation {
+    pub id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    pub is_group: bool,
+    /// Paire d'utilisateurs triée d'une conversation à deux (absente pour un groupe)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direct_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_message_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Conversation {
+    pub fn direct(created_by: String, other_uid: &str) -> Self {
+        let direct_key = Self::direct_key(&created_by, other_uid);
+        Self {
+            id: Uuid::new_v4(),
+            created_by: Some(created_by),
+            is_group: false,
+            direct_key: Some(direct_key),
+            last_message_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn group(created_by: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created_by: Some(created_by),
+            is_group: true,
+            direct_key: None,
+            last_message_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Clé indépendante de l'ordre des deux utilisateurs
+    pub fn direct_key(a: &str, b: &str) -> String {
+        if a <= b {
+            format!("{}:{}", a, b)
+        } else {
+            format!("{}:{}", b, a)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ConversationParticipant {
+    pub conversation_id: Uuid,
+    pub user_uid: String,
+    /// Accusé de lecture : tous les messages antérieurs ont été lus
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read_at: Option<DateTime<Utc>>,
+    pub joined_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Message {
+    pub id: Uuid,
+    pub conversation_id: Uuid,
+    pub sender_uid: String,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Message {
+    pub fn new(conversation_id: Uuid, sender_uid: String, content: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            conversation_id,
+            sender_uid,
+            content,
+            deleted_at: None,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Nombre de messages non lus d'une conversation pour un participant
+#[derive(Debug, Clone, FromRow)]
+pub struct ConversationUnread {
+    pub conversation_id: Uuid,
+    pub unread_count: i64,
+}
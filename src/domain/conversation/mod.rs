@@ -0,0 +1,5 @@
+mod entity;
+mod repository;
+
+pub use entity::{Conversation, ConversationParticipant, ConversationUnread, Message};
+pub use repository::ConversationRepository;
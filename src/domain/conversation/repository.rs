@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{Conversation, ConversationParticipant, ConversationUnread, Message};
+use crate::error::AppError;
+
+#[async_trait]
+pub trait ConversationRepository: Send + Sync {
+    /// Crée la conversation et ses participants dans une même transaction
+    async fn create(&self, conversation: &Conversation, participant_uids: &[String]) -> Result<Conversation, AppError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Conversation>, AppError>;
+    async fn find_direct(&self, direct_key: &str) -> Result<Option<Conversation>, AppError>;
+    /// Conversations de l'utilisateur, la plus récemment active en premier
+    async fn find_for_user(&self, user_uid: &str, limit: i64, offset: i64) -> Result<Vec<Conversation>, AppError>;
+    async fn find_participants(&self, conversation_ids: &[Uuid]) -> Result<Vec<ConversationParticipant>, AppError>;
+    async fn add_participant(&self, conversation_id: Uuid, user_uid: &str) -> Result<(), AppError>;
+    /// Retourne `false` si l'utilisateur ne participait pas
+    async fn remove_participant(&self, conversation_id: Uuid, user_uid: &str) -> Result<bool, AppError>;
+    async fn is_participant(&self, conversation_id: Uuid, user_uid: &str) -> Result<bool, AppError>;
+    /// Enregistre le message, met à jour l'activité de la conversation et
+    /// l'accusé de lecture de l'expéditeur
+    async fn create_message(&self, message: &Message) -> Result<Message, AppError>;
+    async fn find_message(&self, id: Uuid) -> Result<Option<Message>, AppError>;
+    /// Messages du plus récent au plus ancien
+    async fn find_messages(&self, conversation_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Message>, AppError>;
+    /// Dernier message de chaque conversation
+    async fn find_last_messages(&self, conversation_ids: &[Uuid]) -> Result<Vec<Message>, AppError>;
+    /// Suppression logique : le message reste visible comme supprimé
+    async fn delete_message(&self, id: Uuid) -> Result<(), AppError>;
+    /// Retourne `false` si l'utilisateur ne participe pas à la conversation
+    async fn mark_read(&self, conversation_id: Uuid, user_uid: &str) -> Result<bool, AppError>;
+    async fn count_unread(&self, user_uid: &str, conversation_ids: &[Uuid]) -> Result<Vec<ConversationUnread>, AppError>;
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::poll::NewPoll;
+use crate::domain::post::{Audience, NewLabel, ReplyPolicy};
+
+/// Post en préparation, visible de son seul auteur. Avec `publish_at`,
+/// il est publié automatiquement à cette date.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Draft {
+    pub id: Uuid,
+    pub author_uid: String,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll_options: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll_duration_minutes: Option<i32>,
+    pub poll_hide_results: bool,
+    pub reply_policy: String,
+    pub audience: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_warning: Option<String>,
+    pub is_sensitive: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publish_at: Option<DateTime<Utc>>,
+    /// Publication en cours jusqu'à cette date (réservé par le planificateur)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked_until: Option<DateTime<Utc>>,
+    /// Raison de l'échec de la dernière publication planifiée
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Draft {
+    pub fn new(author_uid: String) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            author_uid,
+            content: String::new(),
+            poll_options: None,
+            poll_duration_minutes: None,
+            poll_hide_results: false,
+            reply_policy: ReplyPolicy::Everyone.as_str().to_string(),
+            audience: Audience::Public.as_str().to_string(),
+            content_warning: None,
+            is_sensitive: false,
+            publish_at: None,
+            locked_until: None,
+            last_error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn poll(&self) -> Option<NewPoll> {
+        self.poll_options.clone().map(|options| NewPoll {
+            options,
+            duration_minutes: self.poll_duration_minutes.unwrap_or_default() as i64,
+            hide_results: self.poll_hide_results,
+        })
+    }
+
+    pub fn set_poll(&mut self, poll: Option<NewPoll>) {
+        self.poll_hide_results = poll.as_ref().is_some_and(|poll| poll.hide_results);
+        self.poll_duration_minutes = poll.as_ref().map(|poll| poll.duration_minutes as i32);
+        self.poll_options = poll.map(|poll| poll.options);
+    }
+
+    /// Avertissement de contenu à poser à la publication
+    pub fn label(&self) -> Option<NewLabel> {
+        (self.content_warning.is_some() || self.is_sensitive).then(|| NewLabel {
+            content_warning: self.content_warning.clone(),
+            sensitive: self.is_sensitive,
+        })
+    }
+
+    pub fn set_label(&mut self, label: Option<NewLabel>) {
+        self.is_sensitive = label.as_ref().is_some_and(|label| label.sensitive);
+        self.content_warning = label.and_then(|label| label.content_warning);
+    }
+
+    pub fn reply_policy(&self) -> ReplyPolicy {
+        ReplyPolicy::parse(&self.reply_policy).unwrap_or(ReplyPolicy::Everyone)
+    }
+
+    pub fn audience(&self) -> Audience {
+        Audience::parse(&self.audience).unwrap_or(Audience::Public)
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked_until.is_some_and(|until| until > Utc::now())
+    }
+}
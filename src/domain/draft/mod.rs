@@ -0,0 +1,5 @@
+mod entity;
+mod repository;
+
+pub use entity::Draft;
+pub use repository::DraftRepository;
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use std::time::Duration;
+use uuid::Uuid;
+
+use super::Draft;
+use crate::domain::media::MediaAttachment;
+use crate::error::AppError;
+
+#[async_trait]
+pub trait DraftRepository: Send + Sync {
+    /// Enregistre le brouillon et lui réserve ses médias, en une seule transaction
+    async fn create(&self, draft: &Draft, attachments: &[MediaAttachment]) -> Result<Draft, AppError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Draft>, AppError>;
+    /// Publications planifiées (les plus proches d'abord), puis brouillons (les plus récents d'abord)
+    async fn find_by_author(&self, author_uid: &str, limit: i64, offset: i64) -> Result<Vec<Draft>, AppError>;
+    /// Met à jour le contenu, le sondage, la date de publication et l'erreur, et
+    /// remplace les médias réservés s'ils sont fournis, en une seule transaction.
+    /// Retourne `None` si le brouillon est en cours de publication.
+    async fn update(&self, draft: &Draft, attachments: Option<&[MediaAttachment]>) -> Result<Option<Draft>, AppError>;
+    async fn delete(&self, id: Uuid) -> Result<(), AppError>;
+    /// Réserve pour `lease` les publications arrivées à échéance. Une publication
+    /// interrompue est reprise une fois la réservation expirée.
+    async fn claim_due(&self, lease: Duration, limit: i64) -> Result<Vec<Draft>, AppError>;
+    /// Repasse une publication échouée en brouillon, avec la raison de l'échec
+    async fn mark_failed(&self, id: Uuid, error: &str) -> Result<(), AppError>;
+}
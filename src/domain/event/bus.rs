@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+use super::{DomainEvent, PublishedEvent};
+use crate::error::AppError;
+
+#[async_trait]
+pub trait EventBus: Send + Sync {
+    /// Publie l'événement pour toutes les instances, y compris celle-ci
+    async fn publish(&self, event: &DomainEvent) -> Result<(), AppError>;
+    /// Flux des événements publiés par toutes les instances, numérotés par le
+    /// bus. Il se termine si la connexion au bus est perdue : l'appelant doit
+    /// alors se réabonner.
+    async fn subscribe(&self) -> Result<mpsc::Receiver<PublishedEvent>, AppError>;
+}
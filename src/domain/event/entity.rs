@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::notification::Notification;
+
+/// Événement métier diffusé à toutes les instances, puis aux clients connectés
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DomainEvent {
+    /// Notification créée ou regroupée pour `recipient_uid`
+    Notification { notification: Notification },
+    /// Nouveau nombre de notifications non lues
+    UnreadCount { recipient_uid: String, count: i64 },
+    /// Post ou réponse publié. Seules les données d'aiguillage sont diffusées
+    /// (hashtags normalisés compris) : les abonnés chargent le post eux-mêmes.
+    PostCreated {
+        post_id: Uuid,
+        author_uid: String,
+        parent_id: Option<Uuid>,
+        root_id: Option<Uuid>,
+        hashtags: Vec<String>,
+    },
+    /// Compteurs d'un post après un like, un retrait de like ou une réponse
+    PostCounters {
+        post_id: Uuid,
+        likes_count: i64,
+        replies_count: i64,
+        reposts_count: i64,
+    },
+}
+
+/// Événement reçu du bus, numéroté par celui-ci. Les identifiants sont
+/// partagés par toutes les instances et croissent dans l'ordre de publication.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishedEvent {
+    pub id: u64,
+    pub event: DomainEvent,
+}
+
+impl DomainEvent {
+    /// Nom de l'événement côté client (champ `event` en SSE)
+    pub fn name(&self) -> &'static str {
+        match self {
+            DomainEvent::Notification { .. } => "notification",
+            DomainEvent::UnreadCount { .. } => "unread_count",
+            DomainEvent::PostCreated { .. } => "post_created",
+            DomainEvent::PostCounters { .. } => "post_counters",
+        }
+    }
+
+    /// Destinataire unique de l'événement, `None` s'il concerne tout le monde
+    pub fn recipient_uid(&self) -> Option<&str> {
+        match self {
+            DomainEvent::Notification { notification } => Some(&notification.recipient_uid),
+            DomainEvent::UnreadCount { recipient_uid, .. } => Some(recipient_uid),
+            DomainEvent::PostCreated { .. } | DomainEvent::PostCounters { .. } => None,
+        }
+    }
+}
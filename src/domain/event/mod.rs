@@ -0,0 +1,5 @@
+mod bus;
+mod entity;
+
+pub use bus::EventBus;
+pub use entity::{DomainEvent, PublishedEvent};
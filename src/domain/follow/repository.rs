@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::error::AppError;
+
+#[async_trait]
+pub trait FollowRepository: Send + Sync {
+    /// Retourne `false` si l'abonnement existait déjà
+    async fn follow(&self, follower_uid: &str, followee_uid: &str) -> Result<bool, AppError>;
+    /// Retourne `false` si l'abonnement n'existait pas
+    async fn unfollow(&self, follower_uid: &str, followee_uid: &str) -> Result<bool, AppError>;
+    async fn is_following(&self, follower_uid: &str, followee_uid: &str) -> Result<bool, AppError>;
+}
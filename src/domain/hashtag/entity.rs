@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Hashtag en tendance, tel que calculé lors du dernier rafraîchissement
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TrendingHashtag {
+    pub tag: String,
+    /// Nombre d'utilisations dans la fenêtre récente
+    pub recent_count: i64,
+    /// Vélocité : utilisation récente rapportée à l'utilisation habituelle
+    pub score: f64,
+    pub computed_at: DateTime<Utc>,
+}
@@ -0,0 +1,5 @@
+mod entity;
+mod repository;
+
+pub use entity::TrendingHashtag;
+pub use repository::HashtagRepository;
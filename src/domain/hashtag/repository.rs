@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+use super::TrendingHashtag;
+use crate::error::AppError;
+
+#[async_trait]
+pub trait HashtagRepository: Send + Sync {
+    /// Recalcule le classement des tendances sur une fenêtre glissante `window`,
+    /// comparée aux `baseline_windows` fenêtres précédentes.
+    async fn refresh_trending(&self, window: Duration, baseline_windows: i64, limit: i64) -> Result<(), AppError>;
+    async fn find_trending(&self, limit: i64) -> Result<Vec<TrendingHashtag>, AppError>;
+}
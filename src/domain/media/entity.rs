@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::ProcessedImage;
+
+/// Nombre maximum de médias attachés à un post, sauf politique de contenu contraire
+pub const MAX_ATTACHMENTS_PER_POST: usize = 4;
+/// Longueur maximum du texte alternatif d'un média
+pub const MAX_ALT_TEXT_CHARS: usize = 1000;
+
+/// Image envoyée par un utilisateur. Sans `post_id` tant qu'elle n'est pas
+/// attachée à un post ; les médias orphelins sont purgés périodiquement.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Media {
+    pub id: Uuid,
+    pub owner_uid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_id: Option<Uuid>,
+    /// Brouillon qui réserve le média jusqu'à sa publication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft_id: Option<Uuid>,
+    /// Clé de l'objet dans le stockage
+    pub storage_key: String,
+    pub url: String,
+    pub mime_type: String,
+    pub width: i32,
+    pub height: i32,
+    pub size_bytes: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt_text: Option<String>,
+    /// Ordre d'affichage dans le post
+    pub position: i16,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Media {
+    pub fn new(owner_uid: String, storage_key: String, url: String, image: &ProcessedImage) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            owner_uid,
+            post_id: None,
+            draft_id: None,
+            storage_key,
+            url,
+            mime_type: image.mime_type.to_string(),
+            width: image.width as i32,
+            height: image.height as i32,
+            size_bytes: image.data.len() as i64,
+            alt_text: None,
+            position: 0,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Média à attacher à un post lors de sa création
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaAttachment {
+    pub id: Uuid,
+    pub alt_text: Option<String>,
+}
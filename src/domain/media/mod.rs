@@ -0,0 +1,9 @@
+mod entity;
+mod processing;
+mod repository;
+mod storage;
+
+pub use entity::{Media, MediaAttachment, MAX_ALT_TEXT_CHARS, MAX_ATTACHMENTS_PER_POST};
+pub use processing::{process_image, process_profile_image, ProcessedImage};
+pub use repository::MediaRepository;
+pub use storage::MediaStorage;
@@ -0,0 +1,194 @@
+use std::io::Cursor;
+
+use image::codecs::gif::{GifDecoder, GifEncoder, Repeat};
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{
+    AnimationDecoder, DynamicImage, ImageDecoder, ImageError, ImageFormat, ImageReader, Limits,
+};
+
+/// Plus grand côté accepté, en pixels
+const MAX_DIMENSION: u32 = 8192;
+/// Nombre maximum de pixels décodés (toutes images d'un GIF animé confondues)
+const MAX_PIXELS: u64 = 100_000_000;
+const JPEG_QUALITY: u8 = 85;
+
+/// Image validée et ré-encodée, sans métadonnées (EXIF, XMP, commentaires)
+#[derive(Debug, Clone)]
+pub struct ProcessedImage {
+    pub data: Vec<u8>,
+    pub mime_type: &'static str,
+    pub extension: &'static str,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Identifie le format d'après le contenu (le type annoncé par le client est
+/// ignoré), vérifie taille et dimensions, puis ré-encode l'image : seuls les
+/// pixels sont conservés. L'orientation EXIF est appliquée avant d'être retirée.
+pub fn process_image(bytes: &[u8], max_bytes: usize) -> Result<ProcessedImage, String> {
+    check_size(bytes, max_bytes)?;
+
+    let format = image::guess_format(bytes)
+        .map_err(|_| "Format non reconnu (JPEG, PNG, GIF ou WebP attendu)".to_string())?;
+
+    match format {
+        ImageFormat::Gif => process_gif(bytes),
+        ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP => {
+            let image = decode_image(bytes, format)?;
+            encode_image(&image, format)
+        }
+        _ => Err("Format non supporté (JPEG, PNG, GIF ou WebP attendu)".into()),
+    }
+}
+
+/// Image de profil : recadrée au centre puis redimensionnée à `width` x `height`.
+/// Seule la première image d'un GIF animé est conservée.
+pub fn process_profile_image(
+    bytes: &[u8],
+    max_bytes: usize,
+    width: u32,
+    height: u32,
+) -> Result<ProcessedImage, String> {
+    check_size(bytes, max_bytes)?;
+
+    let format = image::guess_format(bytes)
+        .map_err(|_| "Format non reconnu (JPEG, PNG, GIF ou WebP attendu)".to_string())?;
+    if !matches!(
+        format,
+        ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::Gif | ImageFormat::WebP
+    ) {
+        return Err("Format non supporté (JPEG, PNG, GIF ou WebP attendu)".into());
+    }
+
+    let image = decode_image(bytes, format)?.resize_to_fill(width, height, FilterType::Lanczos3);
+    // PNG uniquement pour conserver la transparence
+    let target = if image.color().has_alpha() {
+        ImageFormat::Png
+    } else {
+        ImageFormat::Jpeg
+    };
+    encode_image(&image, target)
+}
+
+/// Décode une image fixe en appliquant son orientation EXIF
+fn decode_image(bytes: &[u8], format: ImageFormat) -> Result<DynamicImage, String> {
+    let mut reader = ImageReader::with_format(Cursor::new(bytes), format);
+    reader.limits(decoding_limits());
+
+    let mut decoder = reader.into_decoder().map_err(decode_error)?;
+    let (width, height) = decoder.dimensions();
+    check_dimensions(width, height, 1)?;
+
+    let orientation = decoder.orientation().map_err(decode_error)?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(decode_error)?;
+    image.apply_orientation(orientation);
+
+    Ok(image)
+}
+
+/// JPEG et PNG gardent leur format. Le WebP est converti (l'encodeur WebP
+/// disponible est sans perte, donc bien plus lourd) : PNG s'il a de la
+/// transparence, JPEG sinon.
+fn encode_image(image: &DynamicImage, source: ImageFormat) -> Result<ProcessedImage, String> {
+    let target = match source {
+        ImageFormat::Png => ImageFormat::Png,
+        ImageFormat::WebP if image.color().has_alpha() => ImageFormat::Png,
+        _ => ImageFormat::Jpeg,
+    };
+
+    let mut data = Vec::new();
+    let result = match target {
+        ImageFormat::Png => image.write_to(&mut Cursor::new(&mut data), ImageFormat::Png),
+        _ => JpegEncoder::new_with_quality(&mut data, JPEG_QUALITY)
+            .encode_image(&DynamicImage::ImageRgb8(image.to_rgb8())),
+    };
+    result.map_err(|e| format!("Encodage de l'image impossible: {}", e))?;
+
+    let (mime_type, extension) = match target {
+        ImageFormat::Png => ("image/png", "png"),
+        _ => ("image/jpeg", "jpg"),
+    };
+
+    Ok(ProcessedImage {
+        data,
+        mime_type,
+        extension,
+        width: image.width(),
+        height: image.height(),
+    })
+}
+
+/// Ré-encode toutes les images d'un GIF pour conserver l'animation
+fn process_gif(bytes: &[u8]) -> Result<ProcessedImage, String> {
+    let mut decoder = GifDecoder::new(Cursor::new(bytes)).map_err(decode_error)?;
+    decoder.set_limits(decoding_limits()).map_err(decode_error)?;
+    let (width, height) = decoder.dimensions();
+    check_dimensions(width, height, 1)?;
+
+    let mut frames = Vec::new();
+    for (index, frame) in decoder.into_frames().enumerate() {
+        check_dimensions(width, height, index as u64 + 1)?;
+        frames.push(frame.map_err(decode_error)?);
+    }
+
+    let mut data = Vec::new();
+    {
+        let mut encoder = GifEncoder::new(&mut data);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .and_then(|_| encoder.encode_frames(frames))
+            .map_err(|e| format!("Encodage de l'image impossible: {}", e))?;
+    }
+
+    Ok(ProcessedImage {
+        data,
+        mime_type: "image/gif",
+        extension: "gif",
+        width,
+        height,
+    })
+}
+
+fn check_size(bytes: &[u8], max_bytes: usize) -> Result<(), String> {
+    if bytes.is_empty() {
+        return Err("Le fichier est vide".into());
+    }
+    if bytes.len() > max_bytes {
+        return Err(format!(
+            "Le fichier ne peut pas dépasser {} Mo",
+            max_bytes / (1024 * 1024)
+        ));
+    }
+    Ok(())
+}
+
+fn check_dimensions(width: u32, height: u32, frames: u64) -> Result<(), String> {
+    if width == 0 || height == 0 {
+        return Err("Image vide".into());
+    }
+    if width > MAX_DIMENSION || height > MAX_DIMENSION {
+        return Err(format!(
+            "L'image ne peut pas dépasser {} pixels de côté",
+            MAX_DIMENSION
+        ));
+    }
+    if width as u64 * height as u64 * frames > MAX_PIXELS {
+        return Err("Image trop grande".into());
+    }
+    Ok(())
+}
+
+fn decode_error(error: ImageError) -> String {
+    match error {
+        ImageError::Limits(_) => "Image trop grande".into(),
+        _ => "Image illisible".into(),
+    }
+}
+
+fn decoding_limits() -> Limits {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    limits
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use std::time::Duration;
+use uuid::Uuid;
+
+use super::Media;
+use crate::error::AppError;
+
+#[async_trait]
+pub trait MediaRepository: Send + Sync {
+    async fn create(&self, media: &Media) -> Result<Media, AppError>;
+    /// Médias de `owner_uid` parmi `ids` qui ne sont encore attachés à aucun post,
+    /// ni réservés par un autre brouillon que `draft_id`
+    async fn find_unattached(&self, owner_uid: &str, ids: &[Uuid], draft_id: Option<Uuid>) -> Result<Vec<Media>, AppError>;
+    async fn find_by_posts(&self, post_ids: &[Uuid]) -> Result<Vec<Media>, AppError>;
+    async fn find_by_drafts(&self, draft_ids: &[Uuid]) -> Result<Vec<Media>, AppError>;
+    /// Médias sans post ni brouillon créés il y a plus de `max_age`
+    /// (post supprimé, brouillon annulé ou jamais publié)
+    async fn find_orphans(&self, max_age: Duration, limit: i64) -> Result<Vec<Media>, AppError>;
+    async fn delete(&self, id: Uuid) -> Result<(), AppError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::error::AppError;
+
+/// Stockage des fichiers médias (système de fichiers local, S3...)
+#[async_trait]
+pub trait MediaStorage: Send + Sync {
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &str) -> Result<(), AppError>;
+    /// Ne fait rien si l'objet n'existe pas
+    async fn delete(&self, key: &str) -> Result<(), AppError>;
+    /// URL publique de l'objet
+    fn public_url(&self, key: &str) -> String;
+}
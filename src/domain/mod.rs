@@ -1,2 +1,15 @@
+pub mod block;
+pub mod bookmark;
+pub mod close_friend;
+pub mod conversation;
+pub mod draft;
+pub mod event;
+pub mod follow;
+pub mod hashtag;
+pub mod media;
+pub mod mute;
+pub mod notification;
+pub mod poll;
 pub mod post;
+pub mod suggestion;
 pub mod user;
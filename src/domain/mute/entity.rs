@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MutedUser {
+    pub user_uid: String,
+    pub muted_uid: String,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Fil de discussion masqué, identifié par son post racine
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MutedThread {
+    pub user_uid: String,
+    pub root_post_id: Uuid,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Mot-clé ou expression masqué (comparaison insensible à la casse)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MutedWord {
+    pub id: Uuid,
+    pub user_uid: String,
+    pub phrase: String,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl MutedWord {
+    pub fn new(user_uid: String, phrase: String, expires_at: Option<DateTime<Utc>>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_uid,
+            phrase,
+            expires_at,
+            created_at: Utc::now(),
+        }
+    }
+}
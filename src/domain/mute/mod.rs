@@ -0,0 +1,5 @@
+mod entity;
+mod repository;
+
+pub use entity::{MutedThread, MutedUser, MutedWord};
+pub use repository::MuteRepository;
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::{MutedThread, MutedUser, MutedWord};
+use crate::error::AppError;
+
+/// Les listes ne retournent que les masquages encore actifs
+#[async_trait]
+pub trait MuteRepository: Send + Sync {
+    /// Crée le masquage ou met à jour son expiration
+    async fn mute_user(&self, user_uid: &str, muted_uid: &str, expires_at: Option<DateTime<Utc>>) -> Result<MutedUser, AppError>;
+    async fn unmute_user(&self, user_uid: &str, muted_uid: &str) -> Result<(), AppError>;
+    async fn find_muted_users(&self, user_uid: &str, limit: i64, offset: i64) -> Result<Vec<MutedUser>, AppError>;
+
+    /// Crée le masquage ou met à jour son expiration
+    async fn mute_thread(&self, user_uid: &str, root_post_id: Uuid, expires_at: Option<DateTime<Utc>>) -> Result<MutedThread, AppError>;
+    async fn unmute_thread(&self, user_uid: &str, root_post_id: Uuid) -> Result<(), AppError>;
+    async fn find_muted_threads(&self, user_uid: &str, limit: i64, offset: i64) -> Result<Vec<MutedThread>, AppError>;
+
+    async fn create_word(&self, word: &MutedWord) -> Result<MutedWord, AppError>;
+    async fn find_word(&self, user_uid: &str, id: Uuid) -> Result<Option<MutedWord>, AppError>;
+    async fn update_word(&self, word: &MutedWord) -> Result<MutedWord, AppError>;
+    async fn delete_word(&self, user_uid: &str, id: Uuid) -> Result<(), AppError>;
+    async fn find_words(&self, user_uid: &str) -> Result<Vec<MutedWord>, AppError>;
+    /// Nombre de mots masqués non expirés de `user_uid`
+    async fn count_words(&self, user_uid: &str) -> Result<i64, AppError>;
+
+    /// Vrai si `user_uid` a masqué (masquage actif) l'auteur `actor_uid`,
+    /// le fil `root_post_id` ou un mot présent dans `content`
+    async fn is_muted_for(
+        &self,
+        user_uid: &str,
+        actor_uid: &str,
+        root_post_id: Option<Uuid>,
+        content: Option<&str>,
+    ) -> Result<bool, AppError>;
+}
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationKind {
+    Reply,
+    Mention,
+    Like,
+    Follow,
+}
+
+impl NotificationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationKind::Reply => "reply",
+            NotificationKind::Mention => "mention",
+            NotificationKind::Like => "like",
+            NotificationKind::Follow => "follow",
+        }
+    }
+}
+
+/// Notification groupée : les événements de même type sur une même cible
+/// s'accumulent tant que la notification n'est pas lue.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Notification {
+    pub id: Uuid,
+    pub recipient_uid: String,
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_post_id: Option<Uuid>,
+    pub actors_count: i64,
+    pub is_read: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct NotificationActor {
+    pub notification_id: Uuid,
+    pub actor_uid: String,
+    pub source_post_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Événement à notifier à `recipient_uid`
+#[derive(Debug, Clone)]
+pub struct NotificationEvent {
+    pub recipient_uid: String,
+    pub actor_uid: String,
+    pub kind: NotificationKind,
+    /// Post concerné, qui sert de clé de regroupement (absent pour un abonnement)
+    pub target_post_id: Option<Uuid>,
+    /// Post à l'origine de l'événement (réponse ou post mentionnant)
+    pub source_post_id: Option<Uuid>,
+}
+
+impl NotificationEvent {
+    pub fn group_key(&self) -> String {
+        self.target_post_id
+            .map(|id| id.to_string())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,5 @@
+mod entity;
+mod repository;
+
+pub use entity::{Notification, NotificationActor, NotificationEvent, NotificationKind};
+pub use repository::NotificationRepository;
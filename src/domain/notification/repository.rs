@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{Notification, NotificationActor, NotificationEvent};
+use crate::error::AppError;
+
+#[async_trait]
+pub trait NotificationRepository: Send + Sync {
+    /// Ajoute l'événement à la notification non lue du même groupe, ou en crée une
+    async fn record(&self, event: &NotificationEvent) -> Result<Notification, AppError>;
+    async fn find_by_recipient(&self, recipient_uid: &str, limit: i64, offset: i64) -> Result<Vec<Notification>, AppError>;
+    /// Les `per_notification` acteurs les plus récents de chaque notification
+    async fn find_actors(&self, notification_ids: &[Uuid], per_notification: i64) -> Result<Vec<NotificationActor>, AppError>;
+    async fn count_unread(&self, recipient_uid: &str) -> Result<i64, AppError>;
+    /// Retourne `false` si la notification n'existe pas pour ce destinataire
+    async fn mark_read(&self, recipient_uid: &str, id: Uuid) -> Result<bool, AppError>;
+    async fn mark_all_read(&self, recipient_uid: &str) -> Result<(), AppError>;
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+pub const MIN_POLL_OPTIONS: usize = 2;
+pub const MAX_POLL_OPTIONS: usize = 4;
+/// Longueur maximum du libellé d'une option
+pub const MAX_POLL_OPTION_CHARS: usize = 25;
+/// Durée d'un sondage : de 5 minutes à 7 jours
+pub const MIN_POLL_DURATION_MINUTES: i64 = 5;
+pub const MAX_POLL_DURATION_MINUTES: i64 = 7 * 24 * 60;
+
+/// Sondage attaché à un post. Il est terminé dès `closes_at` passé ;
+/// `closed_at` est renseigné ensuite par la tâche périodique.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Poll {
+    pub post_id: Uuid,
+    pub closes_at: DateTime<Utc>,
+    /// Résultats par option cachés aux non-votants jusqu'à la fin du sondage
+    pub hide_results: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Poll {
+    pub fn new(post_id: Uuid, duration_minutes: i64, hide_results: bool) -> Self {
+        let now = Utc::now();
+        Self {
+            post_id,
+            closes_at: now + Duration::minutes(duration_minutes),
+            hide_results,
+            closed_at: None,
+            created_at: now,
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed_at.is_some() || self.closes_at <= Utc::now()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PollOption {
+    pub post_id: Uuid,
+    /// Ordre d'affichage, à partir de 0
+    pub position: i16,
+    pub label: String,
+    pub votes_count: i64,
+}
+
+/// Option choisie par un utilisateur
+#[derive(Debug, Clone, FromRow)]
+pub struct PollVote {
+    pub post_id: Uuid,
+    pub position: i16,
+}
+
+/// Sondage à créer avec un post
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewPoll {
+    pub options: Vec<String>,
+    #[serde(default = "default_duration_minutes")]
+    pub duration_minutes: i64,
+    #[serde(default)]
+    pub hide_results: bool,
+}
+
+fn default_duration_minutes() -> i64 {
+    24 * 60
+}
+
+impl NewPoll {
+    /// Sondage et options à enregistrer avec le post `post_id`
+    pub fn build(&self, post_id: Uuid) -> (Poll, Vec<PollOption>) {
+        let options = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(position, label)| PollOption {
+                post_id,
+                position: position as i16,
+                label: label.clone(),
+                votes_count: 0,
+            })
+            .collect();
+
+        (Poll::new(post_id, self.duration_minutes, self.hide_results), options)
+    }
+}
@@ -0,0 +1,8 @@
+mod entity;
+mod repository;
+
+pub use entity::{
+    NewPoll, Poll, PollOption, PollVote, MAX_POLL_DURATION_MINUTES, MAX_POLL_OPTIONS,
+    MAX_POLL_OPTION_CHARS, MIN_POLL_DURATION_MINUTES, MIN_POLL_OPTIONS,
+};
+pub use repository::PollRepository;
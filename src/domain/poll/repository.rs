@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{Poll, PollOption, PollVote};
+use crate::error::AppError;
+
+#[async_trait]
+pub trait PollRepository: Send + Sync {
+    async fn find_by_posts(&self, post_ids: &[Uuid]) -> Result<Vec<Poll>, AppError>;
+    /// Options des sondages, triées par post puis par position
+    async fn find_options(&self, post_ids: &[Uuid]) -> Result<Vec<PollOption>, AppError>;
+    async fn find_votes(&self, user_uid: &str, post_ids: &[Uuid]) -> Result<Vec<PollVote>, AppError>;
+    /// Enregistre le vote et incrémente le compteur de l'option. Retourne `false`
+    /// si l'utilisateur a déjà voté ou si le sondage est terminé.
+    async fn vote(&self, post_id: Uuid, user_uid: &str, position: i16) -> Result<bool, AppError>;
+    /// Marque comme terminés les sondages expirés et retourne leurs posts
+    async fn close_expired(&self, limit: i64) -> Result<Vec<Uuid>, AppError>;
+}
@@ -15,6 +15,15 @@ pub struct Post {
     pub updated_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_id: Option<Uuid>,
+    /// Post racine du fil de discussion (absent pour un post racine)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root_id: Option<Uuid>,
+    /// Qui peut répondre au fil, pour un post racine (voir `ReplyPolicy`)
+    pub reply_policy: String,
+    /// Réponse masquée par l'auteur du post racine
+    pub is_hidden: bool,
+    /// Qui peut voir le post (voir `Audience`)
+    pub audience: String,
 }
 
 impl Post {
@@ -30,12 +39,122 @@ impl Post {
             created_at: now,
             updated_at: now,
             parent_id: None,
+            root_id: None,
+            reply_policy: ReplyPolicy::Everyone.as_str().to_string(),
+            is_hidden: false,
+            audience: Audience::Public.as_str().to_string(),
         }
     }
 
-    pub fn reply(author_uid: String, content: String, parent_id: Uuid) -> Self {
+    pub fn reply(author_uid: String, content: String, parent: &Post) -> Self {
         let mut post = Self::new(author_uid, content);
-        post.parent_id = Some(parent_id);
+        post.parent_id = Some(parent.id);
+        post.root_id = Some(parent.thread_id());
         post
     }
+
+    /// Identifiant du fil de discussion auquel appartient le post
+    pub fn thread_id(&self) -> Uuid {
+        self.root_id.unwrap_or(self.id)
+    }
+
+    pub fn reply_policy(&self) -> ReplyPolicy {
+        ReplyPolicy::parse(&self.reply_policy).unwrap_or(ReplyPolicy::Everyone)
+    }
+
+    pub fn audience(&self) -> Audience {
+        Audience::parse(&self.audience).unwrap_or(Audience::Public)
+    }
+}
+
+/// Qui peut répondre à un fil de discussion, choisi par l'auteur du post racine.
+/// L'auteur peut toujours répondre.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReplyPolicy {
+    #[default]
+    Everyone,
+    /// Seuls les comptes que l'auteur suit
+    Following,
+    /// Seuls les utilisateurs mentionnés dans le post
+    Mentioned,
+}
+
+impl ReplyPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReplyPolicy::Everyone => "everyone",
+            ReplyPolicy::Following => "following",
+            ReplyPolicy::Mentioned => "mentioned",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "everyone" => Some(ReplyPolicy::Everyone),
+            "following" => Some(ReplyPolicy::Following),
+            "mentioned" => Some(ReplyPolicy::Mentioned),
+            _ => None,
+        }
+    }
+}
+
+/// Qui peut voir un post, choisi par son auteur. L'auteur le voit toujours ;
+/// une réponse n'est visible que si son post racine l'est aussi.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Audience {
+    #[default]
+    Public,
+    /// Seuls les abonnés de l'auteur
+    Followers,
+    /// Seuls les membres de la liste d'amis proches de l'auteur
+    CloseFriends,
+}
+
+impl Audience {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Audience::Public => "public",
+            Audience::Followers => "followers",
+            Audience::CloseFriends => "close_friends",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "public" => Some(Audience::Public),
+            "followers" => Some(Audience::Followers),
+            "close_friends" => Some(Audience::CloseFriends),
+            _ => None,
+        }
+    }
+}
+
+/// État d'un post du point de vue de l'utilisateur connecté
+#[derive(Debug, Clone, FromRow)]
+pub struct PostViewerState {
+    pub post_id: Uuid,
+    pub liked: bool,
+    pub reposted: bool,
+    pub bookmarked: bool,
+}
+
+/// Mention résolue d'un utilisateur dans un post (positions en caractères)
+#[derive(Debug, Clone, FromRow)]
+pub struct PostMention {
+    pub post_id: Uuid,
+    pub mentioned_uid: String,
+    pub username: String,
+    pub start_offset: i32,
+    pub end_offset: i32,
+}
+
+/// Hashtag d'un post, normalisé en minuscules (positions en caractères)
+#[derive(Debug, Clone, FromRow)]
+pub struct PostHashtag {
+    pub post_id: Uuid,
+    pub tag: String,
+    pub start_offset: i32,
+    pub end_offset: i32,
 }
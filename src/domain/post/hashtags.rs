@@ -0,0 +1,111 @@
+use unicode_normalization::UnicodeNormalization;
+
+/// Hashtag `#tag` repéré dans le texte d'un post, normalisé en minuscules.
+/// Les positions sont en caractères (et non en octets), fin exclue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashtagCandidate {
+    pub tag: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+const MAX_HASHTAG_LEN: usize = 100;
+
+fn is_hashtag_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Normalise un hashtag pour la comparaison : sans `#`, en NFC et en minuscules
+pub fn normalize_hashtag(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').nfc().collect::<String>().to_lowercase()
+}
+
+/// Extrait les hashtags du contenu. Un `#` collé à un mot n'ouvre pas de
+/// hashtag, et un hashtag doit contenir au moins une lettre (`#1` est ignoré).
+pub fn extract_hashtags(content: &str) -> Vec<HashtagCandidate> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut hashtags = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let preceded_by_word = i > 0 && is_hashtag_char(chars[i - 1]);
+        if chars[i] != '#' || preceded_by_word {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let mut end = i + 1;
+        while end < chars.len() && is_hashtag_char(chars[end]) {
+            end += 1;
+        }
+
+        let body = &chars[start + 1..end];
+        if body.iter().any(|c| c.is_alphabetic()) {
+            // La longueur est vérifiée après normalisation : la mise en
+            // minuscules peut allonger certains caractères (`İ` devient `i̇`)
+            let tag = normalize_hashtag(&body.iter().collect::<String>());
+            if tag.chars().count() <= MAX_HASHTAG_LEN {
+                hashtags.push(HashtagCandidate { tag, start, end });
+            }
+        }
+
+        i = end.max(i + 1);
+    }
+
+    hashtags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(content: &str) -> Vec<String> {
+        extract_hashtags(content).into_iter().map(|h| h.tag).collect()
+    }
+
+    #[test]
+    fn extrait_et_normalise_les_hashtags() {
+        assert_eq!(tags("#Rust et #rust_lang, #Été!"), ["rust", "rust_lang", "été"]);
+        assert_eq!(tags("#CAFÉ"), ["café"]);
+    }
+
+    #[test]
+    fn positions_en_caracteres() {
+        assert_eq!(
+            extract_hashtags("été #ok"),
+            [HashtagCandidate {
+                tag: "ok".into(),
+                start: 4,
+                end: 7,
+            }]
+        );
+    }
+
+    #[test]
+    fn ignore_les_faux_hashtags() {
+        assert!(tags("#").is_empty());
+        assert!(tags("# rust").is_empty());
+        assert!(tags("#1 #2024").is_empty());
+        assert!(tags("C# page#ancre").is_empty());
+        assert_eq!(tags("##rust"), ["rust"]);
+        assert_eq!(tags("#2024_jo"), ["2024_jo"]);
+    }
+
+    #[test]
+    fn limite_la_longueur_apres_normalisation() {
+        assert_eq!(tags(&format!("#{}", "a".repeat(MAX_HASHTAG_LEN))).len(), 1);
+        assert!(tags(&format!("#{}", "a".repeat(MAX_HASHTAG_LEN + 1))).is_empty());
+
+        // `İ` (1 caractère) donne `i̇` (2 caractères) en minuscules
+        let expanding = format!("#{}", "İ".repeat(60));
+        assert!(tags(&expanding).is_empty());
+        assert_eq!(tags(&format!("#{}", "İ".repeat(50))).len(), 1);
+    }
+
+    #[test]
+    fn normalise_un_hashtag_saisi() {
+        assert_eq!(normalize_hashtag("  #RuSt "), "rust");
+        assert_eq!(normalize_hashtag("Cafe\u{301}"), "café");
+    }
+}
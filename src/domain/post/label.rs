@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Longueur maximum du texte d'un avertissement de contenu
+pub const MAX_CONTENT_WARNING_CHARS: usize = 100;
+
+/// Origine d'un avertissement : un post porte au plus un avertissement de
+/// chaque origine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelSource {
+    /// Posé par l'auteur du post
+    Author,
+    /// Posé par un membre de l'équipe sur le post d'un autre
+    Moderator,
+}
+
+impl LabelSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LabelSource::Author => "author",
+            LabelSource::Moderator => "moderator",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "author" => Some(LabelSource::Author),
+            "moderator" => Some(LabelSource::Moderator),
+            _ => None,
+        }
+    }
+}
+
+/// Avertissement de contenu posé sur un post
+#[derive(Debug, Clone, FromRow)]
+pub struct PostLabel {
+    pub post_id: Uuid,
+    pub source: String,
+    pub content_warning: Option<String>,
+    pub is_sensitive: bool,
+    pub labeled_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PostLabel {
+    pub fn new(post_id: Uuid, source: LabelSource, label: NewLabel, labeled_by: String) -> Self {
+        Self {
+            post_id,
+            source: source.as_str().to_string(),
+            content_warning: label.content_warning,
+            is_sensitive: label.sensitive,
+            labeled_by: Some(labeled_by),
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn source(&self) -> LabelSource {
+        LabelSource::parse(&self.source).unwrap_or(LabelSource::Author)
+    }
+}
+
+/// Avertissement à poser : un texte affiché à la place du post, un marquage
+/// « sensible » (médias à flouter), ou les deux
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewLabel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_warning: Option<String>,
+    #[serde(default)]
+    pub sensitive: bool,
+}
+
+impl NewLabel {
+    /// Normalise le texte de l'avertissement. Retourne `None` si l'avertissement
+    /// est vide (ni texte ni marquage sensible).
+    pub fn normalize(self) -> Result<Option<Self>, String> {
+        let content_warning = self
+            .content_warning
+            .map(|warning| warning.trim().to_string())
+            .filter(|warning| !warning.is_empty());
+
+        if content_warning
+            .as_ref()
+            .is_some_and(|warning| warning.chars().count() > MAX_CONTENT_WARNING_CHARS)
+        {
+            return Err(format!(
+                "L'avertissement ne peut pas dépasser {} caractères",
+                MAX_CONTENT_WARNING_CHARS
+            ));
+        }
+
+        if content_warning.is_none() && !self.sensitive {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            content_warning,
+            sensitive: self.sensitive,
+        }))
+    }
+}
@@ -0,0 +1,116 @@
+/// Mention `@username` repérée dans le texte d'un post.
+/// Les positions sont en caractères (et non en octets), fin exclue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MentionCandidate {
+    pub username: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+const MIN_USERNAME_LEN: usize = 3;
+const MAX_USERNAME_LEN: usize = 30;
+
+fn is_username_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Extrait les mentions du contenu. Un `@` précédé d'une lettre, d'un chiffre
+/// ou d'un underscore (adresse email par exemple) n'est pas une mention, et
+/// seuls les usernames valides (3 à 30 caractères) sont retenus.
+pub fn extract_mentions(content: &str) -> Vec<MentionCandidate> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut mentions = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let preceded_by_word = i > 0 && (chars[i - 1].is_alphanumeric() || chars[i - 1] == '_');
+        if chars[i] != '@' || preceded_by_word {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let mut end = i + 1;
+        while end < chars.len() && is_username_char(chars[end]) {
+            end += 1;
+        }
+
+        let len = end - start - 1;
+        if (MIN_USERNAME_LEN..=MAX_USERNAME_LEN).contains(&len) {
+            mentions.push(MentionCandidate {
+                username: chars[start + 1..end].iter().collect(),
+                start,
+                end,
+            });
+        }
+
+        i = end.max(i + 1);
+    }
+
+    mentions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usernames(content: &str) -> Vec<String> {
+        extract_mentions(content).into_iter().map(|m| m.username).collect()
+    }
+
+    #[test]
+    fn extrait_les_mentions() {
+        assert_eq!(usernames("@alice et @bob_42 !"), ["alice", "bob_42"]);
+        assert_eq!(usernames("(@alice)"), ["alice"]);
+    }
+
+    #[test]
+    fn ignore_les_adresses_email() {
+        assert!(usernames("alice@example.com").is_empty());
+        assert!(usernames("a_@bob").is_empty());
+        assert_eq!(usernames("écrire à contact@example.com ou @support"), ["support"]);
+    }
+
+    #[test]
+    fn laisse_la_ponctuation_finale_au_texte() {
+        assert_eq!(usernames("merci @alice."), ["alice"]);
+        assert_eq!(usernames("@alice, @bob: @carol!"), ["alice", "bob", "carol"]);
+        assert_eq!(usernames("@alice-bob"), ["alice"]);
+    }
+
+    #[test]
+    fn garde_la_casse_et_les_doublons_avec_leur_position() {
+        // La déduplication et la comparaison sans casse se font à la résolution
+        assert_eq!(
+            extract_mentions("@Alice @alice"),
+            [
+                MentionCandidate {
+                    username: "Alice".into(),
+                    start: 0,
+                    end: 6,
+                },
+                MentionCandidate {
+                    username: "alice".into(),
+                    start: 7,
+                    end: 13,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn positions_en_caracteres() {
+        let mentions = extract_mentions("été @bob");
+        assert_eq!((mentions[0].start, mentions[0].end), (4, 8));
+    }
+
+    #[test]
+    fn respecte_la_longueur_des_usernames() {
+        assert!(usernames("@ab").is_empty());
+        assert_eq!(usernames("@abc"), ["abc"]);
+        assert_eq!(usernames(&format!("@{}", "a".repeat(MAX_USERNAME_LEN))).len(), 1);
+        assert!(usernames(&format!("@{}", "a".repeat(MAX_USERNAME_LEN + 1))).is_empty());
+        assert!(usernames("@ @@ @").is_empty());
+        assert!(usernames("@élodie").is_empty());
+    }
+}
@@ -1,5 +1,19 @@
 mod entity;
+mod hashtags;
+mod label;
+mod mentions;
+mod policy;
+mod publication;
 mod repository;
+mod search;
+mod text;
 
-pub use entity::Post;
+pub use entity::{Audience, Post, PostHashtag, PostMention, PostViewerState, ReplyPolicy};
+pub use hashtags::{extract_hashtags, normalize_hashtag, HashtagCandidate};
+pub use label::{LabelSource, NewLabel, PostLabel, MAX_CONTENT_WARNING_CHARS};
+pub use mentions::{extract_mentions, MentionCandidate};
+pub use policy::{ContentPolicies, ContentPolicy};
+pub use publication::Publication;
 pub use repository::PostRepository;
+pub use search::{PostSearchHit, PostSearchQuery, SearchCursor, SearchSort};
+pub use text::{extract_urls, PostText, TextEntity, UrlCandidate, MAX_CONTENT_BYTES, URL_WEIGHT};
@@ -0,0 +1,45 @@
+use serde::Serialize;
+
+use crate::domain::media::MAX_ATTACHMENTS_PER_POST;
+use crate::domain::user::AccountTier;
+
+/// Limites de publication d'un niveau de compte
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ContentPolicy {
+    /// En graphèmes, chaque URL comptant pour `URL_WEIGHT`
+    pub max_post_length: usize,
+    pub max_attachments: usize,
+    /// Posts et réponses publiés sur 24 heures glissantes
+    pub max_posts_per_day: i64,
+    /// Longueur d'un message privé, comptée comme celle d'un post
+    pub max_message_length: usize,
+}
+
+impl Default for ContentPolicy {
+    fn default() -> Self {
+        Self {
+            max_post_length: 280,
+            max_attachments: MAX_ATTACHMENTS_PER_POST,
+            max_posts_per_day: 2400,
+            max_message_length: 280,
+        }
+    }
+}
+
+/// Limites de publication de chaque niveau de compte, chargées depuis la configuration
+#[derive(Debug, Clone, Default)]
+pub struct ContentPolicies {
+    pub standard: ContentPolicy,
+    pub verified: ContentPolicy,
+    pub staff: ContentPolicy,
+}
+
+impl ContentPolicies {
+    pub fn for_tier(&self, tier: AccountTier) -> &ContentPolicy {
+        match tier {
+            AccountTier::Standard => &self.standard,
+            AccountTier::Verified => &self.verified,
+            AccountTier::Staff => &self.staff,
+        }
+    }
+}
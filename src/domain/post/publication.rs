@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+use super::{Post, PostHashtag, PostLabel, PostMention};
+use crate::domain::media::MediaAttachment;
+use crate::domain::poll::{Poll, PollOption};
+
+/// Post à publier avec tout ce qui en dépend, enregistré en une seule
+/// transaction : un échec n'en laisse rien
+#[derive(Debug, Clone)]
+pub struct Publication {
+    pub post: Post,
+    /// Médias déjà validés, dans l'ordre d'affichage
+    pub attachments: Vec<MediaAttachment>,
+    pub poll: Option<(Poll, Vec<PollOption>)>,
+    /// Avertissement posé par l'auteur
+    pub label: Option<PostLabel>,
+    pub mentions: Vec<PostMention>,
+    pub hashtags: Vec<PostHashtag>,
+    /// Brouillon publié : ses médias réservés peuvent être attachés, et il est
+    /// supprimé dans la même transaction
+    pub draft_id: Option<Uuid>,
+}
@@ -1,18 +1,71 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use super::Post;
+use super::{
+    LabelSource, Post, PostHashtag, PostLabel, PostMention, PostSearchHit, PostSearchQuery,
+    PostViewerState, Publication, SearchCursor, SearchSort,
+};
 use crate::error::AppError;
 
 #[async_trait]
 pub trait PostRepository: Send + Sync {
-    async fn create(&self, post: &Post) -> Result<Post, AppError>;
+    /// Enregistre le post, attache ses médias et crée son sondage, son avertissement,
+    /// ses mentions et ses hashtags, et supprime le brouillon publié, en une seule
+    /// transaction. Échoue sans rien enregistrer si un média a été attaché ou
+    /// réservé entre-temps, ou si le brouillon n'existe plus.
+    async fn create(&self, publication: &Publication) -> Result<Post, AppError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Post>, AppError>;
-    async fn find_by_author(&self, author_uid: &str, limit: i64, offset: i64) -> Result<Vec<Post>, AppError>;
-    async fn get_timeline(&self, limit: i64, offset: i64) -> Result<Vec<Post>, AppError>;
-    async fn get_replies(&self, parent_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Post>, AppError>;
+    /// Vrai si l'audience du post, et celle de son post racine, incluent `viewer_uid`,
+    /// et si l'auteur n'est pas un compte privé dont `viewer_uid` n'est pas abonné
+    async fn is_visible_to(&self, id: Uuid, viewer_uid: Option<&str>) -> Result<bool, AppError>;
+    /// Vrai si le post peut être poussé en direct à `viewer_uid` : audience, compte
+    /// privé, blocages, masquages et avertissements cachés vérifiés en une requête
+    async fn is_deliverable_to(&self, id: Uuid, viewer_uid: &str) -> Result<bool, AppError>;
+    /// Les listes excluent les posts dont l'audience n'inclut pas `viewer_uid`, ceux
+    /// des comptes privés qu'il ne suit pas et ceux des auteurs bloqués par ou
+    /// bloquant `viewer_uid`.
+    /// Toutes excluent les posts avec avertissement si `viewer_uid` a choisi de
+    /// les cacher ; la timeline et les réponses excluent aussi ce qu'il a masqué.
+    /// Les posts d'un auteur omettent `excluded_id`, son post épinglé affiché à part.
+    async fn find_by_author(&self, author_uid: &str, viewer_uid: Option<&str>, excluded_id: Option<Uuid>, limit: i64, offset: i64) -> Result<Vec<Post>, AppError>;
+    async fn get_timeline(&self, viewer_uid: Option<&str>, limit: i64, offset: i64) -> Result<Vec<Post>, AppError>;
+    /// Les réponses masquées par l'auteur du fil viennent en dernier
+    async fn get_replies(&self, parent_id: Uuid, viewer_uid: Option<&str>, limit: i64, offset: i64) -> Result<Vec<Post>, AppError>;
+    /// Posts mis en favori par `user_uid` (plus récents d'abord), éventuellement filtrés par dossier.
+    /// Les posts supprimés disparaissent d'eux-mêmes (suppression en cascade).
+    async fn find_bookmarked(&self, user_uid: &str, folder_id: Option<Uuid>, limit: i64, offset: i64) -> Result<Vec<Post>, AppError>;
+    /// Posts et réponses publiés par `author_uid` depuis `since`
+    async fn count_by_author_since(&self, author_uid: &str, since: DateTime<Utc>) -> Result<i64, AppError>;
     async fn delete(&self, id: Uuid) -> Result<(), AppError>;
     async fn increment_likes(&self, id: Uuid) -> Result<(), AppError>;
     async fn decrement_likes(&self, id: Uuid) -> Result<(), AppError>;
     async fn increment_replies(&self, id: Uuid) -> Result<(), AppError>;
+    async fn set_hidden(&self, id: Uuid, hidden: bool) -> Result<(), AppError>;
+    /// Retourne `false` si le like existait déjà
+    async fn add_like(&self, user_uid: &str, post_id: Uuid) -> Result<bool, AppError>;
+    /// Retourne `false` si le like n'existait pas
+    async fn remove_like(&self, user_uid: &str, post_id: Uuid) -> Result<bool, AppError>;
+    async fn find_mentions(&self, post_ids: &[Uuid]) -> Result<Vec<PostMention>, AppError>;
+    /// Posts mentionnant `mentioned_uid`, plus récents d'abord
+    async fn find_mentioning(&self, mentioned_uid: &str, viewer_uid: Option<&str>, limit: i64, offset: i64) -> Result<Vec<Post>, AppError>;
+    async fn find_hashtags(&self, post_ids: &[Uuid]) -> Result<Vec<PostHashtag>, AppError>;
+    /// Posts contenant le hashtag normalisé `tag`, plus récents d'abord
+    async fn find_by_hashtag(&self, tag: &str, viewer_uid: Option<&str>, limit: i64, offset: i64) -> Result<Vec<Post>, AppError>;
+    /// Remplace l'avertissement de même origine
+    async fn set_label(&self, label: &PostLabel) -> Result<PostLabel, AppError>;
+    /// Retourne `false` si le post n'avait pas d'avertissement de cette origine
+    async fn remove_label(&self, post_id: Uuid, source: LabelSource) -> Result<bool, AppError>;
+    async fn find_labels(&self, post_ids: &[Uuid]) -> Result<Vec<PostLabel>, AppError>;
+    async fn get_viewer_states(&self, viewer_uid: &str, post_ids: &[Uuid]) -> Result<Vec<PostViewerState>, AppError>;
+    /// Recherche plein texte, en reprenant après `cursor`. Exclut aussi les
+    /// posts des comptes privés que `viewer_uid` ne suit pas.
+    async fn search(
+        &self,
+        query: &PostSearchQuery,
+        sort: SearchSort,
+        cursor: Option<&SearchCursor>,
+        viewer_uid: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<PostSearchHit>, AppError>;
 }
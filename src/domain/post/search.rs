@@ -0,0 +1,291 @@
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::Post;
+
+/// Ordre des résultats de recherche
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchSort {
+    #[default]
+    Relevance,
+    Recent,
+}
+
+/// Recherche de posts analysée depuis la syntaxe utilisateur :
+/// texte libre (avec `"phrases exactes"`, `OR` et `-exclusion`), `from:username`,
+/// `since:AAAA-MM-JJ`, `until:AAAA-MM-JJ` (jour inclus) et `has:replies`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PostSearchQuery {
+    /// Texte passé à `websearch_to_tsquery`, absent s'il n'y a que des filtres
+    pub text: Option<String>,
+    pub from_username: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub has_replies: bool,
+}
+
+impl PostSearchQuery {
+    pub fn parse(query: &str) -> Result<Self, String> {
+        let mut parsed = PostSearchQuery::default();
+        let mut terms = Vec::new();
+
+        for token in tokenize(query) {
+            if let Some(username) = token.strip_prefix("from:") {
+                let username = username.trim_start_matches('@');
+                if username.is_empty() {
+                    return Err("Nom d'utilisateur manquant après from:".into());
+                }
+                parsed.from_username = Some(username.to_string());
+            } else if let Some(date) = token.strip_prefix("since:") {
+                parsed.since = Some(start_of_day(parse_date(date)?));
+            } else if let Some(date) = token.strip_prefix("until:") {
+                let day = parse_date(date)?
+                    .checked_add_days(Days::new(1))
+                    .ok_or_else(|| format!("Date invalide: {}", date))?;
+                parsed.until = Some(start_of_day(day));
+            } else if let Some(filter) = token.strip_prefix("has:") {
+                match filter {
+                    "replies" => parsed.has_replies = true,
+                    _ => return Err(format!("Filtre inconnu: has:{}", filter)),
+                }
+            } else {
+                terms.push(token);
+            }
+        }
+
+        if terms.iter().any(|term| term.chars().any(char::is_alphanumeric)) {
+            parsed.text = Some(terms.join(" "));
+        }
+
+        if parsed.text.is_none() && parsed.from_username.is_none() {
+            return Err("La recherche doit contenir du texte ou un filtre from:".into());
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Découpe sur les espaces en gardant les phrases entre guillemets entières
+fn tokenize(query: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for c in query.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(c);
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("Date invalide (format AAAA-MM-JJ attendu): {}", value))
+}
+
+fn start_of_day(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0)
+        .expect("minuit est toujours valide")
+        .and_utc()
+}
+
+/// Position dans les résultats : score (pertinence) ou date (récence), puis
+/// identifiant pour départager. Sérialisé en chaîne opaque pour le client.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchCursor {
+    Relevance { rank: f32, id: Uuid },
+    Recent { created_at: DateTime<Utc>, id: Uuid },
+}
+
+impl SearchCursor {
+    /// Curseur placé juste après `hit` dans l'ordre `sort`
+    pub fn after(hit: &PostSearchHit, sort: SearchSort) -> Self {
+        match sort {
+            SearchSort::Relevance => SearchCursor::Relevance {
+                rank: hit.rank,
+                id: hit.post.id,
+            },
+            SearchSort::Recent => SearchCursor::Recent {
+                created_at: hit.post.created_at,
+                id: hit.post.id,
+            },
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        match self {
+            SearchCursor::Relevance { rank, id } => format!("r{}_{}", rank, id),
+            SearchCursor::Recent { created_at, id } => {
+                format!("t{}_{}", created_at.timestamp_micros(), id)
+            }
+        }
+    }
+
+    pub fn decode(cursor: &str, sort: SearchSort) -> Option<Self> {
+        let (kind, rest) = cursor.split_at_checked(1)?;
+        let (value, id) = rest.split_once('_')?;
+        let id = Uuid::parse_str(id).ok()?;
+
+        match (kind, sort) {
+            ("r", SearchSort::Relevance) => Some(SearchCursor::Relevance {
+                rank: value.parse().ok()?,
+                id,
+            }),
+            ("t", SearchSort::Recent) => Some(SearchCursor::Recent {
+                created_at: DateTime::from_timestamp_micros(value.parse().ok()?)?,
+                id,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Post trouvé par une recherche, avec son score de pertinence
+#[derive(Debug, Clone, FromRow)]
+pub struct PostSearchHit {
+    #[sqlx(flatten)]
+    pub post: Post,
+    pub rank: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn decoupe_en_gardant_les_phrases_entre_guillemets() {
+        assert_eq!(
+            tokenize("  rust \"borrow  checker\"\t-java OR go "),
+            ["rust", "\"borrow  checker\"", "-java", "OR", "go"]
+        );
+        assert_eq!(tokenize("\"non fermée"), ["\"non fermée"]);
+        assert!(tokenize(" \n ").is_empty());
+    }
+
+    #[test]
+    fn garde_le_texte_libre_et_les_phrases() {
+        let query = PostSearchQuery::parse("\"rust async\" tokio -java").unwrap();
+
+        assert_eq!(
+            query,
+            PostSearchQuery {
+                text: Some("\"rust async\" tokio -java".into()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn analyse_from() {
+        let query = PostSearchQuery::parse("from:@alice").unwrap();
+        assert_eq!(query.from_username.as_deref(), Some("alice"));
+        assert_eq!(query.text, None);
+
+        assert!(PostSearchQuery::parse("from:").is_err());
+        assert!(PostSearchQuery::parse("from:@ rust").is_err());
+    }
+
+    #[test]
+    fn until_inclut_le_jour_donne() {
+        let query = PostSearchQuery::parse("rust since:2024-03-01 until:2024-03-31").unwrap();
+
+        assert_eq!(query.since, Some(Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()));
+        assert_eq!(query.until, Some(Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()));
+        assert_eq!(query.text.as_deref(), Some("rust"));
+
+        assert!(PostSearchQuery::parse("rust since:2024-02-30").is_err());
+        assert!(PostSearchQuery::parse("rust until:01/03/2024").is_err());
+    }
+
+    #[test]
+    fn analyse_has() {
+        assert!(PostSearchQuery::parse("rust has:replies").unwrap().has_replies);
+        assert!(!PostSearchQuery::parse("rust").unwrap().has_replies);
+        assert!(PostSearchQuery::parse("rust has:media").is_err());
+    }
+
+    #[test]
+    fn exige_du_texte_ou_from() {
+        assert!(PostSearchQuery::parse("").is_err());
+        assert!(PostSearchQuery::parse("since:2024-01-01 has:replies").is_err());
+        assert!(PostSearchQuery::parse("- \"\"").is_err());
+        assert!(PostSearchQuery::parse("from:alice since:2024-01-01").is_ok());
+    }
+
+    #[test]
+    fn le_curseur_de_pertinence_conserve_le_score() {
+        let id = Uuid::new_v4();
+        for rank in [0.0_f32, 0.1, 0.060_793_52, 1.0e-7, 123.456, f32::MAX] {
+            let cursor = SearchCursor::Relevance { rank, id };
+            let decoded = SearchCursor::decode(&cursor.encode(), SearchSort::Relevance);
+            assert_eq!(decoded, Some(cursor), "{}", rank);
+        }
+    }
+
+    #[test]
+    fn le_curseur_de_recence_conserve_la_date() {
+        let cursor = SearchCursor::Recent {
+            created_at: DateTime::from_timestamp_micros(1_700_000_000_123_456).unwrap(),
+            id: Uuid::new_v4(),
+        };
+
+        assert_eq!(SearchCursor::decode(&cursor.encode(), SearchSort::Recent), Some(cursor));
+    }
+
+    #[test]
+    fn rejette_un_curseur_invalide_ou_d_un_autre_tri() {
+        let cursor = SearchCursor::Relevance {
+            rank: 0.5,
+            id: Uuid::new_v4(),
+        };
+
+        assert_eq!(SearchCursor::decode(&cursor.encode(), SearchSort::Recent), None);
+        assert_eq!(SearchCursor::decode("", SearchSort::Relevance), None);
+        assert_eq!(SearchCursor::decode("r0.5", SearchSort::Relevance), None);
+        assert_eq!(SearchCursor::decode("rabc_not-a-uuid", SearchSort::Relevance), None);
+        assert_eq!(SearchCursor::decode("é", SearchSort::Relevance), None);
+    }
+
+    #[test]
+    fn le_curseur_suivant_reprend_apres_le_dernier_resultat() {
+        let hit = PostSearchHit {
+            post: Post::new("alice".into(), "rust".into()),
+            rank: 0.25,
+        };
+
+        assert_eq!(
+            SearchCursor::after(&hit, SearchSort::Relevance),
+            SearchCursor::Relevance {
+                rank: 0.25,
+                id: hit.post.id,
+            }
+        );
+        let cursor = SearchCursor::after(&hit, SearchSort::Recent).encode();
+        assert_eq!(
+            SearchCursor::decode(&cursor, SearchSort::Recent),
+            Some(SearchCursor::Recent {
+                created_at: DateTime::from_timestamp_micros(hit.post.created_at.timestamp_micros())
+                    .unwrap(),
+                id: hit.post.id,
+            })
+        );
+    }
+}
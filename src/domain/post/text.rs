@@ -0,0 +1,301 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::{extract_hashtags, extract_mentions, HashtagCandidate, MentionCandidate};
+
+/// Poids d'une URL dans la longueur d'un post, quelle que soit sa longueur réelle
+pub const URL_WEIGHT: usize = 23;
+
+/// Taille maximum du texte brut, en octets. Une URL comptant pour `URL_WEIGHT`
+/// quelle que soit sa longueur, la longueur pondérée seule ne borne pas la
+/// taille stockée.
+pub const MAX_CONTENT_BYTES: usize = 16 * 1024;
+
+const URL_SCHEMES: [&str; 2] = ["https://", "http://"];
+
+/// Ponctuation qui termine une phrase plutôt qu'une URL
+const URL_TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', '\'', '"'];
+
+/// URL `http(s)://` repérée dans le texte d'un post.
+/// Les positions sont en caractères (et non en octets), fin exclue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlCandidate {
+    pub url: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Entité du texte d'un post
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextEntity {
+    Url(UrlCandidate),
+    Mention(MentionCandidate),
+    Hashtag(HashtagCandidate),
+}
+
+impl TextEntity {
+    pub fn start(&self) -> usize {
+        match self {
+            TextEntity::Url(url) => url.start,
+            TextEntity::Mention(mention) => mention.start,
+            TextEntity::Hashtag(hashtag) => hashtag.start,
+        }
+    }
+
+    pub fn end(&self) -> usize {
+        match self {
+            TextEntity::Url(url) => url.end,
+            TextEntity::Mention(mention) => mention.end,
+            TextEntity::Hashtag(hashtag) => hashtag.end,
+        }
+    }
+}
+
+/// Texte d'un post analysé une seule fois à la création : contenu normalisé,
+/// longueur affichée et entités
+#[derive(Debug, Clone)]
+pub struct PostText {
+    /// Contenu sans espaces en bordure, en forme normale NFC
+    pub content: String,
+    /// Nombre de graphèmes (caractères perçus : un emoji composé compte pour un),
+    /// chaque URL comptant pour `URL_WEIGHT`
+    pub weighted_length: usize,
+    /// Entités par position croissante. Les mentions et hashtags situés dans
+    /// une URL n'en sont pas.
+    pub entities: Vec<TextEntity>,
+}
+
+impl PostText {
+    pub fn parse(raw: &str) -> Self {
+        let content: String = raw.trim().nfc().collect();
+
+        let urls = extract_urls(&content);
+        let in_url = |start: usize, end: usize| urls.iter().any(|url| start < url.end && url.start < end);
+
+        let mut entities: Vec<TextEntity> = extract_mentions(&content)
+            .into_iter()
+            .filter(|mention| !in_url(mention.start, mention.end))
+            .map(TextEntity::Mention)
+            .collect();
+        entities.extend(
+            extract_hashtags(&content)
+                .into_iter()
+                .filter(|hashtag| !in_url(hashtag.start, hashtag.end))
+                .map(TextEntity::Hashtag),
+        );
+
+        let url_graphemes: usize = urls.iter().map(|url| url.url.graphemes(true).count()).sum();
+        let weighted_length = content.graphemes(true).count().saturating_sub(url_graphemes)
+            + urls.len() * URL_WEIGHT;
+
+        entities.extend(urls.into_iter().map(TextEntity::Url));
+        entities.sort_by_key(TextEntity::start);
+
+        Self {
+            content,
+            weighted_length,
+            entities,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    pub fn urls(&self) -> impl Iterator<Item = &UrlCandidate> {
+        self.entities.iter().filter_map(|entity| match entity {
+            TextEntity::Url(url) => Some(url),
+            _ => None,
+        })
+    }
+
+    pub fn mentions(&self) -> impl Iterator<Item = &MentionCandidate> {
+        self.entities.iter().filter_map(|entity| match entity {
+            TextEntity::Mention(mention) => Some(mention),
+            _ => None,
+        })
+    }
+
+    pub fn hashtags(&self) -> impl Iterator<Item = &HashtagCandidate> {
+        self.entities.iter().filter_map(|entity| match entity {
+            TextEntity::Hashtag(hashtag) => Some(hashtag),
+            _ => None,
+        })
+    }
+}
+
+/// Extrait les URL `http://` et `https://`. Une URL s'arrête au premier espace ;
+/// la ponctuation finale et une parenthèse fermante non ouverte dans l'URL
+/// sont laissées au texte.
+pub fn extract_urls(content: &str) -> Vec<UrlCandidate> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut urls = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let preceded_by_word = i > 0 && chars[i - 1].is_alphanumeric();
+        let scheme = URL_SCHEMES.iter().find(|scheme| {
+            chars.len() - i >= scheme.len()
+                && chars[i..i + scheme.len()]
+                    .iter()
+                    .zip(scheme.chars())
+                    .all(|(c, s)| c.to_ascii_lowercase() == s)
+        });
+        let Some(scheme) = scheme.filter(|_| !preceded_by_word) else {
+            i += 1;
+            continue;
+        };
+
+        let start = i;
+        let mut end = i + scheme.len();
+        while end < chars.len() && !chars[end].is_whitespace() {
+            end += 1;
+        }
+
+        while end > start + scheme.len() {
+            let last = chars[end - 1];
+            let unbalanced_paren = last == ')' && {
+                let url = &chars[start..end];
+                url.iter().filter(|c| **c == ')').count() > url.iter().filter(|c| **c == '(').count()
+            };
+            if URL_TRAILING_PUNCTUATION.contains(&last) || unbalanced_paren {
+                end -= 1;
+            } else {
+                break;
+            }
+        }
+
+        // Un schéma seul n'est pas une URL
+        if end > start + scheme.len() {
+            urls.push(UrlCandidate {
+                url: chars[start..end].iter().collect(),
+                start,
+                end,
+            });
+        }
+
+        i = end.max(i + 1);
+    }
+
+    urls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(content: &str) -> Vec<String> {
+        extract_urls(content).into_iter().map(|url| url.url).collect()
+    }
+
+    #[test]
+    fn normalise_en_nfc_et_retire_les_espaces() {
+        let text = PostText::parse("  Cafe\u{301} crème \n");
+
+        assert_eq!(text.content, "Caf\u{e9} crème");
+        assert_eq!(text.weighted_length, 10);
+    }
+
+    #[test]
+    fn un_emoji_compose_compte_pour_un() {
+        let family = "👩\u{200d}👩\u{200d}👧";
+        assert_eq!(PostText::parse(family).weighted_length, 1);
+        assert_eq!(PostText::parse("🇫🇷 ok").weighted_length, 4);
+    }
+
+    #[test]
+    fn un_post_accentue_de_200_caracteres() {
+        let decomposed = "e\u{301}".repeat(200);
+        let text = PostText::parse(&decomposed);
+
+        assert_eq!(text.content, "é".repeat(200));
+        assert_eq!(text.content.chars().count(), 200);
+        assert_eq!(text.weighted_length, 200);
+    }
+
+    #[test]
+    fn une_url_compte_pour_url_weight() {
+        let long_url = format!("https://example.com/{}", "a".repeat(100));
+        let text = PostText::parse(&format!("voir {}", long_url));
+        assert_eq!(text.weighted_length, "voir ".len() + URL_WEIGHT);
+
+        let text = PostText::parse("http://a.b et https://c.d");
+        assert_eq!(text.weighted_length, 2 * URL_WEIGHT + " et ".len());
+    }
+
+    #[test]
+    fn laisse_la_ponctuation_finale_au_texte() {
+        assert_eq!(urls("voir https://example.com."), ["https://example.com"]);
+        assert_eq!(urls("https://example.com/a?b=1, puis"), ["https://example.com/a?b=1"]);
+        assert_eq!(urls("« https://example.com/! »"), ["https://example.com/"]);
+        assert_eq!(urls("\"https://example.com\""), ["https://example.com"]);
+    }
+
+    #[test]
+    fn garde_les_parentheses_equilibrees() {
+        assert_eq!(
+            urls("(voir https://fr.wikipedia.org/wiki/Rust_(langage))"),
+            ["https://fr.wikipedia.org/wiki/Rust_(langage)"]
+        );
+        assert_eq!(urls("(https://example.com)"), ["https://example.com"]);
+        assert_eq!(urls("https://example.com/a))."), ["https://example.com/a"]);
+    }
+
+    #[test]
+    fn ignore_un_schema_seul_et_les_schemas_colles_a_un_mot() {
+        assert!(urls("https://").is_empty());
+        assert!(urls("http://.").is_empty());
+        assert!(urls("ftp://example.com").is_empty());
+        assert!(urls("xhttps://example.com").is_empty());
+        assert_eq!(urls("HTTPS://Example.com"), ["HTTPS://Example.com"]);
+    }
+
+    #[test]
+    fn positions_en_caracteres() {
+        let candidates = extract_urls("été https://é.fr");
+
+        assert_eq!(
+            candidates,
+            [UrlCandidate {
+                url: "https://é.fr".into(),
+                start: 4,
+                end: 16,
+            }]
+        );
+    }
+
+    #[test]
+    fn chaque_url_compte_pour_url_weight() {
+        let long = format!("http://example.fr/{}", "é".repeat(100));
+        let text = PostText::parse(&format!("a https://x.io b {} c", long));
+
+        assert_eq!(text.urls().count(), 2);
+        assert_eq!(text.weighted_length, "a  b  c".len() + 2 * URL_WEIGHT);
+    }
+
+    #[test]
+    fn un_texte_blanc_est_vide() {
+        let text = PostText::parse(" \n\t\u{3000} ");
+
+        assert!(text.is_empty());
+        assert_eq!(text.weighted_length, 0);
+        assert!(text.entities.is_empty());
+    }
+
+    #[test]
+    fn mentions_et_hashtags_dans_une_url_ignores() {
+        let text = PostText::parse("https://example.com/@alice#intro?tag=#rust @bob #rust");
+
+        assert_eq!(text.urls().count(), 1);
+        assert_eq!(
+            text.mentions().map(|m| m.username.as_str()).collect::<Vec<_>>(),
+            ["bob"]
+        );
+        assert_eq!(
+            text.hashtags().map(|h| h.tag.as_str()).collect::<Vec<_>>(),
+            ["rust"]
+        );
+        let starts: Vec<usize> = text.entities.iter().map(TextEntity::start).collect();
+        assert!(starts.windows(2).all(|w| w[0] < w[1]));
+    }
+}
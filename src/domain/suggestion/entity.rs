@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Signal principal ayant fait remonter une suggestion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuggestionReason {
+    /// Suivi par des comptes que l'utilisateur suit
+    FollowedByFollowing,
+    /// Aimé récemment par des comptes que l'utilisateur suit
+    PopularInNetwork,
+    /// Compte vérifié populaire (démarrage à froid)
+    Verified,
+}
+
+impl SuggestionReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SuggestionReason::FollowedByFollowing => "followed_by_following",
+            SuggestionReason::PopularInNetwork => "popular_in_network",
+            SuggestionReason::Verified => "verified",
+        }
+    }
+}
+
+/// Suggestion d'abonnement pré-calculée pour `user_uid`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct FollowSuggestion {
+    pub user_uid: String,
+    pub suggested_uid: String,
+    pub reason: String,
+    /// Nombre de comptes suivis par `user_uid` qui suivent aussi `suggested_uid`
+    pub mutual_count: i64,
+    pub score: f64,
+}
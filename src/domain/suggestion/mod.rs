@@ -0,0 +1,5 @@
+mod entity;
+mod repository;
+
+pub use entity::{FollowSuggestion, SuggestionReason};
+pub use repository::SuggestionRepository;
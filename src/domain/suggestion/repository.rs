@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+use super::FollowSuggestion;
+use crate::error::AppError;
+
+#[async_trait]
+pub trait SuggestionRepository: Send + Sync {
+    /// Recalcule et remplace les suggestions en cache de `user_uid`
+    async fn refresh(&self, user_uid: &str, limit: i64) -> Result<(), AppError>;
+    /// Suggestions en cache, sans les comptes suivis, bloqués ou masqués depuis le calcul.
+    /// `None` si elles n'ont jamais été calculées ou datent de plus de `max_age`.
+    async fn find_fresh(
+        &self,
+        user_uid: &str,
+        max_age: Duration,
+        limit: i64,
+    ) -> Result<Option<Vec<FollowSuggestion>>, AppError>;
+    /// Utilisateurs dont le cache date de plus de `max_age`, les plus anciens d'abord
+    async fn find_stale_users(&self, max_age: Duration, limit: i64) -> Result<Vec<String>, AppError>;
+}
@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct User {
@@ -12,11 +13,18 @@ pub struct User {
     pub bio: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner_url: Option<String>,
+    /// Post épinglé en tête du profil
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_post_id: Option<Uuid>,
     pub followers_count: i64,
     pub following_count: i64,
     pub posts_count: i64,
     pub is_verified: bool,
     pub is_private: bool,
+    /// Compte de l'équipe, autorisé à modérer
+    pub is_staff: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -31,6 +39,8 @@ impl User {
             display_name,
             bio: None,
             avatar_url: None,
+            banner_url: None,
+            pinned_post_id: None,
             created_at: now,
             updated_at: now,
             followers_count: 0,
@@ -38,6 +48,50 @@ impl User {
             posts_count: 0,
             is_verified: false,
             is_private: false,
+            is_staff: false,
+        }
+    }
+
+    /// Niveau du compte, qui détermine ses limites de publication
+    pub fn tier(&self) -> AccountTier {
+        if self.is_staff {
+            AccountTier::Staff
+        } else if self.is_verified {
+            AccountTier::Verified
+        } else {
+            AccountTier::Standard
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountTier {
+    Standard,
+    Verified,
+    Staff,
+}
+
+/// Image de profil téléversée, recadrée à une taille standard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileImageKind {
+    Avatar,
+    Banner,
+}
+
+impl ProfileImageKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Avatar => "avatar",
+            Self::Banner => "banner",
+        }
+    }
+
+    /// Dimensions finales (largeur, hauteur) en pixels
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            Self::Avatar => (400, 400),
+            Self::Banner => (1500, 500),
         }
     }
 }
@@ -1,5 +1,7 @@
 mod entity;
 mod repository;
+mod settings;
 
-pub use entity::User;
-pub use repository::UserRepository;
+pub use entity::{AccountTier, ProfileImageKind, User};
+pub use repository::{UserRepository, UserSettingsRepository};
+pub use settings::{DmPolicy, SensitiveContent, UserSettings};
@@ -1,14 +1,35 @@
 use async_trait::async_trait;
+use uuid::Uuid;
 
-use super::User;
+use super::{ProfileImageKind, User, UserSettings};
 use crate::error::AppError;
 
 #[async_trait]
 pub trait UserRepository: Send + Sync {
     async fn find_by_uid(&self, uid: &str) -> Result<Option<User>, AppError>;
+    async fn find_by_uids(&self, uids: &[String]) -> Result<Vec<User>, AppError>;
     async fn find_by_username(&self, username: &str) -> Result<Option<User>, AppError>;
     async fn create(&self, user: &User) -> Result<User, AppError>;
     async fn update(&self, user: &User) -> Result<User, AppError>;
     async fn delete(&self, uid: &str) -> Result<(), AppError>;
     async fn is_username_taken(&self, username: &str) -> Result<bool, AppError>;
+    /// Préfixe de username ou sous-chaîne du nom affiché, sans tenir compte de la casse
+    /// ni des accents. `term` est déjà en minuscules. Exclut les blocages avec `viewer_uid`.
+    async fn search(&self, term: &str, viewer_uid: Option<&str>, limit: i64) -> Result<Vec<User>, AppError>;
+    async fn set_pinned_post(&self, uid: &str, post_id: Option<Uuid>) -> Result<(), AppError>;
+    /// Remplace l'avatar ou la bannière et retourne la clé de stockage de l'ancienne image
+    async fn set_profile_image(
+        &self,
+        uid: &str,
+        kind: ProfileImageKind,
+        url: &str,
+        storage_key: &str,
+    ) -> Result<Option<String>, AppError>;
+}
+
+#[async_trait]
+pub trait UserSettingsRepository: Send + Sync {
+    /// Réglages de l'utilisateur, ou les valeurs par défaut s'il n'en a pas
+    async fn find(&self, user_uid: &str) -> Result<UserSettings, AppError>;
+    async fn save(&self, settings: &UserSettings) -> Result<UserSettings, AppError>;
 }
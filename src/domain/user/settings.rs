@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Qui peut envoyer un message privé à l'utilisateur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DmPolicy {
+    Everyone,
+    /// Seuls les abonnés de l'utilisateur
+    Followers,
+    Nobody,
+}
+
+impl DmPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DmPolicy::Everyone => "everyone",
+            DmPolicy::Followers => "followers",
+            DmPolicy::Nobody => "nobody",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "everyone" => Some(DmPolicy::Everyone),
+            "followers" => Some(DmPolicy::Followers),
+            "nobody" => Some(DmPolicy::Nobody),
+            _ => None,
+        }
+    }
+}
+
+/// Affichage des posts portant un avertissement de contenu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SensitiveContent {
+    /// Repliés derrière leur avertissement
+    Warn,
+    /// Dépliés d'office
+    Show,
+    /// Retirés des timelines, des réponses et de la recherche
+    Hide,
+}
+
+impl SensitiveContent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SensitiveContent::Warn => "warn",
+            SensitiveContent::Show => "show",
+            SensitiveContent::Hide => "hide",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "warn" => Some(SensitiveContent::Warn),
+            "show" => Some(SensitiveContent::Show),
+            "hide" => Some(SensitiveContent::Hide),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UserSettings {
+    pub user_uid: String,
+    pub dm_policy: String,
+    pub sensitive_content: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UserSettings {
+    /// Réglages par défaut d'un utilisateur qui n'a rien modifié
+    pub fn default_for(user_uid: String) -> Self {
+        Self {
+            user_uid,
+            dm_policy: DmPolicy::Everyone.as_str().to_string(),
+            sensitive_content: SensitiveContent::Warn.as_str().to_string(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn dm_policy(&self) -> DmPolicy {
+        DmPolicy::parse(&self.dm_policy).unwrap_or(DmPolicy::Everyone)
+    }
+
+    pub fn sensitive_content(&self) -> SensitiveContent {
+        SensitiveContent::parse(&self.sensitive_content).unwrap_or(SensitiveContent::Warn)
+    }
+}
@@ -23,6 +23,9 @@ pub enum AppError {
     #[error("Conflit: {0}")]
     Conflict(String),
 
+    #[error("Limite atteinte: {0}")]
+    TooManyRequests(String),
+
     #[error("Erreur interne: {0}")]
     Internal(String),
 
@@ -49,6 +52,9 @@ impl IntoResponse for AppError {
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, "NOT_FOUND", msg.clone()),
             AppError::Validation(msg) => (StatusCode::BAD_REQUEST, "VALIDATION_ERROR", msg.clone()),
             AppError::Conflict(msg) => (StatusCode::CONFLICT, "CONFLICT", msg.clone()),
+            AppError::TooManyRequests(msg) => {
+                (StatusCode::TOO_MANY_REQUESTS, "TOO_MANY_REQUESTS", msg.clone())
+            }
             AppError::Internal(msg) => {
                 tracing::error!("Erreur interne: {}", msg);
                 (
@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use super::follow_repository::delete_follow;
+use crate::domain::block::{Block, BlockRepository};
+use crate::error::AppError;
+
+pub struct PostgresBlockRepository {
+    pool: PgPool,
+}
+
+impl PostgresBlockRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BlockRepository for PostgresBlockRepository {
+    async fn block(&self, blocker_uid: &str, blocked_uid: &str) -> Result<(), AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO blocks (blocker_uid, blocked_uid)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(blocker_uid)
+        .bind(blocked_uid)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur création blocage: {}", e)))?;
+
+        // Un blocage rompt les abonnements dans les deux sens,
+        delete_follow(&mut tx, blocker_uid, blocked_uid).await?;
+        delete_follow(&mut tx, blocked_uid, blocker_uid).await?;
+
+        // ainsi que l'appartenance aux listes d'amis proches
+        sqlx::query(
+            r#"
+            DELETE FROM close_friends
+            WHERE (user_uid = $1 AND friend_uid = $2) OR (user_uid = $2 AND friend_uid = $1)
+            "#,
+        )
+        .bind(blocker_uid)
+        .bind(blocked_uid)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        tracing::info!("Utilisateur {} bloqué par {}", blocked_uid, blocker_uid);
+
+        Ok(())
+    }
+
+    async fn unblock(&self, blocker_uid: &str, blocked_uid: &str) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM blocks WHERE blocker_uid = $1 AND blocked_uid = $2")
+            .bind(blocker_uid)
+            .bind(blocked_uid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn is_blocked_between(&self, uid_a: &str, uid_b: &str) -> Result<bool, AppError> {
+        let result: (bool,) = sqlx::query_as(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM blocks
+                WHERE (blocker_uid = $1 AND blocked_uid = $2)
+                   OR (blocker_uid = $2 AND blocked_uid = $1)
+            )
+            "#,
+        )
+        .bind(uid_a)
+        .bind(uid_b)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.0)
+    }
+
+    async fn find_by_blocker(&self, blocker_uid: &str, limit: i64, offset: i64) -> Result<Vec<Block>, AppError> {
+        let blocks = sqlx::query_as::<_, Block>(
+            r#"
+            SELECT blocker_uid, blocked_uid, created_at
+            FROM blocks
+            WHERE blocker_uid = $1
+            ORDER BY created_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(blocker_uid)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(blocks)
+    }
+}
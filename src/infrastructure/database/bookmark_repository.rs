@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::bookmark::{Bookmark, BookmarkFolder, BookmarkRepository};
+use crate::error::AppError;
+
+pub struct PostgresBookmarkRepository {
+    pool: PgPool,
+}
+
+impl PostgresBookmarkRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_folder_error(e: sqlx::Error) -> AppError {
+    let err_str = e.to_string();
+    if err_str.contains("duplicate key") || err_str.contains("unique constraint") {
+        AppError::Conflict("Un dossier porte déjà ce nom".into())
+    } else {
+        AppError::Internal(format!("Erreur DB: {}", e))
+    }
+}
+
+#[async_trait]
+impl BookmarkRepository for PostgresBookmarkRepository {
+    async fn save(&self, user_uid: &str, post_id: Uuid, folder_id: Option<Uuid>) -> Result<Bookmark, AppError> {
+        let bookmark = sqlx::query_as::<_, Bookmark>(
+            r#"
+            INSERT INTO bookmarks (user_uid, post_id, folder_id)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_uid, post_id) DO UPDATE SET folder_id = EXCLUDED.folder_id
+            RETURNING user_uid, post_id, folder_id, created_at
+            "#,
+        )
+        .bind(user_uid)
+        .bind(post_id)
+        .bind(folder_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(bookmark)
+    }
+
+    async fn remove(&self, user_uid: &str, post_id: Uuid) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM bookmarks WHERE user_uid = $1 AND post_id = $2")
+            .bind(user_uid)
+            .bind(post_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn create_folder(&self, folder: &BookmarkFolder) -> Result<BookmarkFolder, AppError> {
+        let created = sqlx::query_as::<_, BookmarkFolder>(
+            r#"
+            INSERT INTO bookmark_folders (id, user_uid, name, created_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, user_uid, name, 0::BIGINT AS bookmarks_count, created_at
+            "#,
+        )
+        .bind(folder.id)
+        .bind(&folder.user_uid)
+        .bind(&folder.name)
+        .bind(folder.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_folder_error)?;
+
+        Ok(created)
+    }
+
+    async fn find_folder(&self, user_uid: &str, id: Uuid) -> Result<Option<BookmarkFolder>, AppError> {
+        let folder = sqlx::query_as::<_, BookmarkFolder>(
+            r#"
+            SELECT f.id, f.user_uid, f.name,
+                (SELECT COUNT(*) FROM bookmarks b WHERE b.folder_id = f.id) AS bookmarks_count,
+                f.created_at
+            FROM bookmark_folders f
+            WHERE f.id = $1 AND f.user_uid = $2
+            "#,
+        )
+        .bind(id)
+        .bind(user_uid)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(folder)
+    }
+
+    async fn find_folders(&self, user_uid: &str) -> Result<Vec<BookmarkFolder>, AppError> {
+        let folders = sqlx::query_as::<_, BookmarkFolder>(
+            r#"
+            SELECT f.id, f.user_uid, f.name,
+                (SELECT COUNT(*) FROM bookmarks b WHERE b.folder_id = f.id) AS bookmarks_count,
+                f.created_at
+            FROM bookmark_folders f
+            WHERE f.user_uid = $1
+            ORDER BY LOWER(f.name) ASC
+            "#,
+        )
+        .bind(user_uid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(folders)
+    }
+
+    async fn rename_folder(&self, user_uid: &str, id: Uuid, name: &str) -> Result<BookmarkFolder, AppError> {
+        let folder = sqlx::query_as::<_, BookmarkFolder>(
+            r#"
+            UPDATE bookmark_folders f
+            SET name = $3
+            WHERE f.id = $1 AND f.user_uid = $2
+            RETURNING f.id, f.user_uid, f.name,
+                (SELECT COUNT(*) FROM bookmarks b WHERE b.folder_id = f.id) AS bookmarks_count,
+                f.created_at
+            "#,
+        )
+        .bind(id)
+        .bind(user_uid)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_folder_error)?
+        .ok_or_else(|| AppError::NotFound("Dossier non trouvé".into()))?;
+
+        Ok(folder)
+    }
+
+    async fn delete_folder(&self, user_uid: &str, id: Uuid) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM bookmark_folders WHERE id = $1 AND user_uid = $2")
+            .bind(id)
+            .bind(user_uid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+}
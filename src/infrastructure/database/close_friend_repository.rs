@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::close_friend::{CloseFriend, CloseFriendRepository};
+use crate::error::AppError;
+
+pub struct PostgresCloseFriendRepository {
+    pool: PgPool,
+}
+
+impl PostgresCloseFriendRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CloseFriendRepository for PostgresCloseFriendRepository {
+    async fn add(&self, user_uid: &str, friend_uid: &str) -> Result<bool, AppError> {
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO close_friends (user_uid, friend_uid)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(user_uid)
+        .bind(friend_uid)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur ajout ami proche: {}", e)))?
+        .rows_affected()
+            > 0;
+
+        Ok(inserted)
+    }
+
+    async fn remove(&self, user_uid: &str, friend_uid: &str) -> Result<bool, AppError> {
+        let deleted = sqlx::query("DELETE FROM close_friends WHERE user_uid = $1 AND friend_uid = $2")
+            .bind(user_uid)
+            .bind(friend_uid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?
+            .rows_affected()
+            > 0;
+
+        Ok(deleted)
+    }
+
+    async fn is_close_friend(&self, user_uid: &str, friend_uid: &str) -> Result<bool, AppError> {
+        let result: (bool,) = sqlx::query_as(
+            "SELECT EXISTS(SELECT 1 FROM close_friends WHERE user_uid = $1 AND friend_uid = $2)",
+        )
+        .bind(user_uid)
+        .bind(friend_uid)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.0)
+    }
+
+    async fn count(&self, user_uid: &str) -> Result<i64, AppError> {
+        let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM close_friends WHERE user_uid = $1")
+            .bind(user_uid)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.0)
+    }
+
+    async fn find_by_user(&self, user_uid: &str, limit: i64, offset: i64) -> Result<Vec<CloseFriend>, AppError> {
+        let friends = sqlx::query_as::<_, CloseFriend>(
+            r#"
+            SELECT user_uid, friend_uid, created_at
+            FROM close_friends
+            WHERE user_uid = $1
+            ORDER BY created_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(user_uid)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(friends)
+    }
+}
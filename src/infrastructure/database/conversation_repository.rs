@@ -0,0 +1,354 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::conversation::{
+    Conversation, ConversationParticipant, ConversationRepository, ConversationUnread, Message,
+};
+use crate::error::AppError;
+
+pub struct PostgresConversationRepository {
+    pool: PgPool,
+}
+
+impl PostgresConversationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ConversationRepository for PostgresConversationRepository {
+    async fn create(&self, conversation: &Conversation, participant_uids: &[String]) -> Result<Conversation, AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        let created = sqlx::query_as::<_, Conversation>(
+            r#"
+            INSERT INTO conversations (id, created_by, is_group, direct_key, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, created_by, is_group, direct_key, last_message_at, created_at
+            "#,
+        )
+        .bind(conversation.id)
+        .bind(&conversation.created_by)
+        .bind(conversation.is_group)
+        .bind(&conversation.direct_key)
+        .bind(conversation.created_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            let err_str = e.to_string();
+            if err_str.contains("duplicate key") || err_str.contains("unique constraint") {
+                AppError::Conflict("Cette conversation existe déjà".into())
+            } else {
+                AppError::Internal(format!("Erreur DB: {}", e))
+            }
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO conversation_participants (conversation_id, user_uid)
+            SELECT $1, UNNEST($2::VARCHAR[])
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(created.id)
+        .bind(participant_uids)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Conversation>, AppError> {
+        let conversation = sqlx::query_as::<_, Conversation>(
+            r#"
+            SELECT id, created_by, is_group, direct_key, last_message_at, created_at
+            FROM conversations
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(conversation)
+    }
+
+    async fn find_direct(&self, direct_key: &str) -> Result<Option<Conversation>, AppError> {
+        let conversation = sqlx::query_as::<_, Conversation>(
+            r#"
+            SELECT id, created_by, is_group, direct_key, last_message_at, created_at
+            FROM conversations
+            WHERE direct_key = $1
+            "#,
+        )
+        .bind(direct_key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(conversation)
+    }
+
+    async fn find_for_user(&self, user_uid: &str, limit: i64, offset: i64) -> Result<Vec<Conversation>, AppError> {
+        let conversations = sqlx::query_as::<_, Conversation>(
+            r#"
+            SELECT c.id, c.created_by, c.is_group, c.direct_key, c.last_message_at, c.created_at
+            FROM conversations c
+            JOIN conversation_participants p ON p.conversation_id = c.id
+            WHERE p.user_uid = $1
+            ORDER BY COALESCE(c.last_message_at, c.created_at) DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(user_uid)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(conversations)
+    }
+
+    async fn find_participants(&self, conversation_ids: &[Uuid]) -> Result<Vec<ConversationParticipant>, AppError> {
+        if conversation_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let participants = sqlx::query_as::<_, ConversationParticipant>(
+            r#"
+            SELECT conversation_id, user_uid, last_read_at, joined_at
+            FROM conversation_participants
+            WHERE conversation_id = ANY($1)
+            ORDER BY joined_at ASC
+            "#,
+        )
+        .bind(conversation_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(participants)
+    }
+
+    async fn add_participant(&self, conversation_id: Uuid, user_uid: &str) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            INSERT INTO conversation_participants (conversation_id, user_uid)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(conversation_id)
+        .bind(user_uid)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn remove_participant(&self, conversation_id: Uuid, user_uid: &str) -> Result<bool, AppError> {
+        let result = sqlx::query(
+            "DELETE FROM conversation_participants WHERE conversation_id = $1 AND user_uid = $2",
+        )
+        .bind(conversation_id)
+        .bind(user_uid)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn is_participant(&self, conversation_id: Uuid, user_uid: &str) -> Result<bool, AppError> {
+        let result: (bool,) = sqlx::query_as(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM conversation_participants
+                WHERE conversation_id = $1 AND user_uid = $2
+            )
+            "#,
+        )
+        .bind(conversation_id)
+        .bind(user_uid)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.0)
+    }
+
+    async fn create_message(&self, message: &Message) -> Result<Message, AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        let created = sqlx::query_as::<_, Message>(
+            r#"
+            INSERT INTO messages (id, conversation_id, sender_uid, content, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, conversation_id, sender_uid, content, deleted_at, created_at
+            "#,
+        )
+        .bind(message.id)
+        .bind(message.conversation_id)
+        .bind(&message.sender_uid)
+        .bind(&message.content)
+        .bind(message.created_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        sqlx::query("UPDATE conversations SET last_message_at = $2 WHERE id = $1")
+            .bind(created.conversation_id)
+            .bind(created.created_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        // L'expéditeur a forcément lu la conversation jusqu'à son message
+        sqlx::query(
+            r#"
+            UPDATE conversation_participants SET last_read_at = $3
+            WHERE conversation_id = $1 AND user_uid = $2
+            "#,
+        )
+        .bind(created.conversation_id)
+        .bind(&created.sender_uid)
+        .bind(created.created_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(created)
+    }
+
+    async fn find_message(&self, id: Uuid) -> Result<Option<Message>, AppError> {
+        let message = sqlx::query_as::<_, Message>(
+            r#"
+            SELECT id, conversation_id, sender_uid, content, deleted_at, created_at
+            FROM messages
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(message)
+    }
+
+    async fn find_messages(&self, conversation_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Message>, AppError> {
+        let messages = sqlx::query_as::<_, Message>(
+            r#"
+            SELECT id, conversation_id, sender_uid, content, deleted_at, created_at
+            FROM messages
+            WHERE conversation_id = $1
+            ORDER BY created_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(conversation_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(messages)
+    }
+
+    async fn find_last_messages(&self, conversation_ids: &[Uuid]) -> Result<Vec<Message>, AppError> {
+        if conversation_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let messages = sqlx::query_as::<_, Message>(
+            r#"
+            SELECT DISTINCT ON (conversation_id)
+                id, conversation_id, sender_uid, content, deleted_at, created_at
+            FROM messages
+            WHERE conversation_id = ANY($1)
+            ORDER BY conversation_id, created_at DESC
+            "#,
+        )
+        .bind(conversation_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(messages)
+    }
+
+    async fn delete_message(&self, id: Uuid) -> Result<(), AppError> {
+        sqlx::query("UPDATE messages SET content = '', deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn mark_read(&self, conversation_id: Uuid, user_uid: &str) -> Result<bool, AppError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE conversation_participants SET last_read_at = NOW()
+            WHERE conversation_id = $1 AND user_uid = $2
+            "#,
+        )
+        .bind(conversation_id)
+        .bind(user_uid)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn count_unread(&self, user_uid: &str, conversation_ids: &[Uuid]) -> Result<Vec<ConversationUnread>, AppError> {
+        if conversation_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let unread = sqlx::query_as::<_, ConversationUnread>(
+            r#"
+            SELECT p.conversation_id, COUNT(m.id) AS unread_count
+            FROM conversation_participants p
+            LEFT JOIN messages m ON m.conversation_id = p.conversation_id
+                AND m.sender_uid <> p.user_uid
+                AND m.deleted_at IS NULL
+                AND (p.last_read_at IS NULL OR m.created_at > p.last_read_at)
+            WHERE p.user_uid = $1 AND p.conversation_id = ANY($2)
+            GROUP BY p.conversation_id
+            "#,
+        )
+        .bind(user_uid)
+        .bind(conversation_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(unread)
+    }
+}
@@ -0,0 +1,197 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use super::media_repository::reserve_media;
+use crate::domain::draft::{Draft, DraftRepository};
+use crate::domain::media::MediaAttachment;
+use crate::error::AppError;
+
+const DRAFT_COLUMNS: &str = "id, author_uid, content, poll_options, poll_duration_minutes, \
+    poll_hide_results, reply_policy, audience, content_warning, is_sensitive, publish_at, \
+    locked_until, last_error, created_at, updated_at";
+
+pub struct PostgresDraftRepository {
+    pool: PgPool,
+}
+
+impl PostgresDraftRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DraftRepository for PostgresDraftRepository {
+    async fn create(&self, draft: &Draft, attachments: &[MediaAttachment]) -> Result<Draft, AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        let created = sqlx::query_as::<_, Draft>(&format!(
+            r#"
+            INSERT INTO post_drafts (id, author_uid, content, poll_options, poll_duration_minutes,
+                poll_hide_results, reply_policy, audience, content_warning, is_sensitive, publish_at,
+                locked_until, last_error, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            RETURNING {DRAFT_COLUMNS}
+            "#
+        ))
+        .bind(draft.id)
+        .bind(&draft.author_uid)
+        .bind(&draft.content)
+        .bind(&draft.poll_options)
+        .bind(draft.poll_duration_minutes)
+        .bind(draft.poll_hide_results)
+        .bind(&draft.reply_policy)
+        .bind(&draft.audience)
+        .bind(&draft.content_warning)
+        .bind(draft.is_sensitive)
+        .bind(draft.publish_at)
+        .bind(draft.locked_until)
+        .bind(&draft.last_error)
+        .bind(draft.created_at)
+        .bind(draft.updated_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        reserve_media(&mut tx, created.id, &created.author_uid, attachments).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Draft>, AppError> {
+        let draft = sqlx::query_as::<_, Draft>(&format!(
+            "SELECT {DRAFT_COLUMNS} FROM post_drafts WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(draft)
+    }
+
+    async fn find_by_author(&self, author_uid: &str, limit: i64, offset: i64) -> Result<Vec<Draft>, AppError> {
+        let drafts = sqlx::query_as::<_, Draft>(&format!(
+            r#"
+            SELECT {DRAFT_COLUMNS}
+            FROM post_drafts
+            WHERE author_uid = $1
+            ORDER BY publish_at ASC NULLS LAST, updated_at DESC
+            LIMIT $2 OFFSET $3
+            "#
+        ))
+        .bind(author_uid)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(drafts)
+    }
+
+    async fn update(&self, draft: &Draft, attachments: Option<&[MediaAttachment]>) -> Result<Option<Draft>, AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        let updated = sqlx::query_as::<_, Draft>(&format!(
+            r#"
+            UPDATE post_drafts
+            SET content = $2, poll_options = $3, poll_duration_minutes = $4,
+                poll_hide_results = $5, reply_policy = $6, audience = $7, content_warning = $8,
+                is_sensitive = $9, publish_at = $10, last_error = $11, updated_at = NOW()
+            WHERE id = $1 AND (locked_until IS NULL OR locked_until <= NOW())
+            RETURNING {DRAFT_COLUMNS}
+            "#
+        ))
+        .bind(draft.id)
+        .bind(&draft.content)
+        .bind(&draft.poll_options)
+        .bind(draft.poll_duration_minutes)
+        .bind(draft.poll_hide_results)
+        .bind(&draft.reply_policy)
+        .bind(&draft.audience)
+        .bind(&draft.content_warning)
+        .bind(draft.is_sensitive)
+        .bind(draft.publish_at)
+        .bind(&draft.last_error)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        if let (Some(updated), Some(attachments)) = (&updated, attachments) {
+            reserve_media(&mut tx, updated.id, &updated.author_uid, attachments).await?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM post_drafts WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn claim_due(&self, lease: Duration, limit: i64) -> Result<Vec<Draft>, AppError> {
+        // SKIP LOCKED : plusieurs instances peuvent faire tourner le planificateur sans réserver deux fois la même publication
+        let drafts = sqlx::query_as::<_, Draft>(&format!(
+            r#"
+            UPDATE post_drafts
+            SET locked_until = NOW() + make_interval(secs => $1)
+            WHERE id IN (
+                SELECT id FROM post_drafts
+                WHERE publish_at <= NOW() AND (locked_until IS NULL OR locked_until <= NOW())
+                ORDER BY publish_at
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING {DRAFT_COLUMNS}
+            "#
+        ))
+        .bind(lease.as_secs_f64())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(drafts)
+    }
+
+    async fn mark_failed(&self, id: Uuid, error: &str) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE post_drafts
+            SET publish_at = NULL, locked_until = NULL, last_error = $2, updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+}
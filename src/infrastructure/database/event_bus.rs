@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+use tokio::sync::mpsc;
+
+use crate::domain::event::{DomainEvent, EventBus, PublishedEvent};
+use crate::error::AppError;
+
+/// Canal `LISTEN/NOTIFY` partagé par toutes les instances
+const CHANNEL: &str = "authify_events";
+
+/// Limite de PostgreSQL pour la charge utile d'un `NOTIFY`, moins la place
+/// réservée à l'identifiant ajouté par la base
+const MAX_PAYLOAD_BYTES: usize = 7900;
+
+/// Taille de la file d'attente entre le listener et l'abonné
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Bus partagé entre instances via `LISTEN/NOTIFY` sur la base existante
+pub struct PostgresEventBus {
+    pool: PgPool,
+}
+
+impl PostgresEventBus {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EventBus for PostgresEventBus {
+    async fn publish(&self, event: &DomainEvent) -> Result<(), AppError> {
+        let payload = serde_json::to_string(event)
+            .map_err(|e| AppError::Internal(format!("Erreur sérialisation événement: {}", e)))?;
+
+        if payload.len() >= MAX_PAYLOAD_BYTES {
+            return Err(AppError::Internal(format!(
+                "Événement trop volumineux pour NOTIFY ({} octets)",
+                payload.len()
+            )));
+        }
+
+        // L'identifiant vient d'une séquence commune à toutes les instances :
+        // un client peut reprendre le flux sur n'importe laquelle
+        sqlx::query(
+            "SELECT pg_notify($1, json_build_object('id', nextval('event_ids'), 'event', $2::json)::text)",
+        )
+            .bind(CHANNEL)
+            .bind(payload)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn subscribe(&self) -> Result<mpsc::Receiver<PublishedEvent>, AppError> {
+        let mut listener = PgListener::connect_with(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+        listener
+            .listen(CHANNEL)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+
+        tokio::spawn(async move {
+            loop {
+                // `try_recv` retourne `None` quand la connexion est perdue : le
+                // flux se termine pour que l'abonné sache que des événements
+                // ont pu être manqués
+                let notification = match listener.try_recv().await {
+                    Ok(Some(notification)) => notification,
+                    Ok(None) => break,
+                    Err(e) => {
+                        tracing::warn!("Erreur écoute du bus d'événements: {}", e);
+                        break;
+                    }
+                };
+
+                match serde_json::from_str::<PublishedEvent>(notification.payload()) {
+                    Ok(event) => {
+                        if tx.send(event).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => tracing::warn!("Événement illisible ignoré: {}", e),
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
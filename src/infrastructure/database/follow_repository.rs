@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::follow::FollowRepository;
+use crate::error::AppError;
+
+pub struct PostgresFollowRepository {
+    pool: PgPool,
+}
+
+impl PostgresFollowRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FollowRepository for PostgresFollowRepository {
+    async fn follow(&self, follower_uid: &str, followee_uid: &str) -> Result<bool, AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO follows (follower_uid, followee_uid)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(follower_uid)
+        .bind(followee_uid)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur création abonnement: {}", e)))?
+        .rows_affected()
+            > 0;
+
+        if inserted {
+            sqlx::query("UPDATE users SET following_count = following_count + 1 WHERE uid = $1")
+                .bind(follower_uid)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+            sqlx::query("UPDATE users SET followers_count = followers_count + 1 WHERE uid = $1")
+                .bind(followee_uid)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(inserted)
+    }
+
+    async fn unfollow(&self, follower_uid: &str, followee_uid: &str) -> Result<bool, AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        let deleted = delete_follow(&mut tx, follower_uid, followee_uid).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(deleted)
+    }
+
+    async fn is_following(&self, follower_uid: &str, followee_uid: &str) -> Result<bool, AppError> {
+        let result: (bool,) = sqlx::query_as(
+            "SELECT EXISTS(SELECT 1 FROM follows WHERE follower_uid = $1 AND followee_uid = $2)",
+        )
+        .bind(follower_uid)
+        .bind(followee_uid)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.0)
+    }
+}
+
+/// Supprime un abonnement et met à jour les compteurs dans la transaction donnée
+pub(crate) async fn delete_follow(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    follower_uid: &str,
+    followee_uid: &str,
+) -> Result<bool, AppError> {
+    let deleted = sqlx::query("DELETE FROM follows WHERE follower_uid = $1 AND followee_uid = $2")
+        .bind(follower_uid)
+        .bind(followee_uid)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?
+        .rows_affected()
+        > 0;
+
+    if deleted {
+        sqlx::query("UPDATE users SET following_count = GREATEST(following_count - 1, 0) WHERE uid = $1")
+            .bind(follower_uid)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        sqlx::query("UPDATE users SET followers_count = GREATEST(followers_count - 1, 0) WHERE uid = $1")
+            .bind(followee_uid)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+    }
+
+    Ok(deleted)
+}
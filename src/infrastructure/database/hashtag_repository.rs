@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::time::Duration;
+
+use crate::domain::hashtag::{HashtagRepository, TrendingHashtag};
+use crate::error::AppError;
+
+pub struct PostgresHashtagRepository {
+    pool: PgPool,
+}
+
+impl PostgresHashtagRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HashtagRepository for PostgresHashtagRepository {
+    async fn refresh_trending(&self, window: Duration, baseline_windows: i64, limit: i64) -> Result<(), AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        sqlx::query("DELETE FROM trending_hashtags")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        // Score = écart entre l'usage récent et l'usage moyen par fenêtre sur la
+        // période de référence, atténué pour les tags déjà très utilisés.
+        // Seuls les posts publics comptent.
+        sqlx::query(
+            r#"
+            INSERT INTO trending_hashtags (tag, recent_count, score, computed_at)
+            SELECT tag, recent, (recent - baseline) / SQRT(baseline + 1), NOW()
+            FROM (
+                SELECT tag,
+                    COUNT(*) FILTER (WHERE created_at > NOW() - make_interval(secs => $1)) AS recent,
+                    COUNT(*) FILTER (WHERE created_at <= NOW() - make_interval(secs => $1))::DOUBLE PRECISION
+                        / $2 AS baseline
+                FROM post_hashtags
+                WHERE created_at > NOW() - make_interval(secs => $1 * ($2 + 1))
+                  AND EXISTS (
+                      SELECT 1 FROM posts p
+                      WHERE p.id = post_hashtags.post_id AND p.audience = 'public'
+                  )
+                GROUP BY tag
+            ) usage
+            WHERE recent >= 2 AND recent > baseline
+            ORDER BY (recent - baseline) / SQRT(baseline + 1) DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(window.as_secs_f64())
+        .bind(baseline_windows as f64)
+        .bind(limit)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur calcul tendances: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_trending(&self, limit: i64) -> Result<Vec<TrendingHashtag>, AppError> {
+        let hashtags = sqlx::query_as::<_, TrendingHashtag>(
+            r#"
+            SELECT tag, recent_count, score, computed_at
+            FROM trending_hashtags
+            ORDER BY score DESC
+            LIMIT $1
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(hashtags)
+    }
+}
@@ -0,0 +1,233 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::media::{Media, MediaAttachment, MediaRepository};
+use crate::error::AppError;
+
+const MEDIA_COLUMNS: &str = "id, owner_uid, post_id, draft_id, storage_key, url, mime_type, width, height, \
+    size_bytes, alt_text, position, created_at";
+
+/// Attache les médias au post dans l'ordre donné, dans la transaction de sa
+/// création. Seuls les médias libres ou réservés par `draft_id` (le brouillon
+/// publié) peuvent être attachés ; échoue si l'un d'eux a été pris entre-temps.
+pub(super) async fn attach_media(
+    conn: &mut PgConnection,
+    post_id: Uuid,
+    owner_uid: &str,
+    attachments: &[MediaAttachment],
+    draft_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    for (position, attachment) in attachments.iter().enumerate() {
+        let result = sqlx::query(
+            r#"
+            UPDATE media SET post_id = $1, draft_id = NULL, alt_text = $2, position = $3
+            WHERE id = $4 AND owner_uid = $5 AND post_id IS NULL
+              AND (draft_id IS NULL OR draft_id = $6)
+            "#,
+        )
+        .bind(post_id)
+        .bind(&attachment.alt_text)
+        .bind(position as i16)
+        .bind(attachment.id)
+        .bind(owner_uid)
+        .bind(draft_id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::Conflict(format!(
+                "Le média {} est introuvable ou déjà attaché",
+                attachment.id
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Réserve les médias pour le brouillon dans l'ordre donné, à la place des
+/// précédents, dans la transaction qui enregistre le brouillon. Échoue si l'un
+/// d'eux n'est plus disponible.
+pub(super) async fn reserve_media(
+    conn: &mut PgConnection,
+    draft_id: Uuid,
+    owner_uid: &str,
+    attachments: &[MediaAttachment],
+) -> Result<(), AppError> {
+    sqlx::query("UPDATE media SET draft_id = NULL WHERE draft_id = $1")
+        .bind(draft_id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+    for (position, attachment) in attachments.iter().enumerate() {
+        let result = sqlx::query(
+            r#"
+            UPDATE media SET draft_id = $1, alt_text = $2, position = $3
+            WHERE id = $4 AND owner_uid = $5 AND post_id IS NULL
+              AND (draft_id IS NULL OR draft_id = $1)
+            "#,
+        )
+        .bind(draft_id)
+        .bind(&attachment.alt_text)
+        .bind(position as i16)
+        .bind(attachment.id)
+        .bind(owner_uid)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::Conflict(format!(
+                "Le média {} est introuvable ou déjà utilisé",
+                attachment.id
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+pub struct PostgresMediaRepository {
+    pool: PgPool,
+}
+
+impl PostgresMediaRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MediaRepository for PostgresMediaRepository {
+    async fn create(&self, media: &Media) -> Result<Media, AppError> {
+        let created = sqlx::query_as::<_, Media>(&format!(
+            r#"
+            INSERT INTO media (id, owner_uid, post_id, storage_key, url, mime_type, width, height,
+                size_bytes, alt_text, position, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING {MEDIA_COLUMNS}
+            "#
+        ))
+        .bind(media.id)
+        .bind(&media.owner_uid)
+        .bind(media.post_id)
+        .bind(&media.storage_key)
+        .bind(&media.url)
+        .bind(&media.mime_type)
+        .bind(media.width)
+        .bind(media.height)
+        .bind(media.size_bytes)
+        .bind(&media.alt_text)
+        .bind(media.position)
+        .bind(media.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(created)
+    }
+
+    async fn find_unattached(
+        &self,
+        owner_uid: &str,
+        ids: &[Uuid],
+        draft_id: Option<Uuid>,
+    ) -> Result<Vec<Media>, AppError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let media = sqlx::query_as::<_, Media>(&format!(
+            r#"
+            SELECT {MEDIA_COLUMNS}
+            FROM media
+            WHERE owner_uid = $1 AND id = ANY($2) AND post_id IS NULL
+              AND (draft_id IS NULL OR draft_id = $3)
+            "#
+        ))
+        .bind(owner_uid)
+        .bind(ids)
+        .bind(draft_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(media)
+    }
+
+    async fn find_by_posts(&self, post_ids: &[Uuid]) -> Result<Vec<Media>, AppError> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let media = sqlx::query_as::<_, Media>(&format!(
+            r#"
+            SELECT {MEDIA_COLUMNS}
+            FROM media
+            WHERE post_id = ANY($1)
+            ORDER BY post_id, position
+            "#
+        ))
+        .bind(post_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(media)
+    }
+
+    async fn find_by_drafts(&self, draft_ids: &[Uuid]) -> Result<Vec<Media>, AppError> {
+        if draft_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let media = sqlx::query_as::<_, Media>(&format!(
+            r#"
+            SELECT {MEDIA_COLUMNS}
+            FROM media
+            WHERE draft_id = ANY($1)
+            ORDER BY draft_id, position
+            "#
+        ))
+        .bind(draft_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(media)
+    }
+
+    async fn find_orphans(&self, max_age: Duration, limit: i64) -> Result<Vec<Media>, AppError> {
+        let media = sqlx::query_as::<_, Media>(&format!(
+            r#"
+            SELECT {MEDIA_COLUMNS}
+            FROM media
+            WHERE post_id IS NULL AND draft_id IS NULL
+              AND created_at < NOW() - make_interval(secs => $1)
+            ORDER BY created_at ASC
+            LIMIT $2
+            "#
+        ))
+        .bind(max_age.as_secs_f64())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(media)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM media WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,35 @@
+mod block_repository;
+mod bookmark_repository;
+mod close_friend_repository;
+mod conversation_repository;
+mod draft_repository;
+mod event_bus;
+mod follow_repository;
+mod hashtag_repository;
+mod media_repository;
+mod mute_repository;
+mod notification_repository;
 mod pool;
+mod poll_repository;
 mod post_repository;
+mod suggestion_repository;
 mod user_repository;
+mod user_settings_repository;
 
+pub use block_repository::PostgresBlockRepository;
+pub use bookmark_repository::PostgresBookmarkRepository;
+pub use close_friend_repository::PostgresCloseFriendRepository;
+pub use conversation_repository::PostgresConversationRepository;
+pub use draft_repository::PostgresDraftRepository;
+pub use event_bus::PostgresEventBus;
+pub use follow_repository::PostgresFollowRepository;
+pub use hashtag_repository::PostgresHashtagRepository;
+pub use media_repository::PostgresMediaRepository;
+pub use mute_repository::PostgresMuteRepository;
+pub use notification_repository::PostgresNotificationRepository;
+pub use poll_repository::PostgresPollRepository;
 pub use pool::create_pool;
 pub use post_repository::PostgresPostRepository;
+pub use suggestion_repository::PostgresSuggestionRepository;
 pub use user_repository::PostgresUserRepository;
+pub use user_settings_repository::PostgresUserSettingsRepository;
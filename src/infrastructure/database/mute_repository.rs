@@ -0,0 +1,273 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::mute::{MuteRepository, MutedThread, MutedUser, MutedWord};
+use crate::error::AppError;
+
+pub struct PostgresMuteRepository {
+    pool: PgPool,
+}
+
+impl PostgresMuteRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_word_error(e: sqlx::Error) -> AppError {
+    let err_str = e.to_string();
+    if err_str.contains("duplicate key") || err_str.contains("unique constraint") {
+        AppError::Conflict("Ce mot est déjà masqué".into())
+    } else {
+        AppError::Internal(format!("Erreur DB: {}", e))
+    }
+}
+
+#[async_trait]
+impl MuteRepository for PostgresMuteRepository {
+    async fn mute_user(
+        &self,
+        user_uid: &str,
+        muted_uid: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<MutedUser, AppError> {
+        let muted = sqlx::query_as::<_, MutedUser>(
+            r#"
+            INSERT INTO muted_users (user_uid, muted_uid, expires_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_uid, muted_uid) DO UPDATE SET expires_at = EXCLUDED.expires_at
+            RETURNING user_uid, muted_uid, expires_at, created_at
+            "#,
+        )
+        .bind(user_uid)
+        .bind(muted_uid)
+        .bind(expires_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(muted)
+    }
+
+    async fn unmute_user(&self, user_uid: &str, muted_uid: &str) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM muted_users WHERE user_uid = $1 AND muted_uid = $2")
+            .bind(user_uid)
+            .bind(muted_uid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_muted_users(&self, user_uid: &str, limit: i64, offset: i64) -> Result<Vec<MutedUser>, AppError> {
+        let muted = sqlx::query_as::<_, MutedUser>(
+            r#"
+            SELECT user_uid, muted_uid, expires_at, created_at
+            FROM muted_users
+            WHERE user_uid = $1 AND (expires_at IS NULL OR expires_at > NOW())
+            ORDER BY created_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(user_uid)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(muted)
+    }
+
+    async fn mute_thread(
+        &self,
+        user_uid: &str,
+        root_post_id: Uuid,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<MutedThread, AppError> {
+        let muted = sqlx::query_as::<_, MutedThread>(
+            r#"
+            INSERT INTO muted_threads (user_uid, root_post_id, expires_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_uid, root_post_id) DO UPDATE SET expires_at = EXCLUDED.expires_at
+            RETURNING user_uid, root_post_id, expires_at, created_at
+            "#,
+        )
+        .bind(user_uid)
+        .bind(root_post_id)
+        .bind(expires_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(muted)
+    }
+
+    async fn unmute_thread(&self, user_uid: &str, root_post_id: Uuid) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM muted_threads WHERE user_uid = $1 AND root_post_id = $2")
+            .bind(user_uid)
+            .bind(root_post_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_muted_threads(&self, user_uid: &str, limit: i64, offset: i64) -> Result<Vec<MutedThread>, AppError> {
+        let muted = sqlx::query_as::<_, MutedThread>(
+            r#"
+            SELECT user_uid, root_post_id, expires_at, created_at
+            FROM muted_threads
+            WHERE user_uid = $1 AND (expires_at IS NULL OR expires_at > NOW())
+            ORDER BY created_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(user_uid)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(muted)
+    }
+
+    async fn create_word(&self, word: &MutedWord) -> Result<MutedWord, AppError> {
+        let created = sqlx::query_as::<_, MutedWord>(
+            r#"
+            INSERT INTO muted_words (id, user_uid, phrase, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, user_uid, phrase, expires_at, created_at
+            "#,
+        )
+        .bind(word.id)
+        .bind(&word.user_uid)
+        .bind(&word.phrase)
+        .bind(word.expires_at)
+        .bind(word.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_word_error)?;
+
+        Ok(created)
+    }
+
+    async fn find_word(&self, user_uid: &str, id: Uuid) -> Result<Option<MutedWord>, AppError> {
+        let word = sqlx::query_as::<_, MutedWord>(
+            r#"
+            SELECT id, user_uid, phrase, expires_at, created_at
+            FROM muted_words
+            WHERE id = $1 AND user_uid = $2
+            "#,
+        )
+        .bind(id)
+        .bind(user_uid)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(word)
+    }
+
+    async fn update_word(&self, word: &MutedWord) -> Result<MutedWord, AppError> {
+        let updated = sqlx::query_as::<_, MutedWord>(
+            r#"
+            UPDATE muted_words
+            SET phrase = $3, expires_at = $4
+            WHERE id = $1 AND user_uid = $2
+            RETURNING id, user_uid, phrase, expires_at, created_at
+            "#,
+        )
+        .bind(word.id)
+        .bind(&word.user_uid)
+        .bind(&word.phrase)
+        .bind(word.expires_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_word_error)?;
+
+        Ok(updated)
+    }
+
+    async fn delete_word(&self, user_uid: &str, id: Uuid) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM muted_words WHERE id = $1 AND user_uid = $2")
+            .bind(id)
+            .bind(user_uid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_words(&self, user_uid: &str) -> Result<Vec<MutedWord>, AppError> {
+        let words = sqlx::query_as::<_, MutedWord>(
+            r#"
+            SELECT id, user_uid, phrase, expires_at, created_at
+            FROM muted_words
+            WHERE user_uid = $1 AND (expires_at IS NULL OR expires_at > NOW())
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(user_uid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(words)
+    }
+
+    async fn count_words(&self, user_uid: &str) -> Result<i64, AppError> {
+        let result: (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM muted_words WHERE user_uid = $1 AND (expires_at IS NULL OR expires_at > NOW())",
+        )
+        .bind(user_uid)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.0)
+    }
+
+    async fn is_muted_for(
+        &self,
+        user_uid: &str,
+        actor_uid: &str,
+        root_post_id: Option<Uuid>,
+        content: Option<&str>,
+    ) -> Result<bool, AppError> {
+        let result: (bool,) = sqlx::query_as(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM muted_users
+                WHERE user_uid = $1 AND muted_uid = $2
+                  AND (expires_at IS NULL OR expires_at > NOW())
+            )
+            OR EXISTS(
+                SELECT 1 FROM muted_threads
+                WHERE user_uid = $1 AND root_post_id = $3
+                  AND (expires_at IS NULL OR expires_at > NOW())
+            )
+            OR EXISTS(
+                SELECT 1 FROM muted_words
+                WHERE user_uid = $1 AND matches_muted_phrase($4, phrase)
+                  AND (expires_at IS NULL OR expires_at > NOW())
+            )
+            "#,
+        )
+        .bind(user_uid)
+        .bind(actor_uid)
+        .bind(root_post_id)
+        .bind(content)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.0)
+    }
+}
@@ -0,0 +1,168 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::notification::{
+    Notification, NotificationActor, NotificationEvent, NotificationRepository,
+};
+use crate::error::AppError;
+
+pub struct PostgresNotificationRepository {
+    pool: PgPool,
+}
+
+impl PostgresNotificationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for PostgresNotificationRepository {
+    async fn record(&self, event: &NotificationEvent) -> Result<Notification, AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        // Rejoindre le groupe non lu existant, ou en ouvrir un nouveau
+        let (notification_id,): (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO notifications (recipient_uid, kind, target_post_id, group_key)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (recipient_uid, kind, group_key) WHERE is_read = FALSE
+            DO UPDATE SET updated_at = NOW()
+            RETURNING id
+            "#,
+        )
+        .bind(&event.recipient_uid)
+        .bind(event.kind.as_str())
+        .bind(event.target_post_id)
+        .bind(event.group_key())
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur création notification: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO notification_actors (notification_id, actor_uid, source_post_id)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (notification_id, actor_uid)
+            DO UPDATE SET source_post_id = EXCLUDED.source_post_id, created_at = NOW()
+            "#,
+        )
+        .bind(notification_id)
+        .bind(&event.actor_uid)
+        .bind(event.source_post_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        let notification = sqlx::query_as::<_, Notification>(
+            r#"
+            UPDATE notifications
+            SET actors_count = (SELECT COUNT(*) FROM notification_actors WHERE notification_id = $1),
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING id, recipient_uid, kind, target_post_id, actors_count, is_read,
+                created_at, updated_at
+            "#,
+        )
+        .bind(notification_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(notification)
+    }
+
+    async fn find_by_recipient(&self, recipient_uid: &str, limit: i64, offset: i64) -> Result<Vec<Notification>, AppError> {
+        let notifications = sqlx::query_as::<_, Notification>(
+            r#"
+            SELECT id, recipient_uid, kind, target_post_id, actors_count, is_read,
+                created_at, updated_at
+            FROM notifications
+            WHERE recipient_uid = $1
+            ORDER BY updated_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(recipient_uid)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(notifications)
+    }
+
+    async fn find_actors(&self, notification_ids: &[Uuid], per_notification: i64) -> Result<Vec<NotificationActor>, AppError> {
+        if notification_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let actors = sqlx::query_as::<_, NotificationActor>(
+            r#"
+            SELECT notification_id, actor_uid, source_post_id, created_at
+            FROM (
+                SELECT notification_id, actor_uid, source_post_id, created_at,
+                    ROW_NUMBER() OVER (PARTITION BY notification_id ORDER BY created_at DESC) AS rank
+                FROM notification_actors
+                WHERE notification_id = ANY($1)
+            ) ranked
+            WHERE rank <= $2
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(notification_ids)
+        .bind(per_notification)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(actors)
+    }
+
+    async fn count_unread(&self, recipient_uid: &str) -> Result<i64, AppError> {
+        let result: (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM notifications WHERE recipient_uid = $1 AND is_read = FALSE",
+        )
+        .bind(recipient_uid)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.0)
+    }
+
+    async fn mark_read(&self, recipient_uid: &str, id: Uuid) -> Result<bool, AppError> {
+        let result = sqlx::query(
+            "UPDATE notifications SET is_read = TRUE WHERE id = $1 AND recipient_uid = $2",
+        )
+        .bind(id)
+        .bind(recipient_uid)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn mark_all_read(&self, recipient_uid: &str) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE notifications SET is_read = TRUE WHERE recipient_uid = $1 AND is_read = FALSE",
+        )
+        .bind(recipient_uid)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::poll::{Poll, PollOption, PollRepository, PollVote};
+use crate::error::AppError;
+
+/// Crée le sondage et ses options, dans la transaction de création du post
+pub(super) async fn insert_poll(
+    conn: &mut PgConnection,
+    poll: &Poll,
+    options: &[PollOption],
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO polls (post_id, closes_at, hide_results, closed_at, created_at)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+    )
+    .bind(poll.post_id)
+    .bind(poll.closes_at)
+    .bind(poll.hide_results)
+    .bind(poll.closed_at)
+    .bind(poll.created_at)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+    for option in options {
+        sqlx::query(
+            r#"
+            INSERT INTO poll_options (post_id, position, label, votes_count)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(option.post_id)
+        .bind(option.position)
+        .bind(&option.label)
+        .bind(option.votes_count)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+    }
+
+    Ok(())
+}
+
+pub struct PostgresPollRepository {
+    pool: PgPool,
+}
+
+impl PostgresPollRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PollRepository for PostgresPollRepository {
+    async fn find_by_posts(&self, post_ids: &[Uuid]) -> Result<Vec<Poll>, AppError> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let polls = sqlx::query_as::<_, Poll>(
+            r#"
+            SELECT post_id, closes_at, hide_results, closed_at, created_at
+            FROM polls
+            WHERE post_id = ANY($1)
+            "#,
+        )
+        .bind(post_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(polls)
+    }
+
+    async fn find_options(&self, post_ids: &[Uuid]) -> Result<Vec<PollOption>, AppError> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let options = sqlx::query_as::<_, PollOption>(
+            r#"
+            SELECT post_id, position, label, votes_count
+            FROM poll_options
+            WHERE post_id = ANY($1)
+            ORDER BY post_id, position
+            "#,
+        )
+        .bind(post_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(options)
+    }
+
+    async fn find_votes(&self, user_uid: &str, post_ids: &[Uuid]) -> Result<Vec<PollVote>, AppError> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let votes = sqlx::query_as::<_, PollVote>(
+            r#"
+            SELECT post_id, position
+            FROM poll_votes
+            WHERE user_uid = $1 AND post_id = ANY($2)
+            "#,
+        )
+        .bind(user_uid)
+        .bind(post_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(votes)
+    }
+
+    async fn vote(&self, post_id: Uuid, user_uid: &str, position: i16) -> Result<bool, AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        // La clé primaire (post_id, user_uid) rejette un second vote
+        let result = sqlx::query(
+            r#"
+            INSERT INTO poll_votes (post_id, user_uid, position)
+            SELECT post_id, $2, $3
+            FROM polls
+            WHERE post_id = $1 AND closed_at IS NULL AND closes_at > NOW()
+            ON CONFLICT (post_id, user_uid) DO NOTHING
+            "#,
+        )
+        .bind(post_id)
+        .bind(user_uid)
+        .bind(position)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            "UPDATE poll_options SET votes_count = votes_count + 1 WHERE post_id = $1 AND position = $2",
+        )
+        .bind(post_id)
+        .bind(position)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(true)
+    }
+
+    async fn close_expired(&self, limit: i64) -> Result<Vec<Uuid>, AppError> {
+        let closed: Vec<(Uuid,)> = sqlx::query_as(
+            r#"
+            UPDATE polls
+            SET closed_at = closes_at
+            WHERE post_id IN (
+                SELECT post_id FROM polls
+                WHERE closed_at IS NULL AND closes_at <= NOW()
+                ORDER BY closes_at
+                LIMIT $1
+            )
+            RETURNING post_id
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(closed.into_iter().map(|(post_id,)| post_id).collect())
+    }
+}
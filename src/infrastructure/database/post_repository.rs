@@ -1,10 +1,131 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
-use crate::domain::post::{Post, PostRepository};
+use super::media_repository::attach_media;
+use super::poll_repository::insert_poll;
+use crate::domain::post::{
+    LabelSource, Post, PostHashtag, PostLabel, PostMention, PostRepository, PostSearchHit,
+    PostSearchQuery, PostViewerState, Publication, SearchCursor, SearchSort,
+};
 use crate::error::AppError;
 
+const POST_COLUMNS: &str = "posts.id, posts.author_uid, posts.content, posts.likes_count, \
+    posts.replies_count, posts.reposts_count, posts.parent_id, posts.root_id, \
+    posts.created_at, posts.updated_at, posts.reply_policy, posts.is_hidden, posts.audience";
+
+/// Exclut les posts dont l'auteur a bloqué le lecteur ($1) ou a été bloqué par lui.
+/// Sans lecteur ($1 NULL), aucune ligne ne correspond et rien n'est exclu.
+const BLOCK_FILTER: &str = r#"
+    NOT EXISTS (
+        SELECT 1 FROM blocks b
+        WHERE (b.blocker_uid = posts.author_uid AND b.blocked_uid = $1)
+           OR (b.blocker_uid = $1 AND b.blocked_uid = posts.author_uid)
+    )
+"#;
+
+/// Exclut les comptes, fils de discussion et mots masqués par le lecteur ($1),
+/// en ignorant les masquages expirés.
+const MUTE_FILTER: &str = r#"
+    NOT EXISTS (
+        SELECT 1 FROM muted_users m
+        WHERE m.user_uid = $1 AND m.muted_uid = posts.author_uid
+          AND (m.expires_at IS NULL OR m.expires_at > NOW())
+    )
+    AND NOT EXISTS (
+        SELECT 1 FROM muted_threads t
+        WHERE t.user_uid = $1 AND t.root_post_id = COALESCE(posts.root_id, posts.id)
+          AND (t.expires_at IS NULL OR t.expires_at > NOW())
+    )
+    AND NOT EXISTS (
+        SELECT 1 FROM muted_words w
+        WHERE w.user_uid = $1 AND posts.author_uid <> $1
+          AND matches_muted_phrase(posts.content, w.phrase)
+          AND (w.expires_at IS NULL OR w.expires_at > NOW())
+    )
+"#;
+
+/// Exclut les posts avec avertissement si le lecteur ($1) a choisi de les cacher,
+/// sauf les siens
+const LABEL_FILTER: &str = r#"
+    NOT EXISTS (
+        SELECT 1 FROM post_labels pl
+        JOIN user_settings ls ON ls.user_uid = $1 AND ls.sensitive_content = 'hide'
+        WHERE pl.post_id = posts.id AND posts.author_uid <> $1
+    )
+"#;
+
+/// Exclut les posts des comptes privés, sauf pour leur auteur et ses abonnés ($1)
+const PRIVACY_FILTER: &str = r#"
+    (
+        NOT EXISTS (SELECT 1 FROM users pu WHERE pu.uid = posts.author_uid AND pu.is_private)
+        OR posts.author_uid = $1
+        OR EXISTS (
+            SELECT 1 FROM follows pf
+            WHERE pf.follower_uid = $1 AND pf.followee_uid = posts.author_uid
+        )
+    )
+"#;
+
+/// Exclut les posts dont l'audience n'inclut pas le lecteur ($1), ainsi que les
+/// réponses dont le post racine ne lui est pas visible. Sans lecteur, seuls les
+/// posts publics restent.
+const AUDIENCE_FILTER: &str = r#"
+    (
+        posts.audience = 'public'
+        OR posts.author_uid = $1
+        OR (posts.audience = 'followers' AND EXISTS (
+            SELECT 1 FROM follows af
+            WHERE af.follower_uid = $1 AND af.followee_uid = posts.author_uid
+        ))
+        OR (posts.audience = 'close_friends' AND EXISTS (
+            SELECT 1 FROM close_friends acf
+            WHERE acf.user_uid = posts.author_uid AND acf.friend_uid = $1
+        ))
+    )
+    AND NOT EXISTS (
+        SELECT 1 FROM posts ar
+        WHERE ar.id = posts.root_id
+          AND ar.audience <> 'public'
+          AND ar.author_uid IS DISTINCT FROM $1
+          AND NOT (ar.audience = 'followers' AND EXISTS (
+              SELECT 1 FROM follows arf
+              WHERE arf.follower_uid = $1 AND arf.followee_uid = ar.author_uid
+          ))
+          AND NOT (ar.audience = 'close_friends' AND EXISTS (
+              SELECT 1 FROM close_friends arcf
+              WHERE arcf.user_uid = ar.author_uid AND arcf.friend_uid = $1
+          ))
+    )
+"#;
+
+/// Remplace l'avertissement de même origine
+async fn upsert_label(executor: impl PgExecutor<'_>, label: &PostLabel) -> Result<PostLabel, AppError> {
+    let saved = sqlx::query_as::<_, PostLabel>(
+        r#"
+        INSERT INTO post_labels (post_id, source, content_warning, is_sensitive, labeled_by, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (post_id, source)
+        DO UPDATE SET content_warning = EXCLUDED.content_warning,
+            is_sensitive = EXCLUDED.is_sensitive, labeled_by = EXCLUDED.labeled_by,
+            created_at = EXCLUDED.created_at
+        RETURNING post_id, source, content_warning, is_sensitive, labeled_by, created_at
+        "#,
+    )
+    .bind(label.post_id)
+    .bind(&label.source)
+    .bind(&label.content_warning)
+    .bind(label.is_sensitive)
+    .bind(&label.labeled_by)
+    .bind(label.created_at)
+    .fetch_one(executor)
+    .await
+    .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+    Ok(saved)
+}
+
 pub struct PostgresPostRepository {
     pool: PgPool,
 }
@@ -17,16 +138,23 @@ impl PostgresPostRepository {
 
 #[async_trait]
 impl PostRepository for PostgresPostRepository {
-    async fn create(&self, post: &Post) -> Result<Post, AppError> {
-        let created = sqlx::query_as::<_, Post>(
+    async fn create(&self, publication: &Publication) -> Result<Post, AppError> {
+        let post = &publication.post;
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        let created = sqlx::query_as::<_, Post>(&format!(
             r#"
             INSERT INTO posts (id, author_uid, content, likes_count, replies_count,
-                reposts_count, parent_id, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-            RETURNING id, author_uid, content, likes_count, replies_count,
-                reposts_count, parent_id, created_at, updated_at
-            "#,
-        )
+                reposts_count, parent_id, root_id, created_at, updated_at, reply_policy, is_hidden,
+                audience)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            RETURNING {POST_COLUMNS}
+            "#
+        ))
         .bind(post.id)
         .bind(&post.author_uid)
         .bind(&post.content)
@@ -34,38 +162,119 @@ impl PostRepository for PostgresPostRepository {
         .bind(post.replies_count)
         .bind(post.reposts_count)
         .bind(post.parent_id)
+        .bind(post.root_id)
         .bind(post.created_at)
         .bind(post.updated_at)
-        .fetch_one(&self.pool)
+        .bind(&post.reply_policy)
+        .bind(post.is_hidden)
+        .bind(&post.audience)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| AppError::Internal(format!("Erreur création post: {}", e)))?;
 
         // Incrémenter le compteur de posts de l'utilisateur
         sqlx::query("UPDATE users SET posts_count = posts_count + 1 WHERE uid = $1")
             .bind(&post.author_uid)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await
-            .ok();
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
 
         // Si c'est une réponse, incrémenter le compteur de réponses du parent
         if let Some(parent_id) = post.parent_id {
-            self.increment_replies(parent_id).await.ok();
+            sqlx::query("UPDATE posts SET replies_count = replies_count + 1 WHERE id = $1")
+                .bind(parent_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+        }
+
+        attach_media(
+            &mut tx,
+            post.id,
+            &post.author_uid,
+            &publication.attachments,
+            publication.draft_id,
+        )
+        .await?;
+        if let Some((poll, options)) = &publication.poll {
+            insert_poll(&mut tx, poll, options).await?;
+        }
+        if let Some(label) = &publication.label {
+            upsert_label(&mut *tx, label).await?;
         }
 
+        if !publication.mentions.is_empty() {
+            let uids: Vec<&str> = publication.mentions.iter().map(|m| m.mentioned_uid.as_str()).collect();
+            let starts: Vec<i32> = publication.mentions.iter().map(|m| m.start_offset).collect();
+            let ends: Vec<i32> = publication.mentions.iter().map(|m| m.end_offset).collect();
+
+            sqlx::query(
+                r#"
+                INSERT INTO post_mentions (post_id, mentioned_uid, start_offset, end_offset)
+                SELECT $1, * FROM UNNEST($2::VARCHAR[], $3::INTEGER[], $4::INTEGER[])
+                ON CONFLICT DO NOTHING
+                "#,
+            )
+            .bind(post.id)
+            .bind(&uids)
+            .bind(&starts)
+            .bind(&ends)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+        }
+
+        if !publication.hashtags.is_empty() {
+            let tags: Vec<&str> = publication.hashtags.iter().map(|h| h.tag.as_str()).collect();
+            let starts: Vec<i32> = publication.hashtags.iter().map(|h| h.start_offset).collect();
+            let ends: Vec<i32> = publication.hashtags.iter().map(|h| h.end_offset).collect();
+
+            sqlx::query(
+                r#"
+                INSERT INTO post_hashtags (post_id, tag, start_offset, end_offset)
+                SELECT $1, * FROM UNNEST($2::VARCHAR[], $3::INTEGER[], $4::INTEGER[])
+                ON CONFLICT DO NOTHING
+                "#,
+            )
+            .bind(post.id)
+            .bind(&tags)
+            .bind(&starts)
+            .bind(&ends)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+        }
+
+        if let Some(draft_id) = publication.draft_id {
+            let deleted = sqlx::query("DELETE FROM post_drafts WHERE id = $1")
+                .bind(draft_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?
+                .rows_affected();
+            // Brouillon annulé ou déjà publié par une autre instance
+            if deleted == 0 {
+                return Err(AppError::Conflict("Ce brouillon n'existe plus".into()));
+            }
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
         tracing::info!("Post créé: {} par {}", created.id, created.author_uid);
 
         Ok(created)
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Post>, AppError> {
-        let post = sqlx::query_as::<_, Post>(
+        let post = sqlx::query_as::<_, Post>(&format!(
             r#"
-            SELECT id, author_uid, content, likes_count, replies_count,
-                reposts_count, parent_id, created_at, updated_at
+            SELECT {POST_COLUMNS}
             FROM posts
             WHERE id = $1
-            "#,
-        )
+            "#
+        ))
         .bind(id)
         .fetch_optional(&self.pool)
         .await
@@ -74,20 +283,62 @@ impl PostRepository for PostgresPostRepository {
         Ok(post)
     }
 
-    async fn find_by_author(&self, author_uid: &str, limit: i64, offset: i64) -> Result<Vec<Post>, AppError> {
-        let posts = sqlx::query_as::<_, Post>(
+    async fn is_visible_to(&self, id: Uuid, viewer_uid: Option<&str>) -> Result<bool, AppError> {
+        let result: (bool,) = sqlx::query_as(&format!(
+            "SELECT EXISTS(SELECT 1 FROM posts WHERE posts.id = $2 AND {AUDIENCE_FILTER} AND {PRIVACY_FILTER})"
+        ))
+        .bind(viewer_uid)
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.0)
+    }
+
+    async fn is_deliverable_to(&self, id: Uuid, viewer_uid: &str) -> Result<bool, AppError> {
+        let result: (bool,) = sqlx::query_as(&format!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM posts
+                WHERE posts.id = $2 AND {AUDIENCE_FILTER} AND {PRIVACY_FILTER}
+                  AND {BLOCK_FILTER} AND {MUTE_FILTER} AND {LABEL_FILTER}
+            )
+            "#
+        ))
+        .bind(viewer_uid)
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.0)
+    }
+
+    async fn find_by_author(
+        &self,
+        author_uid: &str,
+        viewer_uid: Option<&str>,
+        excluded_id: Option<Uuid>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Post>, AppError> {
+        let posts = sqlx::query_as::<_, Post>(&format!(
             r#"
-            SELECT id, author_uid, content, likes_count, replies_count,
-                reposts_count, parent_id, created_at, updated_at
+            SELECT {POST_COLUMNS}
             FROM posts
-            WHERE author_uid = $1 AND parent_id IS NULL
+            WHERE author_uid = $2 AND parent_id IS NULL AND posts.id IS DISTINCT FROM $5
+              AND {AUDIENCE_FILTER} AND {PRIVACY_FILTER}
+              AND {BLOCK_FILTER} AND {LABEL_FILTER}
             ORDER BY created_at DESC
-            LIMIT $2 OFFSET $3
-            "#,
-        )
+            LIMIT $3 OFFSET $4
+            "#
+        ))
+        .bind(viewer_uid)
         .bind(author_uid)
         .bind(limit)
         .bind(offset)
+        .bind(excluded_id)
         .fetch_all(&self.pool)
         .await
         .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
@@ -95,17 +346,18 @@ impl PostRepository for PostgresPostRepository {
         Ok(posts)
     }
 
-    async fn get_timeline(&self, limit: i64, offset: i64) -> Result<Vec<Post>, AppError> {
-        let posts = sqlx::query_as::<_, Post>(
+    async fn get_timeline(&self, viewer_uid: Option<&str>, limit: i64, offset: i64) -> Result<Vec<Post>, AppError> {
+        let posts = sqlx::query_as::<_, Post>(&format!(
             r#"
-            SELECT id, author_uid, content, likes_count, replies_count,
-                reposts_count, parent_id, created_at, updated_at
+            SELECT {POST_COLUMNS}
             FROM posts
-            WHERE parent_id IS NULL
+            WHERE parent_id IS NULL AND {AUDIENCE_FILTER} AND {PRIVACY_FILTER}
+              AND {BLOCK_FILTER} AND {MUTE_FILTER} AND {LABEL_FILTER}
             ORDER BY created_at DESC
-            LIMIT $1 OFFSET $2
-            "#,
-        )
+            LIMIT $2 OFFSET $3
+            "#
+        ))
+        .bind(viewer_uid)
         .bind(limit)
         .bind(offset)
         .fetch_all(&self.pool)
@@ -115,17 +367,24 @@ impl PostRepository for PostgresPostRepository {
         Ok(posts)
     }
 
-    async fn get_replies(&self, parent_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Post>, AppError> {
-        let posts = sqlx::query_as::<_, Post>(
+    async fn get_replies(
+        &self,
+        parent_id: Uuid,
+        viewer_uid: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Post>, AppError> {
+        let posts = sqlx::query_as::<_, Post>(&format!(
             r#"
-            SELECT id, author_uid, content, likes_count, replies_count,
-                reposts_count, parent_id, created_at, updated_at
+            SELECT {POST_COLUMNS}
             FROM posts
-            WHERE parent_id = $1
-            ORDER BY created_at ASC
-            LIMIT $2 OFFSET $3
-            "#,
-        )
+            WHERE parent_id = $2 AND {AUDIENCE_FILTER} AND {PRIVACY_FILTER}
+              AND {BLOCK_FILTER} AND {MUTE_FILTER} AND {LABEL_FILTER}
+            ORDER BY is_hidden ASC, created_at ASC
+            LIMIT $3 OFFSET $4
+            "#
+        ))
+        .bind(viewer_uid)
         .bind(parent_id)
         .bind(limit)
         .bind(offset)
@@ -136,6 +395,49 @@ impl PostRepository for PostgresPostRepository {
         Ok(posts)
     }
 
+    async fn find_bookmarked(
+        &self,
+        user_uid: &str,
+        folder_id: Option<Uuid>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Post>, AppError> {
+        let posts = sqlx::query_as::<_, Post>(&format!(
+            r#"
+            SELECT {POST_COLUMNS}
+            FROM posts
+            JOIN bookmarks bm ON bm.post_id = posts.id
+            WHERE bm.user_uid = $1
+              AND ($2::UUID IS NULL OR bm.folder_id = $2)
+              AND {AUDIENCE_FILTER} AND {PRIVACY_FILTER}
+              AND {BLOCK_FILTER} AND {LABEL_FILTER}
+            ORDER BY bm.created_at DESC
+            LIMIT $3 OFFSET $4
+            "#
+        ))
+        .bind(user_uid)
+        .bind(folder_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(posts)
+    }
+
+    async fn count_by_author_since(&self, author_uid: &str, since: DateTime<Utc>) -> Result<i64, AppError> {
+        let result: (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM posts WHERE author_uid = $1 AND created_at >= $2")
+                .bind(author_uid)
+                .bind(since)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(result.0)
+    }
+
     async fn delete(&self, id: Uuid) -> Result<(), AppError> {
         // Récupérer le post pour décrémenter le compteur de l'utilisateur
         if let Some(post) = self.find_by_id(id).await? {
@@ -187,4 +489,272 @@ impl PostRepository for PostgresPostRepository {
 
         Ok(())
     }
+
+    async fn set_hidden(&self, id: Uuid, hidden: bool) -> Result<(), AppError> {
+        sqlx::query("UPDATE posts SET is_hidden = $2 WHERE id = $1")
+            .bind(id)
+            .bind(hidden)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn add_like(&self, user_uid: &str, post_id: Uuid) -> Result<bool, AppError> {
+        let inserted = sqlx::query(
+            "INSERT INTO likes (user_uid, post_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+        )
+        .bind(user_uid)
+        .bind(post_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?
+        .rows_affected()
+            > 0;
+
+        if inserted {
+            self.increment_likes(post_id).await?;
+        }
+
+        Ok(inserted)
+    }
+
+    async fn remove_like(&self, user_uid: &str, post_id: Uuid) -> Result<bool, AppError> {
+        let deleted = sqlx::query("DELETE FROM likes WHERE user_uid = $1 AND post_id = $2")
+            .bind(user_uid)
+            .bind(post_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?
+            .rows_affected()
+            > 0;
+
+        if deleted {
+            self.decrement_likes(post_id).await?;
+        }
+
+        Ok(deleted)
+    }
+
+    async fn find_mentions(&self, post_ids: &[Uuid]) -> Result<Vec<PostMention>, AppError> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mentions = sqlx::query_as::<_, PostMention>(
+            r#"
+            SELECT m.post_id, m.mentioned_uid, u.username, m.start_offset, m.end_offset
+            FROM post_mentions m
+            JOIN users u ON u.uid = m.mentioned_uid
+            WHERE m.post_id = ANY($1)
+            ORDER BY m.start_offset ASC
+            "#,
+        )
+        .bind(post_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(mentions)
+    }
+
+    async fn find_mentioning(
+        &self,
+        mentioned_uid: &str,
+        viewer_uid: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Post>, AppError> {
+        let posts = sqlx::query_as::<_, Post>(&format!(
+            r#"
+            SELECT {POST_COLUMNS}
+            FROM posts
+            WHERE posts.id IN (SELECT post_id FROM post_mentions WHERE mentioned_uid = $2)
+              AND {AUDIENCE_FILTER} AND {PRIVACY_FILTER}
+              AND {BLOCK_FILTER} AND {MUTE_FILTER} AND {LABEL_FILTER}
+            ORDER BY posts.created_at DESC
+            LIMIT $3 OFFSET $4
+            "#
+        ))
+        .bind(viewer_uid)
+        .bind(mentioned_uid)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(posts)
+    }
+
+    async fn find_hashtags(&self, post_ids: &[Uuid]) -> Result<Vec<PostHashtag>, AppError> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let hashtags = sqlx::query_as::<_, PostHashtag>(
+            r#"
+            SELECT post_id, tag, start_offset, end_offset
+            FROM post_hashtags
+            WHERE post_id = ANY($1)
+            ORDER BY start_offset ASC
+            "#,
+        )
+        .bind(post_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(hashtags)
+    }
+
+    async fn find_by_hashtag(
+        &self,
+        tag: &str,
+        viewer_uid: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Post>, AppError> {
+        let posts = sqlx::query_as::<_, Post>(&format!(
+            r#"
+            SELECT {POST_COLUMNS}
+            FROM posts
+            WHERE posts.id IN (SELECT post_id FROM post_hashtags WHERE tag = $2)
+              AND {AUDIENCE_FILTER} AND {PRIVACY_FILTER}
+              AND {BLOCK_FILTER} AND {MUTE_FILTER} AND {LABEL_FILTER}
+            ORDER BY posts.created_at DESC
+            LIMIT $3 OFFSET $4
+            "#
+        ))
+        .bind(viewer_uid)
+        .bind(tag)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(posts)
+    }
+
+    async fn set_label(&self, label: &PostLabel) -> Result<PostLabel, AppError> {
+        upsert_label(&self.pool, label).await
+    }
+
+    async fn remove_label(&self, post_id: Uuid, source: LabelSource) -> Result<bool, AppError> {
+        let deleted = sqlx::query("DELETE FROM post_labels WHERE post_id = $1 AND source = $2")
+            .bind(post_id)
+            .bind(source.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?
+            .rows_affected()
+            > 0;
+
+        Ok(deleted)
+    }
+
+    async fn find_labels(&self, post_ids: &[Uuid]) -> Result<Vec<PostLabel>, AppError> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let labels = sqlx::query_as::<_, PostLabel>(
+            r#"
+            SELECT post_id, source, content_warning, is_sensitive, labeled_by, created_at
+            FROM post_labels
+            WHERE post_id = ANY($1)
+            ORDER BY source ASC
+            "#,
+        )
+        .bind(post_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(labels)
+    }
+
+    async fn get_viewer_states(&self, viewer_uid: &str, post_ids: &[Uuid]) -> Result<Vec<PostViewerState>, AppError> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let states = sqlx::query_as::<_, PostViewerState>(
+            r#"
+            SELECT p.id AS post_id,
+                EXISTS(SELECT 1 FROM likes WHERE post_id = p.id AND user_uid = $1) AS liked,
+                EXISTS(SELECT 1 FROM reposts WHERE post_id = p.id AND user_uid = $1) AS reposted,
+                EXISTS(SELECT 1 FROM bookmarks WHERE post_id = p.id AND user_uid = $1) AS bookmarked
+            FROM posts p
+            WHERE p.id = ANY($2)
+            "#,
+        )
+        .bind(viewer_uid)
+        .bind(post_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(states)
+    }
+
+    async fn search(
+        &self,
+        query: &PostSearchQuery,
+        sort: SearchSort,
+        cursor: Option<&SearchCursor>,
+        viewer_uid: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<PostSearchHit>, AppError> {
+        let order = match sort {
+            SearchSort::Relevance => "hits.rank DESC, hits.id DESC",
+            SearchSort::Recent => "hits.created_at DESC, hits.id DESC",
+        };
+
+        let (cursor_rank, cursor_created_at, cursor_id) = match cursor {
+            Some(SearchCursor::Relevance { rank, id }) => (Some(*rank), None, Some(*id)),
+            Some(SearchCursor::Recent { created_at, id }) => (None, Some(*created_at), Some(*id)),
+            None => (None, None, None),
+        };
+
+        let hits = sqlx::query_as::<_, PostSearchHit>(&format!(
+            r#"
+            SELECT * FROM (
+                SELECT {POST_COLUMNS},
+                    COALESCE(ts_rank(posts.search_vector, tsq), 0)::REAL AS rank
+                FROM posts, websearch_to_tsquery('simple', $2::TEXT) AS tsq
+                WHERE ($2::TEXT IS NULL OR posts.search_vector @@ tsq)
+                  AND ($3::TEXT IS NULL OR posts.author_uid IN (
+                      SELECT uid FROM users WHERE LOWER(username) = LOWER($3)
+                  ))
+                  AND ($4::TIMESTAMPTZ IS NULL OR posts.created_at >= $4)
+                  AND ($5::TIMESTAMPTZ IS NULL OR posts.created_at < $5)
+                  AND (NOT $6::BOOLEAN OR posts.replies_count > 0)
+                  AND {PRIVACY_FILTER} AND {AUDIENCE_FILTER}
+                  AND {BLOCK_FILTER} AND {MUTE_FILTER} AND {LABEL_FILTER}
+            ) hits
+            WHERE ($7::REAL IS NULL OR (hits.rank, hits.id) < ($7, $9::UUID))
+              AND ($8::TIMESTAMPTZ IS NULL OR (hits.created_at, hits.id) < ($8, $9::UUID))
+            ORDER BY {order}
+            LIMIT $10
+            "#
+        ))
+        .bind(viewer_uid)
+        .bind(&query.text)
+        .bind(&query.from_username)
+        .bind(query.since)
+        .bind(query.until)
+        .bind(query.has_replies)
+        .bind(cursor_rank)
+        .bind(cursor_created_at)
+        .bind(cursor_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(hits)
+    }
 }
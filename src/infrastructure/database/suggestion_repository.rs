@@ -0,0 +1,187 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::time::Duration;
+
+use crate::domain::suggestion::{FollowSuggestion, SuggestionReason, SuggestionRepository};
+use crate::error::AppError;
+
+/// Comptes que l'utilisateur ($1) ne peut pas se voir suggérer : lui-même,
+/// les comptes déjà suivis, bloqués dans un sens ou dans l'autre, ou masqués.
+const EXCLUSION_FILTER: &str = r#"
+    candidate_uid <> $1
+    AND NOT EXISTS (
+        SELECT 1 FROM follows f
+        WHERE f.follower_uid = $1 AND f.followee_uid = candidate_uid
+    )
+    AND NOT EXISTS (
+        SELECT 1 FROM blocks b
+        WHERE (b.blocker_uid = candidate_uid AND b.blocked_uid = $1)
+           OR (b.blocker_uid = $1 AND b.blocked_uid = candidate_uid)
+    )
+    AND NOT EXISTS (
+        SELECT 1 FROM muted_users m
+        WHERE m.user_uid = $1 AND m.muted_uid = candidate_uid
+          AND (m.expires_at IS NULL OR m.expires_at > NOW())
+    )
+"#;
+
+pub struct PostgresSuggestionRepository {
+    pool: PgPool,
+}
+
+impl PostgresSuggestionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SuggestionRepository for PostgresSuggestionRepository {
+    async fn refresh(&self, user_uid: &str, limit: i64) -> Result<(), AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        sqlx::query("DELETE FROM user_suggestions WHERE user_uid = $1")
+            .bind(user_uid)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        // Chaque signal apporte un score ; la raison affichée est celle du signal
+        // le plus fort. Les comptes vérifiés servent de repli pour un graphe vide.
+        sqlx::query(&format!(
+            r#"
+            INSERT INTO user_suggestions (user_uid, suggested_uid, reason, mutual_count, score)
+            SELECT $1, candidate_uid,
+                (ARRAY_AGG(reason ORDER BY score DESC))[1],
+                MAX(mutual_count),
+                SUM(score)
+            FROM (
+                SELECT f2.followee_uid AS candidate_uid, $2::TEXT AS reason,
+                    COUNT(*) AS mutual_count, COUNT(*) * 3.0 AS score
+                FROM follows f1
+                JOIN follows f2 ON f2.follower_uid = f1.followee_uid
+                WHERE f1.follower_uid = $1
+                GROUP BY f2.followee_uid
+
+                UNION ALL
+
+                SELECT p.author_uid, $3::TEXT, 0, COUNT(DISTINCT l.user_uid) * 1.0
+                FROM follows f
+                JOIN likes l ON l.user_uid = f.followee_uid
+                JOIN posts p ON p.id = l.post_id
+                WHERE f.follower_uid = $1
+                  AND l.created_at > NOW() - INTERVAL '30 days'
+                GROUP BY p.author_uid
+
+                UNION ALL
+
+                (
+                    SELECT uid, $4::TEXT, 0, LN(followers_count + 2) / 10.0
+                    FROM users
+                    WHERE is_verified
+                    ORDER BY followers_count DESC
+                    LIMIT 100
+                )
+            ) candidates
+            WHERE {EXCLUSION_FILTER}
+            GROUP BY candidate_uid
+            ORDER BY SUM(score) DESC
+            LIMIT $5
+            "#
+        ))
+        .bind(user_uid)
+        .bind(SuggestionReason::FollowedByFollowing.as_str())
+        .bind(SuggestionReason::PopularInNetwork.as_str())
+        .bind(SuggestionReason::Verified.as_str())
+        .bind(limit)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur calcul suggestions: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO user_suggestion_runs (user_uid, computed_at)
+            VALUES ($1, NOW())
+            ON CONFLICT (user_uid) DO UPDATE SET computed_at = NOW()
+            "#,
+        )
+        .bind(user_uid)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn find_fresh(
+        &self,
+        user_uid: &str,
+        max_age: Duration,
+        limit: i64,
+    ) -> Result<Option<Vec<FollowSuggestion>>, AppError> {
+        let is_fresh: (bool,) = sqlx::query_as(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM user_suggestion_runs
+                WHERE user_uid = $1 AND computed_at > NOW() - make_interval(secs => $2)
+            )
+            "#,
+        )
+        .bind(user_uid)
+        .bind(max_age.as_secs_f64())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        if !is_fresh.0 {
+            return Ok(None);
+        }
+
+        let suggestions = sqlx::query_as::<_, FollowSuggestion>(&format!(
+            r#"
+            SELECT user_uid, suggested_uid, reason, mutual_count, score
+            FROM (
+                SELECT *, suggested_uid AS candidate_uid
+                FROM user_suggestions
+                WHERE user_uid = $1
+            ) cached
+            WHERE {EXCLUSION_FILTER}
+            ORDER BY score DESC, suggested_uid ASC
+            LIMIT $2
+            "#
+        ))
+        .bind(user_uid)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(Some(suggestions))
+    }
+
+    async fn find_stale_users(&self, max_age: Duration, limit: i64) -> Result<Vec<String>, AppError> {
+        let uids: Vec<(String,)> = sqlx::query_as(
+            r#"
+            SELECT user_uid FROM user_suggestion_runs
+            WHERE computed_at <= NOW() - make_interval(secs => $1)
+            ORDER BY computed_at ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(max_age.as_secs_f64())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(uids.into_iter().map(|(uid,)| uid).collect())
+    }
+}
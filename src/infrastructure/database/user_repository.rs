@@ -1,9 +1,13 @@
 use async_trait::async_trait;
 use sqlx::PgPool;
+use uuid::Uuid;
 
-use crate::domain::user::{User, UserRepository};
+use crate::domain::user::{ProfileImageKind, User, UserRepository};
 use crate::error::AppError;
 
+/// Longueur minimale d'un terme pour chercher aussi dans le nom affiché
+const MIN_DISPLAY_NAME_SEARCH_CHARS: usize = 3;
+
 pub struct PostgresUserRepository {
     pool: PgPool,
 }
@@ -19,9 +23,9 @@ impl UserRepository for PostgresUserRepository {
     async fn find_by_uid(&self, uid: &str) -> Result<Option<User>, AppError> {
         let user = sqlx::query_as::<_, User>(
             r#"
-            SELECT uid, email, username, display_name, bio, avatar_url,
+            SELECT uid, email, username, display_name, bio, avatar_url, banner_url, pinned_post_id,
                 followers_count, following_count, posts_count,
-                is_verified, is_private, created_at, updated_at
+                is_verified, is_private, is_staff, created_at, updated_at
             FROM users
             WHERE uid = $1
             "#,
@@ -34,12 +38,34 @@ impl UserRepository for PostgresUserRepository {
         Ok(user)
     }
 
+    async fn find_by_uids(&self, uids: &[String]) -> Result<Vec<User>, AppError> {
+        if uids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let users = sqlx::query_as::<_, User>(
+            r#"
+            SELECT uid, email, username, display_name, bio, avatar_url, banner_url, pinned_post_id,
+                followers_count, following_count, posts_count,
+                is_verified, is_private, is_staff, created_at, updated_at
+            FROM users
+            WHERE uid = ANY($1)
+            "#,
+        )
+        .bind(uids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(users)
+    }
+
     async fn find_by_username(&self, username: &str) -> Result<Option<User>, AppError> {
         let user = sqlx::query_as::<_, User>(
             r#"
-            SELECT uid, email, username, display_name, bio, avatar_url,
+            SELECT uid, email, username, display_name, bio, avatar_url, banner_url, pinned_post_id,
                 followers_count, following_count, posts_count,
-                is_verified, is_private, created_at, updated_at
+                is_verified, is_private, is_staff, created_at, updated_at
             FROM users
             WHERE LOWER(username) = LOWER($1)
             "#,
@@ -59,9 +85,9 @@ impl UserRepository for PostgresUserRepository {
                 followers_count, following_count, posts_count, is_verified, is_private,
                 created_at, updated_at)
             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
-            RETURNING uid, email, username, display_name, bio, avatar_url,
+            RETURNING uid, email, username, display_name, bio, avatar_url, banner_url, pinned_post_id,
                 followers_count, following_count, posts_count,
-                is_verified, is_private, created_at, updated_at
+                is_verified, is_private, is_staff, created_at, updated_at
             "#,
         )
         .bind(&user.uid)
@@ -103,17 +129,16 @@ impl UserRepository for PostgresUserRepository {
         let updated = sqlx::query_as::<_, User>(
             r#"
             UPDATE users
-            SET display_name = $2, bio = $3, avatar_url = $4, updated_at = NOW()
+            SET display_name = $2, bio = $3, updated_at = NOW()
             WHERE uid = $1
-            RETURNING uid, email, username, display_name, bio, avatar_url,
+            RETURNING uid, email, username, display_name, bio, avatar_url, banner_url, pinned_post_id,
                 followers_count, following_count, posts_count,
-                is_verified, is_private, created_at, updated_at
+                is_verified, is_private, is_staff, created_at, updated_at
             "#,
         )
         .bind(&user.uid)
         .bind(&user.display_name)
         .bind(&user.bio)
-        .bind(&user.avatar_url)
         .fetch_one(&self.pool)
         .await
         .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
@@ -144,4 +169,108 @@ impl UserRepository for PostgresUserRepository {
 
         Ok(result.0)
     }
+
+    async fn search(&self, term: &str, viewer_uid: Option<&str>, limit: i64) -> Result<Vec<User>, AppError> {
+        let escaped = escape_like(term);
+        // En dessous de 3 caractères, une recherche dans le nom affiché correspond à
+        // presque tous les comptes et ne peut pas utiliser l'index trigramme :
+        // seul le préfixe du username est cherché
+        let display_name_pattern =
+            (term.chars().count() >= MIN_DISPLAY_NAME_SEARCH_CHARS).then(|| format!("%{}%", escaped));
+
+        // Username ou nom affiché exact d'abord, puis comptes vérifiés et les plus suivis
+        let users = sqlx::query_as::<_, User>(
+            r#"
+            SELECT uid, email, username, display_name, bio, avatar_url, banner_url, pinned_post_id,
+                followers_count, following_count, posts_count,
+                is_verified, is_private, is_staff, created_at, updated_at
+            FROM users
+            WHERE (
+                LOWER(immutable_unaccent(username)) LIKE LOWER(immutable_unaccent($2))
+                OR ($3::text IS NOT NULL
+                    AND LOWER(immutable_unaccent(display_name)) LIKE LOWER(immutable_unaccent($3)))
+            )
+              AND NOT EXISTS (
+                SELECT 1 FROM blocks b
+                WHERE (b.blocker_uid = users.uid AND b.blocked_uid = $1)
+                   OR (b.blocker_uid = $1 AND b.blocked_uid = users.uid)
+              )
+            ORDER BY (
+                    LOWER(immutable_unaccent(username)) = LOWER(immutable_unaccent($4))
+                    OR LOWER(immutable_unaccent(display_name)) = LOWER(immutable_unaccent($4))
+                ) DESC,
+                is_verified DESC,
+                followers_count DESC,
+                username ASC
+            LIMIT $5
+            "#,
+        )
+        .bind(viewer_uid)
+        .bind(format!("{}%", escaped))
+        .bind(display_name_pattern)
+        .bind(term)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(users)
+    }
+
+    async fn set_pinned_post(&self, uid: &str, post_id: Option<Uuid>) -> Result<(), AppError> {
+        sqlx::query("UPDATE users SET pinned_post_id = $2, updated_at = NOW() WHERE uid = $1")
+            .bind(uid)
+            .bind(post_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn set_profile_image(
+        &self,
+        uid: &str,
+        kind: ProfileImageKind,
+        url: &str,
+        storage_key: &str,
+    ) -> Result<Option<String>, AppError> {
+        let (url_column, key_column) = match kind {
+            ProfileImageKind::Avatar => ("avatar_url", "avatar_key"),
+            ProfileImageKind::Banner => ("banner_url", "banner_key"),
+        };
+
+        // Le verrou de la ligne évite que deux envois simultanés perdent la trace d'un fichier remplacé
+        let previous: Option<(Option<String>,)> = sqlx::query_as(&format!(
+            r#"
+            UPDATE users
+            SET {url_column} = $2, {key_column} = $3, updated_at = NOW()
+            FROM (SELECT {key_column} AS old_key FROM users WHERE uid = $1 FOR UPDATE) previous
+            WHERE users.uid = $1
+            RETURNING previous.old_key
+            "#
+        ))
+        .bind(uid)
+        .bind(url)
+        .bind(storage_key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        previous
+            .map(|(old_key,)| old_key)
+            .ok_or_else(|| AppError::NotFound("Utilisateur non trouvé".into()))
+    }
+}
+
+/// Échappe les caractères spéciaux de LIKE (`_` est fréquent dans les usernames)
+fn escape_like(term: &str) -> String {
+    let mut escaped = String::with_capacity(term.len());
+    for c in term.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
 }
@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::user::{UserSettings, UserSettingsRepository};
+use crate::error::AppError;
+
+pub struct PostgresUserSettingsRepository {
+    pool: PgPool,
+}
+
+impl PostgresUserSettingsRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserSettingsRepository for PostgresUserSettingsRepository {
+    async fn find(&self, user_uid: &str) -> Result<UserSettings, AppError> {
+        let settings = sqlx::query_as::<_, UserSettings>(
+            r#"
+            SELECT user_uid, dm_policy, sensitive_content, updated_at
+            FROM user_settings
+            WHERE user_uid = $1
+            "#,
+        )
+        .bind(user_uid)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(settings.unwrap_or_else(|| UserSettings::default_for(user_uid.to_string())))
+    }
+
+    async fn save(&self, settings: &UserSettings) -> Result<UserSettings, AppError> {
+        let saved = sqlx::query_as::<_, UserSettings>(
+            r#"
+            INSERT INTO user_settings (user_uid, dm_policy, sensitive_content, updated_at)
+            VALUES ($1, $2, $3, NOW())
+            ON CONFLICT (user_uid)
+            DO UPDATE SET dm_policy = EXCLUDED.dm_policy,
+                sensitive_content = EXCLUDED.sensitive_content, updated_at = NOW()
+            RETURNING user_uid, dm_policy, sensitive_content, updated_at
+            "#,
+        )
+        .bind(&settings.user_uid)
+        .bind(&settings.dm_policy)
+        .bind(&settings.sensitive_content)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Erreur DB: {}", e)))?;
+
+        Ok(saved)
+    }
+}
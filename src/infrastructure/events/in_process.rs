@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::{broadcast, mpsc};
+
+use crate::domain::event::{DomainEvent, EventBus, PublishedEvent};
+use crate::error::AppError;
+
+/// Taille des files d'attente du bus
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Bus limité au processus courant, pour une instance unique et les tests
+pub struct InProcessEventBus {
+    sender: broadcast::Sender<PublishedEvent>,
+    last_id: AtomicU64,
+}
+
+impl InProcessEventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self {
+            sender,
+            last_id: AtomicU64::new(0),
+        }
+    }
+}
+
+impl Default for InProcessEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl EventBus for InProcessEventBus {
+    async fn publish(&self, event: &DomainEvent) -> Result<(), AppError> {
+        let id = self.last_id.fetch_add(1, Ordering::SeqCst) + 1;
+        // Aucun abonné n'est pas une erreur
+        let _ = self.sender.send(PublishedEvent {
+            id,
+            event: event.clone(),
+        });
+        Ok(())
+    }
+
+    async fn subscribe(&self) -> Result<mpsc::Receiver<PublishedEvent>, AppError> {
+        let mut receiver = self.sender.subscribe();
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+
+        tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => {
+                        if tx.send(event).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(missed)) => {
+                        tracing::warn!("{} événements perdus par le bus local", missed);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unread_count(count: i64) -> DomainEvent {
+        DomainEvent::UnreadCount {
+            recipient_uid: "alice".into(),
+            count,
+        }
+    }
+
+    #[tokio::test]
+    async fn numerote_les_evenements_dans_l_ordre_de_publication() {
+        let bus = InProcessEventBus::new();
+        let mut first = bus.subscribe().await.unwrap();
+        let mut second = bus.subscribe().await.unwrap();
+
+        for count in 1..=3 {
+            bus.publish(&unread_count(count)).await.unwrap();
+        }
+
+        for receiver in [&mut first, &mut second] {
+            let mut ids = Vec::new();
+            for _ in 0..3 {
+                ids.push(receiver.recv().await.unwrap().id);
+            }
+            assert_eq!(ids, [1, 2, 3]);
+        }
+    }
+
+    #[tokio::test]
+    async fn publie_sans_abonne_sans_perdre_la_numerotation() {
+        let bus = InProcessEventBus::new();
+        bus.publish(&unread_count(1)).await.unwrap();
+
+        let mut receiver = bus.subscribe().await.unwrap();
+        bus.publish(&unread_count(2)).await.unwrap();
+
+        let published = receiver.recv().await.unwrap();
+        assert_eq!(published.id, 2);
+        assert!(matches!(published.event, DomainEvent::UnreadCount { count: 2, .. }));
+    }
+}
@@ -37,13 +37,7 @@ struct FirebaseTokenClaims {
 
 impl FirebaseClient {
     pub async fn new(config: &AppConfig) -> Result<Self, AppError> {
-        let http_client = reqwest::Client::new();
-
-        let client = Self {
-            project_id: config.firebase_project_id.clone(),
-            http_client,
-            cached_keys: Arc::new(RwLock::new(HashMap::new())),
-        };
+        let client = Self::without_keys(config);
 
         // Pre-fetch keys
         client.refresh_keys().await?;
@@ -53,6 +47,16 @@ impl FirebaseClient {
         Ok(client)
     }
 
+    /// Client sans clés de signature : tous les tokens sont refusés tant que
+    /// les clés ne sont pas chargées
+    pub fn without_keys(config: &AppConfig) -> Self {
+        Self {
+            project_id: config.firebase_project_id.clone(),
+            http_client: reqwest::Client::new(),
+            cached_keys: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
     async fn refresh_keys(&self) -> Result<(), AppError> {
         let response: HashMap<String, String> = self
             .http_client
@@ -3,8 +3,9 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use async_trait::async_trait;
+use uuid::Uuid;
 
-use crate::domain::user::{User, UserRepository};
+use crate::domain::user::{ProfileImageKind, User, UserRepository};
 use crate::error::AppError;
 
 /// Repository utilisateur en mémoire pour le développement.
@@ -36,6 +37,11 @@ impl UserRepository for InMemoryUserRepository {
         Ok(users.get(uid).cloned())
     }
 
+    async fn find_by_uids(&self, uids: &[String]) -> Result<Vec<User>, AppError> {
+        let users = self.users.read().await;
+        Ok(uids.iter().filter_map(|uid| users.get(uid).cloned()).collect())
+    }
+
     async fn find_by_username(&self, username: &str) -> Result<Option<User>, AppError> {
         let usernames = self.usernames.read().await;
         let username_lower = username.to_lowercase();
@@ -100,4 +106,57 @@ impl UserRepository for InMemoryUserRepository {
         let usernames = self.usernames.read().await;
         Ok(usernames.contains_key(&username.to_lowercase()))
     }
+
+    /// Version simplifiée : ni accents ni blocages (pas de table de blocages en mémoire)
+    async fn search(&self, term: &str, _viewer_uid: Option<&str>, limit: i64) -> Result<Vec<User>, AppError> {
+        let users = self.users.read().await;
+        let mut matches: Vec<User> = users
+            .values()
+            .filter(|user| {
+                user.username.to_lowercase().starts_with(term)
+                    || user.display_name.to_lowercase().contains(term)
+            })
+            .cloned()
+            .collect();
+
+        matches.sort_by_key(|user| {
+            let exact = user.username.to_lowercase() == term
+                || user.display_name.to_lowercase() == term;
+            (!exact, !user.is_verified, -user.followers_count, user.username.clone())
+        });
+        matches.truncate(limit.max(0) as usize);
+
+        Ok(matches)
+    }
+
+    async fn set_pinned_post(&self, uid: &str, post_id: Option<Uuid>) -> Result<(), AppError> {
+        let mut users = self.users.write().await;
+        let user = users
+            .get_mut(uid)
+            .ok_or_else(|| AppError::NotFound("Utilisateur non trouvé".into()))?;
+        user.pinned_post_id = post_id;
+
+        Ok(())
+    }
+
+    /// Les clés de stockage ne sont pas conservées en mémoire
+    async fn set_profile_image(
+        &self,
+        uid: &str,
+        kind: ProfileImageKind,
+        url: &str,
+        _storage_key: &str,
+    ) -> Result<Option<String>, AppError> {
+        let mut users = self.users.write().await;
+        let user = users
+            .get_mut(uid)
+            .ok_or_else(|| AppError::NotFound("Utilisateur non trouvé".into()))?;
+
+        match kind {
+            ProfileImageKind::Avatar => user.avatar_url = Some(url.to_string()),
+            ProfileImageKind::Banner => user.banner_url = Some(url.to_string()),
+        }
+
+        Ok(None)
+    }
 }
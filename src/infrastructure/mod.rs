@@ -1,2 +1,4 @@
 pub mod database;
+pub mod events;
 pub mod firebase;
+pub mod storage;
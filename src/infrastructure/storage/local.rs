@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use std::path::{Component, Path, PathBuf};
+
+use crate::domain::media::MediaStorage;
+use crate::error::AppError;
+
+/// Stockage sur le système de fichiers local, servi par l'API sous `/media`.
+/// Pour une instance unique ; en production multi-instances, utiliser S3.
+pub struct LocalMediaStorage {
+    root: PathBuf,
+    public_url: String,
+}
+
+impl LocalMediaStorage {
+    pub fn new(root: impl Into<PathBuf>, public_url: &str) -> Self {
+        Self {
+            root: root.into(),
+            public_url: public_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Refuse les clés qui sortiraient du répertoire racine
+    fn path_for(&self, key: &str) -> Result<PathBuf, AppError> {
+        let relative = Path::new(key);
+        if !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+            return Err(AppError::Internal(format!("Clé de média invalide: {}", key)));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl MediaStorage for LocalMediaStorage {
+    async fn put(&self, key: &str, data: Vec<u8>, _content_type: &str) -> Result<(), AppError> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| AppError::Internal(format!("Erreur stockage: {}", e)))?;
+        }
+
+        tokio::fs::write(&path, data)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur stockage: {}", e)))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        match tokio::fs::remove_file(self.path_for(key)?).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(AppError::Internal(format!("Erreur stockage: {}", e))),
+        }
+    }
+
+    fn public_url(&self, key: &str) -> String {
+        format!("{}/{}", self.public_url, key)
+    }
+}
@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use s3::creds::Credentials;
+use s3::{Bucket, Region};
+
+use crate::domain::media::MediaStorage;
+use crate::error::AppError;
+
+/// Stockage compatible S3 (AWS, MinIO, Scaleway...). Avec un endpoint
+/// personnalisé, les URLs sont en « path style » (`endpoint/bucket/clé`).
+pub struct S3MediaStorage {
+    bucket: Box<Bucket>,
+    public_url: String,
+}
+
+impl S3MediaStorage {
+    pub fn new(
+        bucket_name: &str,
+        region: &str,
+        endpoint: Option<&str>,
+        access_key_id: Option<&str>,
+        secret_access_key: Option<&str>,
+        public_url: Option<&str>,
+    ) -> Result<Self, AppError> {
+        let region = match endpoint {
+            Some(endpoint) => Region::Custom {
+                region: region.to_string(),
+                endpoint: endpoint.trim_end_matches('/').to_string(),
+            },
+            None => region
+                .parse()
+                .map_err(|e| AppError::Internal(format!("Région S3 invalide: {}", e)))?,
+        };
+
+        let credentials = Credentials::new(access_key_id, secret_access_key, None, None, None)
+            .map_err(|e| AppError::Internal(format!("Identifiants S3 invalides: {}", e)))?;
+
+        let mut bucket = Bucket::new(bucket_name, region, credentials)
+            .map_err(|e| AppError::Internal(format!("Configuration S3 invalide: {}", e)))?;
+        if endpoint.is_some() {
+            bucket = bucket.with_path_style();
+        }
+
+        let public_url = match public_url {
+            Some(url) => url.trim_end_matches('/').to_string(),
+            None => bucket.url(),
+        };
+
+        Ok(Self { bucket, public_url })
+    }
+}
+
+#[async_trait]
+impl MediaStorage for S3MediaStorage {
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &str) -> Result<(), AppError> {
+        let response = self
+            .bucket
+            .put_object_with_content_type(key, &data, content_type)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur stockage S3: {}", e)))?;
+
+        match response.status_code() {
+            200..=299 => Ok(()),
+            status => Err(AppError::Internal(format!(
+                "Erreur stockage S3: statut {} pour {}",
+                status, key
+            ))),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        let response = self
+            .bucket
+            .delete_object(key)
+            .await
+            .map_err(|e| AppError::Internal(format!("Erreur stockage S3: {}", e)))?;
+
+        // S3 répond 204 même si l'objet n'existe pas
+        match response.status_code() {
+            200..=299 | 404 => Ok(()),
+            status => Err(AppError::Internal(format!(
+                "Erreur stockage S3: statut {} pour {}",
+                status, key
+            ))),
+        }
+    }
+
+    fn public_url(&self, key: &str) -> String {
+        format!("{}/{}", self.public_url, key)
+    }
+}
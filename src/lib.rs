@@ -6,11 +6,27 @@ pub mod infrastructure;
 pub mod presentation;
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use application::services::{PostService, UserService};
+use application::realtime::EventHub;
+use application::services::{
+    BookmarkService, ConversationService, DraftService, HashtagService, MediaService, MuteService,
+    NotificationService, PollService, PostService, SuggestionService, UserService,
+};
 use config::AppConfig;
-use infrastructure::database::{create_pool, PostgresPostRepository, PostgresUserRepository};
+use domain::event::EventBus;
+use domain::media::MediaStorage;
+use infrastructure::database::{
+    create_pool, PostgresBlockRepository, PostgresBookmarkRepository,
+    PostgresCloseFriendRepository, PostgresConversationRepository, PostgresDraftRepository,
+    PostgresEventBus, PostgresFollowRepository, PostgresHashtagRepository,
+    PostgresMediaRepository, PostgresMuteRepository, PostgresNotificationRepository,
+    PostgresPollRepository, PostgresPostRepository, PostgresSuggestionRepository,
+    PostgresUserRepository, PostgresUserSettingsRepository,
+};
+use infrastructure::events::InProcessEventBus;
 use infrastructure::firebase::FirebaseClient;
+use infrastructure::storage::{LocalMediaStorage, S3MediaStorage};
 use sqlx::PgPool;
 
 #[derive(Clone)]
@@ -18,6 +34,16 @@ pub struct AppState {
     pub firebase: Arc<FirebaseClient>,
     pub user_service: Arc<UserService>,
     pub post_service: Arc<PostService>,
+    pub mute_service: Arc<MuteService>,
+    pub bookmark_service: Arc<BookmarkService>,
+    pub hashtag_service: Arc<HashtagService>,
+    pub media_service: Arc<MediaService>,
+    pub poll_service: Arc<PollService>,
+    pub notification_service: Arc<NotificationService>,
+    pub conversation_service: Arc<ConversationService>,
+    pub draft_service: Arc<DraftService>,
+    pub suggestion_service: Arc<SuggestionService>,
+    pub event_hub: Arc<EventHub>,
     pub config: Arc<AppConfig>,
     pub db_pool: PgPool,
 }
@@ -29,25 +55,227 @@ impl AppState {
 
         // Client Firebase pour la vérification des tokens
         let firebase = FirebaseClient::new(&config).await?;
+
+        Self::from_parts(config, db_pool, firebase)
+    }
+
+    /// Assemble les repositories et les services autour d'une connexion à la base
+    /// et d'un client Firebase déjà créés
+    pub fn from_parts(
+        config: AppConfig,
+        db_pool: PgPool,
+        firebase: FirebaseClient,
+    ) -> Result<Self, error::AppError> {
         let firebase = Arc::new(firebase);
 
         // Repositories
         let user_repository = Arc::new(PostgresUserRepository::new(db_pool.clone()));
         let post_repository = Arc::new(PostgresPostRepository::new(db_pool.clone()));
+        let follow_repository = Arc::new(PostgresFollowRepository::new(db_pool.clone()));
+        let block_repository = Arc::new(PostgresBlockRepository::new(db_pool.clone()));
+        let close_friend_repository =
+            Arc::new(PostgresCloseFriendRepository::new(db_pool.clone()));
+        let mute_repository = Arc::new(PostgresMuteRepository::new(db_pool.clone()));
+        let bookmark_repository = Arc::new(PostgresBookmarkRepository::new(db_pool.clone()));
+        let hashtag_repository = Arc::new(PostgresHashtagRepository::new(db_pool.clone()));
+        let notification_repository =
+            Arc::new(PostgresNotificationRepository::new(db_pool.clone()));
+        let settings_repository = Arc::new(PostgresUserSettingsRepository::new(db_pool.clone()));
+        let conversation_repository =
+            Arc::new(PostgresConversationRepository::new(db_pool.clone()));
+        let suggestion_repository = Arc::new(PostgresSuggestionRepository::new(db_pool.clone()));
+        let media_repository = Arc::new(PostgresMediaRepository::new(db_pool.clone()));
+        let poll_repository = Arc::new(PostgresPollRepository::new(db_pool.clone()));
+        let draft_repository = Arc::new(PostgresDraftRepository::new(db_pool.clone()));
+
+        // Stockage des médias
+        let media_storage: Arc<dyn MediaStorage> = match config.media_storage.as_str() {
+            "s3" => {
+                let bucket = config.s3_bucket.as_deref().ok_or_else(|| {
+                    error::AppError::Internal("S3_BUCKET est requis avec MEDIA_STORAGE=s3".into())
+                })?;
+                Arc::new(S3MediaStorage::new(
+                    bucket,
+                    &config.s3_region,
+                    config.s3_endpoint.as_deref(),
+                    config.s3_access_key_id.as_deref(),
+                    config.s3_secret_access_key.as_deref(),
+                    config.media_public_url.as_deref(),
+                )?)
+            }
+            _ => {
+                let public_url = config
+                    .media_public_url
+                    .clone()
+                    .unwrap_or_else(|| format!("http://localhost:{}/media", config.port));
+                Arc::new(LocalMediaStorage::new(&config.media_local_dir, &public_url))
+            }
+        };
+
+        // Diffusion temps réel, partagée entre instances par LISTEN/NOTIFY
+        let event_bus: Arc<dyn EventBus> = match config.event_bus.as_str() {
+            "memory" => Arc::new(InProcessEventBus::new()),
+            _ => Arc::new(PostgresEventBus::new(db_pool.clone())),
+        };
+        let event_hub = Arc::new(EventHub::new(event_bus));
 
         // Services
-        let user_service = Arc::new(UserService::new(user_repository));
-        let post_service = Arc::new(PostService::new(post_repository));
+        let notification_service = Arc::new(NotificationService::new(
+            notification_repository,
+            mute_repository.clone(),
+            block_repository.clone(),
+            user_repository.clone(),
+            event_hub.clone(),
+        ));
+        let media_service = Arc::new(MediaService::new(
+            media_repository,
+            media_storage,
+            config.media_max_bytes,
+        ));
+        let poll_service = Arc::new(PollService::new(poll_repository));
+        let user_service = Arc::new(UserService::new(
+            user_repository.clone(),
+            follow_repository.clone(),
+            block_repository.clone(),
+            close_friend_repository,
+            settings_repository.clone(),
+            notification_service.clone(),
+            media_service.clone(),
+        ));
+        let post_service = Arc::new(PostService::new(
+            post_repository.clone(),
+            user_repository.clone(),
+            block_repository.clone(),
+            follow_repository.clone(),
+            settings_repository.clone(),
+            notification_service.clone(),
+            media_service.clone(),
+            poll_service.clone(),
+            event_hub.clone(),
+            config.content_policies.clone(),
+        ));
+        let draft_service = Arc::new(DraftService::new(
+            draft_repository,
+            post_service.clone(),
+            media_service.clone(),
+            poll_service.clone(),
+        ));
+        let mute_service = Arc::new(MuteService::new(
+            mute_repository,
+            user_repository.clone(),
+            post_repository.clone(),
+        ));
+        let bookmark_service = Arc::new(BookmarkService::new(
+            bookmark_repository,
+            post_repository,
+            block_repository.clone(),
+        ));
+        let suggestion_service = Arc::new(SuggestionService::new(
+            suggestion_repository,
+            user_repository.clone(),
+            Duration::from_secs(config.suggestions_ttl_minutes * 60),
+        ));
+        let conversation_service = Arc::new(ConversationService::new(
+            conversation_repository,
+            user_repository,
+            settings_repository,
+            follow_repository,
+            block_repository,
+            config.content_policies.clone(),
+        ));
+        let hashtag_service = Arc::new(HashtagService::new(
+            hashtag_repository,
+            Duration::from_secs(config.trending_window_minutes * 60),
+        ));
 
         Ok(Self {
             firebase,
             user_service,
             post_service,
+            mute_service,
+            bookmark_service,
+            hashtag_service,
+            media_service,
+            poll_service,
+            notification_service,
+            conversation_service,
+            draft_service,
+            suggestion_service,
+            event_hub,
             config: Arc::new(config),
             db_pool,
         })
     }
 
+    /// Lance les tâches périodiques en arrière-plan
+    pub fn spawn_background_jobs(&self) {
+        // Relais des événements du bus vers les clients temps réel
+        let event_hub = self.event_hub.clone();
+        tokio::spawn(async move { event_hub.run().await });
+
+        // Recalcul des hashtags en tendance
+        let hashtag_service = self.hashtag_service.clone();
+        let refresh_interval = Duration::from_secs(self.config.trending_refresh_seconds.max(1));
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(refresh_interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = hashtag_service.refresh_trending().await {
+                    tracing::warn!("Erreur recalcul des tendances: {}", e);
+                }
+            }
+        });
+
+        // Purge horaire des médias orphelins
+        let media_service = self.media_service.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(3600));
+            loop {
+                ticker.tick().await;
+                if let Err(e) = media_service.cleanup_orphans().await {
+                    tracing::warn!("Erreur purge des médias: {}", e);
+                }
+            }
+        });
+
+        // Publication des posts planifiés
+        let draft_service = self.draft_service.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(15));
+            loop {
+                ticker.tick().await;
+                if let Err(e) = draft_service.publish_due().await {
+                    tracing::warn!("Erreur publication des posts planifiés: {}", e);
+                }
+            }
+        });
+
+        // Clôture des sondages expirés
+        let poll_service = self.poll_service.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(60));
+            loop {
+                ticker.tick().await;
+                if let Err(e) = poll_service.close_expired().await {
+                    tracing::warn!("Erreur clôture des sondages: {}", e);
+                }
+            }
+        });
+
+        // Recalcul des suggestions d'abonnement expirées
+        let suggestion_service = self.suggestion_service.clone();
+        let refresh_interval = Duration::from_secs(self.config.suggestions_refresh_seconds.max(1));
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(refresh_interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = suggestion_service.refresh_stale().await {
+                    tracing::warn!("Erreur recalcul des suggestions: {}", e);
+                }
+            }
+        });
+    }
+
     /// Helper pour exécuter un fichier de migration avec plusieurs statements
     async fn execute_migration_file(
         &self,
@@ -55,8 +283,14 @@ impl AppState {
         name: &str,
     ) -> Result<(), error::AppError> {
         for statement in sql.split(';') {
+            // Retirer les commentaires pour ne pas ignorer le statement qui les suit
+            let statement = statement
+                .lines()
+                .filter(|line| !line.trim_start().starts_with("--"))
+                .collect::<Vec<_>>()
+                .join("\n");
             let statement = statement.trim();
-            if !statement.is_empty() && !statement.starts_with("--") {
+            if !statement.is_empty() {
                 sqlx::query(statement)
                     .execute(&self.db_pool)
                     .await
@@ -93,6 +327,160 @@ impl AppState {
         )
         .await?;
 
+        // Migration 4: reposts & bookmarks
+        self.execute_migration_file(
+            include_str!("../migrations/004_create_reposts_bookmarks.sql"),
+            "reposts_bookmarks",
+        )
+        .await?;
+
+        // Migration 5: follows & blocks
+        self.execute_migration_file(
+            include_str!("../migrations/005_create_follows_blocks.sql"),
+            "follows_blocks",
+        )
+        .await?;
+
+        // Migration 6: threads & mutes
+        self.execute_migration_file(
+            include_str!("../migrations/006_create_mutes.sql"),
+            "mutes",
+        )
+        .await?;
+
+        // Migration 7: bookmark folders
+        self.execute_migration_file(
+            include_str!("../migrations/007_create_bookmark_folders.sql"),
+            "bookmark_folders",
+        )
+        .await?;
+
+        // Migration 8: post mentions
+        self.execute_migration_file(
+            include_str!("../migrations/008_create_post_mentions.sql"),
+            "post_mentions",
+        )
+        .await?;
+
+        // Migration 9: hashtags & trending
+        self.execute_migration_file(
+            include_str!("../migrations/009_create_hashtags.sql"),
+            "hashtags",
+        )
+        .await?;
+
+        // Migration 10: notifications
+        self.execute_migration_file(
+            include_str!("../migrations/010_create_notifications.sql"),
+            "notifications",
+        )
+        .await?;
+
+        // Migration 11: conversations & user settings
+        self.execute_migration_file(
+            include_str!("../migrations/011_create_conversations.sql"),
+            "conversations",
+        )
+        .await?;
+
+        // Migration 12: full-text search on posts
+        self.execute_migration_file(
+            include_str!("../migrations/012_add_posts_search.sql"),
+            "posts_search",
+        )
+        .await?;
+
+        // Migration 13: user search (accents, prefixes and substrings)
+        self.execute_migration_file(
+            include_str!("../migrations/013_add_users_search.sql"),
+            "users_search",
+        )
+        .await?;
+
+        // Migration 14: follow suggestions cache
+        self.execute_migration_file(
+            include_str!("../migrations/014_create_user_suggestions.sql"),
+            "user_suggestions",
+        )
+        .await?;
+
+        // Migration 15: media attachments
+        self.execute_migration_file(
+            include_str!("../migrations/015_create_media.sql"),
+            "media",
+        )
+        .await?;
+
+        // Migration 16: avatar and banner uploads
+        self.execute_migration_file(
+            include_str!("../migrations/016_add_profile_images.sql"),
+            "profile_images",
+        )
+        .await?;
+
+        // Migration 17: polls
+        self.execute_migration_file(
+            include_str!("../migrations/017_create_polls.sql"),
+            "polls",
+        )
+        .await?;
+
+        // Migration 18: drafts & scheduled posts
+        self.execute_migration_file(
+            include_str!("../migrations/018_create_post_drafts.sql"),
+            "post_drafts",
+        )
+        .await?;
+
+        // Migration 19: reply controls & hidden replies
+        self.execute_migration_file(
+            include_str!("../migrations/019_add_reply_controls.sql"),
+            "reply_controls",
+        )
+        .await?;
+
+        // Migration 20: pinned posts
+        self.execute_migration_file(
+            include_str!("../migrations/020_add_pinned_posts.sql"),
+            "pinned_posts",
+        )
+        .await?;
+
+        // Migration 21: post audience & close friends
+        self.execute_migration_file(
+            include_str!("../migrations/021_add_post_audience.sql"),
+            "post_audience",
+        )
+        .await?;
+
+        // Migration 22: content warnings & sensitive labels
+        self.execute_migration_file(
+            include_str!("../migrations/022_add_post_labels.sql"),
+            "post_labels",
+        )
+        .await?;
+
+        // Migration 23: keyword mutes on whole words
+        self.execute_migration_file(
+            include_str!("../migrations/023_add_muted_phrase_matching.sql"),
+            "muted_phrase_matching",
+        )
+        .await?;
+
+        // Migration 24: realtime event ids shared by all instances
+        self.execute_migration_file(
+            include_str!("../migrations/024_add_event_ids.sql"),
+            "event_ids",
+        )
+        .await?;
+
+        // Migration 25: accent-insensitive username prefix search
+        self.execute_migration_file(
+            include_str!("../migrations/025_unaccent_username_search.sql"),
+            "unaccent_username_search",
+        )
+        .await?;
+
         tracing::info!("Migrations terminées avec succès");
 
         Ok(())
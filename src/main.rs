@@ -30,6 +30,9 @@ async fn main() {
         .await
         .expect("Erreur lors des migrations");
 
+    // Lancer les tâches périodiques
+    state.spawn_background_jobs();
+
     // Créer le routeur
     let app = create_router(state);
 
@@ -1,5 +1,7 @@
 mod auth_user;
+mod optional_auth_user;
 mod validated_json;
 
 pub use auth_user::AuthUser;
+pub use optional_auth_user::OptionalAuthUser;
 pub use validated_json::ValidatedJson;
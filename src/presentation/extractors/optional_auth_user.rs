@@ -0,0 +1,33 @@
+use std::convert::Infallible;
+
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::request::Parts,
+};
+
+use crate::presentation::middleware::AuthenticatedUser;
+
+/// Utilisateur connecté s'il y en a un, pour les routes publiques
+/// protégées par `optional_auth_middleware`.
+pub struct OptionalAuthUser(pub Option<AuthenticatedUser>);
+
+impl OptionalAuthUser {
+    pub fn uid(&self) -> Option<&str> {
+        self.0.as_ref().map(|user| user.uid.as_str())
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for OptionalAuthUser
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(OptionalAuthUser(
+            parts.extensions.get::<AuthenticatedUser>().cloned(),
+        ))
+    }
+}
@@ -1,6 +1,15 @@
-use axum::{extract::State, http::StatusCode, Json};
+use axum::{
+    extract::{Multipart, State},
+    http::StatusCode,
+    Json,
+};
 
-use crate::application::dto::{ApiResponse, RegisterRequest, UserResponse};
+use super::media::read_file_field;
+use crate::application::dto::{
+    ApiResponse, ContentPolicyResponse, RegisterRequest, SettingsResponse, UpdateSettingsRequest,
+    UserResponse,
+};
+use crate::domain::user::ProfileImageKind;
 use crate::error::AppError;
 use crate::presentation::extractors::{AuthUser, ValidatedJson};
 use crate::AppState;
@@ -38,3 +47,76 @@ pub async fn get_me(
 
     Ok(Json(ApiResponse::success(UserResponse::from(user))))
 }
+
+/// GET /api/v1/auth/me/settings - Réglages du compte
+pub async fn get_settings(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> Result<Json<ApiResponse<SettingsResponse>>, AppError> {
+    let settings = state.user_service.get_settings(&auth_user.uid).await?;
+
+    Ok(Json(ApiResponse::success(SettingsResponse::from(settings))))
+}
+
+/// PUT /api/v1/auth/me/settings - Modifier les réglages du compte
+pub async fn update_settings(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Json(payload): Json<UpdateSettingsRequest>,
+) -> Result<Json<ApiResponse<SettingsResponse>>, AppError> {
+    let settings = state
+        .user_service
+        .update_settings(&auth_user.uid, payload)
+        .await?;
+
+    Ok(Json(ApiResponse::with_message(
+        SettingsResponse::from(settings),
+        "Réglages mis à jour",
+    )))
+}
+
+/// GET /api/v1/auth/me/content-policy - Limites de publication du compte
+pub async fn get_content_policy(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> Result<Json<ApiResponse<ContentPolicyResponse>>, AppError> {
+    let policy = state.post_service.content_policy(&auth_user.uid).await?;
+
+    Ok(Json(ApiResponse::success(ContentPolicyResponse::from(policy))))
+}
+
+/// PUT /api/v1/auth/me/avatar - Remplacer l'avatar (multipart, champ `file`)
+pub async fn update_avatar(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    multipart: Multipart,
+) -> Result<Json<ApiResponse<UserResponse>>, AppError> {
+    let bytes = read_file_field(multipart).await?;
+    let user = state
+        .user_service
+        .update_profile_image(&auth_user.uid, ProfileImageKind::Avatar, bytes)
+        .await?;
+
+    Ok(Json(ApiResponse::with_message(
+        UserResponse::from(user),
+        "Avatar mis à jour",
+    )))
+}
+
+/// PUT /api/v1/auth/me/banner - Remplacer la bannière (multipart, champ `file`)
+pub async fn update_banner(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    multipart: Multipart,
+) -> Result<Json<ApiResponse<UserResponse>>, AppError> {
+    let bytes = read_file_field(multipart).await?;
+    let user = state
+        .user_service
+        .update_profile_image(&auth_user.uid, ProfileImageKind::Banner, bytes)
+        .await?;
+
+    Ok(Json(ApiResponse::with_message(
+        UserResponse::from(user),
+        "Bannière mise à jour",
+    )))
+}
@@ -0,0 +1,117 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    ApiResponse, BookmarkFolderRequest, BookmarkFolderResponse, BookmarkFoldersListResponse,
+    BookmarkRequest, BookmarksFilter, PaginationQuery, PostsListResponse,
+};
+use crate::error::AppError;
+use crate::presentation::extractors::{AuthUser, ValidatedJson};
+use crate::AppState;
+
+/// POST /api/v1/posts/:id/bookmark - Ajouter un post aux favoris (ou le changer de dossier)
+pub async fn bookmark_post(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+    payload: Option<Json<BookmarkRequest>>,
+) -> Result<StatusCode, AppError> {
+    let Json(payload) = payload.unwrap_or_default();
+
+    state
+        .bookmark_service
+        .bookmark(&auth_user.uid, id, payload.folder_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// DELETE /api/v1/posts/:id/bookmark - Retirer un post des favoris
+pub async fn unbookmark_post(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.bookmark_service.unbookmark(&auth_user.uid, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/v1/bookmarks - Lister ses favoris (optionnellement d'un dossier)
+pub async fn get_bookmarks(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(filter): Query<BookmarksFilter>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<PostsListResponse>>, AppError> {
+    let posts = state
+        .bookmark_service
+        .get_bookmarks(&auth_user.uid, filter.folder_id, pagination.limit, pagination.offset)
+        .await?;
+    let posts = state
+        .post_service
+        .build_responses(posts, Some(&auth_user.uid))
+        .await?;
+
+    Ok(Json(ApiResponse::success(PostsListResponse::new(posts))))
+}
+
+/// GET /api/v1/bookmarks/folders - Lister ses dossiers de favoris
+pub async fn get_bookmark_folders(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> Result<Json<ApiResponse<BookmarkFoldersListResponse>>, AppError> {
+    let folders = state.bookmark_service.get_folders(&auth_user.uid).await?;
+
+    Ok(Json(ApiResponse::success(
+        BookmarkFoldersListResponse::from_folders(folders),
+    )))
+}
+
+/// POST /api/v1/bookmarks/folders - Créer un dossier de favoris
+pub async fn create_bookmark_folder(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<BookmarkFolderRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<BookmarkFolderResponse>>), AppError> {
+    let folder = state
+        .bookmark_service
+        .create_folder(auth_user.uid, payload.name)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::with_message(
+            BookmarkFolderResponse::from(folder),
+            "Dossier créé avec succès",
+        )),
+    ))
+}
+
+/// PUT /api/v1/bookmarks/folders/:id - Renommer un dossier de favoris
+pub async fn rename_bookmark_folder(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+    ValidatedJson(payload): ValidatedJson<BookmarkFolderRequest>,
+) -> Result<Json<ApiResponse<BookmarkFolderResponse>>, AppError> {
+    let folder = state
+        .bookmark_service
+        .rename_folder(&auth_user.uid, id, payload.name)
+        .await?;
+
+    Ok(Json(ApiResponse::success(BookmarkFolderResponse::from(folder))))
+}
+
+/// DELETE /api/v1/bookmarks/folders/:id - Supprimer un dossier (les favoris sont conservés)
+pub async fn delete_bookmark_folder(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.bookmark_service.delete_folder(&auth_user.uid, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
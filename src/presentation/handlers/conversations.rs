@@ -0,0 +1,130 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    ApiResponse, ConversationResponse, ConversationsListResponse, MessageResponse,
+    MessagesListResponse, PaginationQuery, SendMessageRequest, StartConversationRequest,
+};
+use crate::error::AppError;
+use crate::presentation::extractors::{AuthUser, ValidatedJson};
+use crate::AppState;
+
+/// GET /api/v1/conversations - Lister ses conversations, la plus récente en premier
+pub async fn get_conversations(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<ConversationsListResponse>>, AppError> {
+    let conversations = state
+        .conversation_service
+        .get_conversations(&auth_user.uid, pagination.limit, pagination.offset)
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        ConversationsListResponse::from_overviews(conversations),
+    )))
+}
+
+/// POST /api/v1/conversations - Démarrer une conversation (ou retrouver la conversation à deux existante)
+pub async fn start_conversation(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<StartConversationRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<ConversationResponse>>), AppError> {
+    let conversation = state
+        .conversation_service
+        .start_conversation(&auth_user.uid, payload.participant_uids, payload.content)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(ConversationResponse::from(conversation))),
+    ))
+}
+
+/// GET /api/v1/conversations/:id - Obtenir une conversation
+pub async fn get_conversation(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ConversationResponse>>, AppError> {
+    let conversation = state
+        .conversation_service
+        .get_conversation(&auth_user.uid, id)
+        .await?;
+
+    Ok(Json(ApiResponse::success(ConversationResponse::from(conversation))))
+}
+
+/// DELETE /api/v1/conversations/:id - Quitter une conversation
+pub async fn leave_conversation(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state
+        .conversation_service
+        .leave_conversation(&auth_user.uid, id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/v1/conversations/:id/messages - Messages d'une conversation, du plus récent au plus ancien
+pub async fn get_messages(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<MessagesListResponse>>, AppError> {
+    let messages = state
+        .conversation_service
+        .get_messages(&auth_user.uid, id, pagination.limit, pagination.offset)
+        .await?;
+
+    Ok(Json(ApiResponse::success(MessagesListResponse::from_messages(messages))))
+}
+
+/// POST /api/v1/conversations/:id/messages - Envoyer un message
+pub async fn send_message(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+    ValidatedJson(payload): ValidatedJson<SendMessageRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<MessageResponse>>), AppError> {
+    let message = state
+        .conversation_service
+        .send_message(&auth_user.uid, id, payload.content)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(MessageResponse::from(message))),
+    ))
+}
+
+/// DELETE /api/v1/conversations/:id/messages/:message_id - Supprimer un de ses messages
+pub async fn delete_message(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path((id, message_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    state
+        .conversation_service
+        .delete_message(&auth_user.uid, id, message_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/conversations/:id/read - Marquer la conversation comme lue
+pub async fn mark_conversation_read(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.conversation_service.mark_read(&auth_user.uid, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
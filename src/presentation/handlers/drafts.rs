@@ -0,0 +1,99 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    ApiResponse, DraftResponse, DraftsListResponse, PaginationQuery, SaveDraftRequest,
+    ScheduleDraftRequest,
+};
+use crate::error::AppError;
+use crate::presentation::extractors::{AuthUser, ValidatedJson};
+use crate::AppState;
+
+/// POST /api/v1/drafts - Créer un brouillon ou planifier un post
+pub async fn create_draft(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<SaveDraftRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<DraftResponse>>), AppError> {
+    let (draft, media) = state
+        .draft_service
+        .create(&auth_user.uid, payload)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(DraftResponse::new(draft, media))),
+    ))
+}
+
+/// GET /api/v1/drafts - Lister ses posts planifiés puis ses brouillons
+pub async fn get_drafts(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<DraftsListResponse>>, AppError> {
+    let drafts = state
+        .draft_service
+        .list(&auth_user.uid, pagination.limit, pagination.offset)
+        .await?
+        .into_iter()
+        .map(|(draft, media)| DraftResponse::new(draft, media))
+        .collect();
+
+    Ok(Json(ApiResponse::success(DraftsListResponse::new(drafts))))
+}
+
+/// GET /api/v1/drafts/:id - Obtenir un brouillon
+pub async fn get_draft(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<DraftResponse>>, AppError> {
+    let (draft, media) = state.draft_service.get(id, &auth_user.uid).await?;
+
+    Ok(Json(ApiResponse::success(DraftResponse::new(draft, media))))
+}
+
+/// PUT /api/v1/drafts/:id - Modifier un brouillon
+pub async fn update_draft(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+    ValidatedJson(payload): ValidatedJson<SaveDraftRequest>,
+) -> Result<Json<ApiResponse<DraftResponse>>, AppError> {
+    let (draft, media) = state
+        .draft_service
+        .update(id, &auth_user.uid, payload)
+        .await?;
+
+    Ok(Json(ApiResponse::success(DraftResponse::new(draft, media))))
+}
+
+/// PUT /api/v1/drafts/:id/schedule - Planifier, replanifier ou déplanifier
+pub async fn schedule_draft(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ScheduleDraftRequest>,
+) -> Result<Json<ApiResponse<DraftResponse>>, AppError> {
+    let (draft, media) = state
+        .draft_service
+        .reschedule(id, &auth_user.uid, payload.publish_at)
+        .await?;
+
+    Ok(Json(ApiResponse::success(DraftResponse::new(draft, media))))
+}
+
+/// DELETE /api/v1/drafts/:id - Supprimer un brouillon ou annuler une publication planifiée
+pub async fn delete_draft(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.draft_service.cancel(id, &auth_user.uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,35 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+
+use crate::application::dto::{ApiResponse, PaginationQuery, PostsListResponse, TrendingListResponse};
+use crate::error::AppError;
+use crate::presentation::extractors::OptionalAuthUser;
+use crate::AppState;
+
+/// GET /api/v1/hashtags/:tag/posts - Timeline d'un hashtag
+pub async fn get_hashtag_posts(
+    State(state): State<AppState>,
+    viewer: OptionalAuthUser,
+    Path(tag): Path<String>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<PostsListResponse>>, AppError> {
+    let posts = state
+        .post_service
+        .get_hashtag_posts(&tag, viewer.uid(), pagination.limit, pagination.offset)
+        .await?;
+    let posts = state.post_service.build_responses(posts, viewer.uid()).await?;
+
+    Ok(Json(ApiResponse::success(PostsListResponse::new(posts))))
+}
+
+/// GET /api/v1/hashtags/trending - Hashtags en tendance
+pub async fn get_trending_hashtags(
+    State(state): State<AppState>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<TrendingListResponse>>, AppError> {
+    let hashtags = state.hashtag_service.get_trending(pagination.limit).await?;
+
+    Ok(Json(ApiResponse::success(TrendingListResponse::from_hashtags(hashtags))))
+}
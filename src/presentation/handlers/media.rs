@@ -0,0 +1,44 @@
+use axum::{
+    extract::{Multipart, State},
+    http::StatusCode,
+    Json,
+};
+
+use crate::application::dto::{ApiResponse, MediaResponse};
+use crate::error::AppError;
+use crate::presentation::extractors::AuthUser;
+use crate::AppState;
+
+/// POST /api/v1/media - Envoyer une image (multipart, champ `file`)
+pub async fn upload_media(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    multipart: Multipart,
+) -> Result<(StatusCode, Json<ApiResponse<MediaResponse>>), AppError> {
+    let bytes = read_file_field(multipart).await?;
+    let media = state.media_service.upload(&auth_user.uid, bytes).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(MediaResponse::from(media))),
+    ))
+}
+
+/// Contenu du champ `file` d'un formulaire multipart
+pub(crate) async fn read_file_field(mut multipart: Multipart) -> Result<Vec<u8>, AppError> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::Validation(format!("Formulaire invalide: {}", e)))?
+    {
+        if field.name() == Some("file") {
+            let bytes = field
+                .bytes()
+                .await
+                .map_err(|e| AppError::Validation(format!("Fichier illisible: {}", e)))?;
+            return Ok(bytes.to_vec());
+        }
+    }
+
+    Err(AppError::Validation("Champ `file` manquant".into()))
+}
@@ -1,7 +1,29 @@
 mod auth;
+mod bookmarks;
+mod conversations;
+mod drafts;
+mod hashtags;
 mod health;
+mod media;
+mod mutes;
+mod notifications;
 mod posts;
+mod search;
+mod stream;
+mod users;
+mod websocket;
 
 pub use auth::*;
+pub use bookmarks::*;
+pub use conversations::*;
+pub use drafts::*;
+pub use hashtags::*;
 pub use health::*;
+pub use media::*;
+pub use mutes::*;
+pub use notifications::*;
 pub use posts::*;
+pub use search::*;
+pub use stream::*;
+pub use users::*;
+pub use websocket::*;
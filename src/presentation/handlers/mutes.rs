@@ -0,0 +1,154 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    ApiResponse, MuteThreadRequest, MuteUserRequest, MuteWordRequest, MutedThreadResponse,
+    MutedUserResponse, MutedWordResponse, MutesListResponse, PaginationQuery,
+};
+use crate::error::AppError;
+use crate::presentation::extractors::{AuthUser, ValidatedJson};
+use crate::AppState;
+
+/// GET /api/v1/mutes/users - Lister les comptes masqués
+pub async fn get_muted_users(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<MutesListResponse<MutedUserResponse>>>, AppError> {
+    let mutes = state
+        .mute_service
+        .get_muted_users(&auth_user.uid, pagination.limit, pagination.offset)
+        .await?
+        .into_iter()
+        .map(|(mute, user)| MutedUserResponse::new(mute, user))
+        .collect();
+
+    Ok(Json(ApiResponse::success(MutesListResponse::new(mutes))))
+}
+
+/// POST /api/v1/mutes/users - Masquer un compte
+pub async fn mute_user(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Json(payload): Json<MuteUserRequest>,
+) -> Result<Json<ApiResponse<MutedUserResponse>>, AppError> {
+    let (mute, user) = state
+        .mute_service
+        .mute_user(&auth_user.uid, &payload.uid, payload.expires_at)
+        .await?;
+
+    Ok(Json(ApiResponse::success(MutedUserResponse::new(mute, user))))
+}
+
+/// DELETE /api/v1/mutes/users/:uid - Ne plus masquer un compte
+pub async fn unmute_user(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(uid): Path<String>,
+) -> Result<StatusCode, AppError> {
+    state.mute_service.unmute_user(&auth_user.uid, &uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/v1/mutes/threads - Lister les fils masqués
+pub async fn get_muted_threads(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<MutesListResponse<MutedThreadResponse>>>, AppError> {
+    let mutes = state
+        .mute_service
+        .get_muted_threads(&auth_user.uid, pagination.limit, pagination.offset)
+        .await?
+        .into_iter()
+        .map(MutedThreadResponse::from)
+        .collect();
+
+    Ok(Json(ApiResponse::success(MutesListResponse::new(mutes))))
+}
+
+/// POST /api/v1/mutes/threads - Masquer un fil de discussion
+pub async fn mute_thread(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Json(payload): Json<MuteThreadRequest>,
+) -> Result<Json<ApiResponse<MutedThreadResponse>>, AppError> {
+    let mute = state
+        .mute_service
+        .mute_thread(&auth_user.uid, payload.post_id, payload.expires_at)
+        .await?;
+
+    Ok(Json(ApiResponse::success(MutedThreadResponse::from(mute))))
+}
+
+/// DELETE /api/v1/mutes/threads/:post_id - Ne plus masquer un fil
+pub async fn unmute_thread(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(post_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.mute_service.unmute_thread(&auth_user.uid, post_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/v1/mutes/words - Lister les mots masqués
+pub async fn get_muted_words(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> Result<Json<ApiResponse<MutesListResponse<MutedWordResponse>>>, AppError> {
+    let words = state
+        .mute_service
+        .get_words(&auth_user.uid)
+        .await?
+        .into_iter()
+        .map(MutedWordResponse::from)
+        .collect();
+
+    Ok(Json(ApiResponse::success(MutesListResponse::new(words))))
+}
+
+/// POST /api/v1/mutes/words - Masquer un mot ou une expression
+pub async fn create_muted_word(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<MuteWordRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<MutedWordResponse>>), AppError> {
+    let word = state
+        .mute_service
+        .add_word(auth_user.uid, payload.phrase, payload.expires_at)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(MutedWordResponse::from(word))),
+    ))
+}
+
+/// PUT /api/v1/mutes/words/:id - Modifier un mot masqué
+pub async fn update_muted_word(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+    ValidatedJson(payload): ValidatedJson<MuteWordRequest>,
+) -> Result<Json<ApiResponse<MutedWordResponse>>, AppError> {
+    let word = state
+        .mute_service
+        .update_word(&auth_user.uid, id, payload.phrase, payload.expires_at)
+        .await?;
+
+    Ok(Json(ApiResponse::success(MutedWordResponse::from(word))))
+}
+
+/// DELETE /api/v1/mutes/words/:id - Supprimer un mot masqué
+pub async fn delete_muted_word(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.mute_service.delete_word(&auth_user.uid, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::application::dto::{
+    ApiResponse, NotificationsListResponse, PaginationQuery, UnreadCountResponse,
+};
+use crate::error::AppError;
+use crate::presentation::extractors::AuthUser;
+use crate::AppState;
+
+/// GET /api/v1/notifications - Notifications groupées de l'utilisateur connecté
+pub async fn get_notifications(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<NotificationsListResponse>>, AppError> {
+    let notifications = state
+        .notification_service
+        .get_notifications(&auth_user.uid, pagination.limit, pagination.offset)
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        NotificationsListResponse::from_notifications(notifications),
+    )))
+}
+
+/// GET /api/v1/notifications/unread-count - Nombre de notifications non lues
+pub async fn get_unread_notifications_count(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> Result<Json<ApiResponse<UnreadCountResponse>>, AppError> {
+    let count = state.notification_service.unread_count(&auth_user.uid).await?;
+
+    Ok(Json(ApiResponse::success(UnreadCountResponse { count })))
+}
+
+/// POST /api/v1/notifications/:id/read - Marquer une notification comme lue
+pub async fn mark_notification_read(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.notification_service.mark_read(&auth_user.uid, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/notifications/read-all - Marquer toutes les notifications comme lues
+pub async fn mark_all_notifications_read(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> Result<StatusCode, AppError> {
+    state.notification_service.mark_all_read(&auth_user.uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -6,23 +6,27 @@ use axum::{
 use uuid::Uuid;
 
 use crate::application::dto::{
-    ApiResponse, CreatePostRequest, PaginationQuery, PostResponse, PostsListResponse,
+    ApiResponse, CreatePostRequest, PaginationQuery, PollVoteRequest, PostResponse,
+    PostsListResponse,
 };
+use crate::domain::post::NewLabel;
 use crate::error::AppError;
-use crate::presentation::extractors::{AuthUser, ValidatedJson};
+use crate::presentation::extractors::{AuthUser, OptionalAuthUser, ValidatedJson};
 use crate::AppState;
 
 /// GET /api/v1/posts - Timeline des posts
 pub async fn get_posts(
     State(state): State<AppState>,
+    viewer: OptionalAuthUser,
     Query(pagination): Query<PaginationQuery>,
 ) -> Result<Json<ApiResponse<PostsListResponse>>, AppError> {
     let posts = state
         .post_service
-        .get_timeline(pagination.limit, pagination.offset)
+        .get_timeline(viewer.uid(), pagination.limit, pagination.offset)
         .await?;
+    let posts = state.post_service.build_responses(posts, viewer.uid()).await?;
 
-    Ok(Json(ApiResponse::success(PostsListResponse::from_posts(posts))))
+    Ok(Json(ApiResponse::success(PostsListResponse::new(posts))))
 }
 
 /// POST /api/v1/posts - Créer un post
@@ -33,13 +37,25 @@ pub async fn create_post(
 ) -> Result<(StatusCode, Json<ApiResponse<PostResponse>>), AppError> {
     let post = state
         .post_service
-        .create_post(auth_user.uid, payload.content)
+        .create_post(
+            auth_user.uid.clone(),
+            payload.content,
+            payload.media,
+            payload.poll,
+            payload.reply_policy,
+            payload.audience,
+            payload.label,
+        )
+        .await?;
+    let post = state
+        .post_service
+        .build_response(post, Some(&auth_user.uid))
         .await?;
 
     Ok((
         StatusCode::CREATED,
         Json(ApiResponse::with_message(
-            PostResponse::from(post),
+            post,
             "Post créé avec succès",
         )),
     ))
@@ -48,15 +64,17 @@ pub async fn create_post(
 /// GET /api/v1/posts/:id - Obtenir un post
 pub async fn get_post(
     State(state): State<AppState>,
+    viewer: OptionalAuthUser,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<PostResponse>>, AppError> {
     let post = state
         .post_service
-        .get_post(id)
+        .get_post(id, viewer.uid())
         .await?
         .ok_or_else(|| AppError::NotFound("Post non trouvé".into()))?;
+    let post = state.post_service.build_response(post, viewer.uid()).await?;
 
-    Ok(Json(ApiResponse::success(PostResponse::from(post))))
+    Ok(Json(ApiResponse::success(post)))
 }
 
 /// DELETE /api/v1/posts/:id - Supprimer un post
@@ -69,18 +87,134 @@ pub async fn delete_post(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// POST /api/v1/posts/:id/like - Aimer un post
+pub async fn like_post(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.post_service.like_post(id, &auth_user.uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// DELETE /api/v1/posts/:id/like - Ne plus aimer un post
+pub async fn unlike_post(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.post_service.unlike_post(id, &auth_user.uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/posts/:id/poll/votes - Voter à un sondage
+pub async fn vote_in_poll(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<PollVoteRequest>,
+) -> Result<Json<ApiResponse<PostResponse>>, AppError> {
+    let post = state
+        .post_service
+        .vote_in_poll(id, &auth_user.uid, payload.option)
+        .await?;
+    let post = state
+        .post_service
+        .build_response(post, Some(&auth_user.uid))
+        .await?;
+
+    Ok(Json(ApiResponse::with_message(post, "Vote enregistré")))
+}
+
+/// POST /api/v1/posts/:id/pin - Épingler un post en tête de son profil
+pub async fn pin_post(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.post_service.pin_post(id, &auth_user.uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// DELETE /api/v1/posts/:id/pin - Désépingler un post
+pub async fn unpin_post(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.post_service.unpin_post(id, &auth_user.uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/posts/:id/hide - Masquer une réponse à sa conversation
+pub async fn hide_reply(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state
+        .post_service
+        .set_reply_hidden(id, &auth_user.uid, true)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// DELETE /api/v1/posts/:id/hide - Réafficher une réponse masquée
+pub async fn unhide_reply(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state
+        .post_service
+        .set_reply_hidden(id, &auth_user.uid, false)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// PUT /api/v1/posts/:id/label - Poser un avertissement de contenu
+pub async fn label_post(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<NewLabel>,
+) -> Result<Json<ApiResponse<PostResponse>>, AppError> {
+    let post = state
+        .post_service
+        .label_post(id, &auth_user.uid, payload)
+        .await?;
+    let post = state
+        .post_service
+        .build_response(post, Some(&auth_user.uid))
+        .await?;
+
+    Ok(Json(ApiResponse::success(post)))
+}
+
+/// DELETE /api/v1/posts/:id/label - Retirer son avertissement de contenu
+pub async fn unlabel_post(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.post_service.unlabel_post(id, &auth_user.uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 /// GET /api/v1/posts/:id/replies - Obtenir les réponses à un post
 pub async fn get_post_replies(
     State(state): State<AppState>,
+    viewer: OptionalAuthUser,
     Path(id): Path<Uuid>,
     Query(pagination): Query<PaginationQuery>,
 ) -> Result<Json<ApiResponse<PostsListResponse>>, AppError> {
     let posts = state
         .post_service
-        .get_replies(id, pagination.limit, pagination.offset)
+        .get_replies(id, viewer.uid(), pagination.limit, pagination.offset)
         .await?;
+    let posts = state.post_service.build_responses(posts, viewer.uid()).await?;
 
-    Ok(Json(ApiResponse::success(PostsListResponse::from_posts(posts))))
+    Ok(Json(ApiResponse::success(PostsListResponse::new(posts))))
 }
 
 /// POST /api/v1/posts/:id/replies - Répondre à un post
@@ -92,13 +226,25 @@ pub async fn create_reply(
 ) -> Result<(StatusCode, Json<ApiResponse<PostResponse>>), AppError> {
     let post = state
         .post_service
-        .create_reply(auth_user.uid, payload.content, parent_id)
+        .create_reply(
+            auth_user.uid.clone(),
+            payload.content,
+            parent_id,
+            payload.media,
+            payload.poll,
+            payload.audience,
+            payload.label,
+        )
+        .await?;
+    let post = state
+        .post_service
+        .build_response(post, Some(&auth_user.uid))
         .await?;
 
     Ok((
         StatusCode::CREATED,
         Json(ApiResponse::with_message(
-            PostResponse::from(post),
+            post,
             "Réponse créée avec succès",
         )),
     ))
@@ -107,13 +253,34 @@ pub async fn create_reply(
 /// GET /api/v1/users/:uid/posts - Obtenir les posts d'un utilisateur
 pub async fn get_user_posts(
     State(state): State<AppState>,
+    viewer: OptionalAuthUser,
+    Path(uid): Path<String>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<PostsListResponse>>, AppError> {
+    let (posts, pinned_id) = state
+        .post_service
+        .get_user_posts(&uid, viewer.uid(), pagination.limit, pagination.offset)
+        .await?;
+    let mut posts = state.post_service.build_responses(posts, viewer.uid()).await?;
+    if let Some(first) = posts.first_mut() {
+        first.pinned = pinned_id == Some(first.id);
+    }
+
+    Ok(Json(ApiResponse::success(PostsListResponse::new(posts))))
+}
+
+/// GET /api/v1/users/:uid/mentions - Obtenir les posts mentionnant un utilisateur
+pub async fn get_user_mentions(
+    State(state): State<AppState>,
+    viewer: OptionalAuthUser,
     Path(uid): Path<String>,
     Query(pagination): Query<PaginationQuery>,
 ) -> Result<Json<ApiResponse<PostsListResponse>>, AppError> {
     let posts = state
         .post_service
-        .get_user_posts(&uid, pagination.limit, pagination.offset)
+        .get_mentions(&uid, viewer.uid(), pagination.limit, pagination.offset)
         .await?;
+    let posts = state.post_service.build_responses(posts, viewer.uid()).await?;
 
-    Ok(Json(ApiResponse::success(PostsListResponse::from_posts(posts))))
+    Ok(Json(ApiResponse::success(PostsListResponse::new(posts))))
 }
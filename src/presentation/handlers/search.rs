@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+
+use crate::application::dto::{
+    ApiResponse, SearchPostsQuery, SearchPostsResponse, SearchUsersQuery, UsersListResponse,
+};
+use crate::error::AppError;
+use crate::presentation::extractors::OptionalAuthUser;
+use crate::AppState;
+
+/// GET /api/v1/search/posts - Recherche plein texte dans les posts
+pub async fn search_posts(
+    State(state): State<AppState>,
+    viewer: OptionalAuthUser,
+    Query(query): Query<SearchPostsQuery>,
+) -> Result<Json<ApiResponse<SearchPostsResponse>>, AppError> {
+    let (posts, next_cursor) = state
+        .post_service
+        .search_posts(
+            &query.q,
+            query.sort,
+            query.cursor.as_deref(),
+            viewer.uid(),
+            query.limit,
+        )
+        .await?;
+    let posts = state.post_service.build_responses(posts, viewer.uid()).await?;
+
+    Ok(Json(ApiResponse::success(SearchPostsResponse::new(posts, next_cursor))))
+}
+
+/// GET /api/v1/search/users - Recherche d'utilisateurs (autocomplétion)
+pub async fn search_users(
+    State(state): State<AppState>,
+    viewer: OptionalAuthUser,
+    Query(query): Query<SearchUsersQuery>,
+) -> Result<Json<ApiResponse<UsersListResponse>>, AppError> {
+    let users = state
+        .user_service
+        .search_users(&query.q, viewer.uid(), query.limit)
+        .await?;
+
+    Ok(Json(ApiResponse::success(UsersListResponse::from_users(users))))
+}
@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use serde::Deserialize;
+use tokio::sync::{broadcast::error::RecvError, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
+use uuid::Uuid;
+
+use crate::application::dto::{NotificationResponse, UnreadCountResponse};
+use crate::application::realtime::EventEnvelope;
+use crate::domain::event::DomainEvent;
+use crate::error::AppError;
+use crate::presentation::extractors::AuthUser;
+use crate::AppState;
+
+/// Nombre maximum de posts suivis par connexion
+const MAX_WATCHED_POSTS: usize = 100;
+
+/// Événements en attente d'envoi avant que le client soit considéré en retard
+const STREAM_BUFFER: usize = 32;
+
+#[derive(Debug, Deserialize)]
+pub struct StreamQuery {
+    /// Identifiants des posts dont suivre les compteurs, séparés par des virgules
+    pub posts: Option<String>,
+}
+
+/// GET /api/v1/stream - Flux SSE des notifications, du nombre de non lues et des compteurs de posts
+pub async fn stream_events(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Query(query): Query<StreamQuery>,
+) -> Result<Sse<ReceiverStream<Result<Event, Infallible>>>, AppError> {
+    let watched_posts = parse_watched_posts(query.posts.as_deref())?;
+    let last_event_id = headers
+        .get("last-event-id")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let subscription = state.event_hub.subscribe(last_event_id.as_deref());
+    let unread_count = state.notification_service.unread_count(&auth_user.uid).await?;
+    let heartbeat = Duration::from_secs(state.config.sse_heartbeat_seconds.max(1));
+
+    let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+    tokio::spawn(async move {
+        let uid = auth_user.uid;
+        let mut receiver = subscription.receiver;
+
+        if subscription.gap && tx.send(Ok(resync_event())).await.is_err() {
+            return;
+        }
+
+        // État initial, puis les événements manqués depuis `Last-Event-ID`
+        let initial = Event::default()
+            .event("unread_count")
+            .json_data(UnreadCountResponse { count: unread_count })
+            .ok();
+        if let Some(event) = initial {
+            if tx.send(Ok(event)).await.is_err() {
+                return;
+            }
+        }
+
+        for envelope in subscription.missed {
+            if let Some(event) = to_sse_event(&state, &uid, &watched_posts, &envelope).await {
+                if tx.send(Ok(event)).await.is_err() {
+                    return;
+                }
+            }
+        }
+
+        loop {
+            let event = tokio::select! {
+                _ = tx.closed() => break,
+                received = receiver.recv() => match received {
+                    Ok(envelope) => to_sse_event(&state, &uid, &watched_posts, &envelope).await,
+                    // Client trop lent : des événements ont été perdus
+                    Err(RecvError::Lagged(_)) => Some(resync_event()),
+                    Err(RecvError::Closed) => break,
+                },
+            };
+
+            if let Some(event) = event {
+                if tx.send(Ok(event)).await.is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    Ok(Sse::new(ReceiverStream::new(rx)).keep_alive(KeepAlive::new().interval(heartbeat)))
+}
+
+fn parse_watched_posts(posts: Option<&str>) -> Result<HashSet<Uuid>, AppError> {
+    let Some(posts) = posts else {
+        return Ok(HashSet::new());
+    };
+
+    let watched = posts
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| {
+            Uuid::parse_str(id)
+                .map_err(|_| AppError::Validation(format!("Identifiant de post invalide: {}", id)))
+        })
+        .collect::<Result<HashSet<_>, _>>()?;
+
+    if watched.len() > MAX_WATCHED_POSTS {
+        return Err(AppError::Validation(format!(
+            "Vous ne pouvez pas suivre plus de {} posts",
+            MAX_WATCHED_POSTS
+        )));
+    }
+
+    Ok(watched)
+}
+
+/// Le client doit recharger son état : des événements ne peuvent pas être rejoués
+fn resync_event() -> Event {
+    Event::default().event("resync").data("{}")
+}
+
+/// Convertit l'événement s'il concerne cette connexion
+async fn to_sse_event(
+    state: &AppState,
+    uid: &str,
+    watched_posts: &HashSet<Uuid>,
+    envelope: &Arc<EventEnvelope>,
+) -> Option<Event> {
+    if let Some(recipient_uid) = envelope.event.recipient_uid() {
+        if recipient_uid != uid {
+            return None;
+        }
+    }
+
+    let event = Event::default().id(&envelope.id).event(envelope.event.name());
+    let event = match &envelope.event {
+        DomainEvent::Notification { notification } => {
+            let (notification, actors) = match state
+                .notification_service
+                .with_actors(vec![notification.clone()])
+                .await
+            {
+                Ok(mut notifications) => notifications.pop()?,
+                Err(e) => {
+                    tracing::warn!("Notification {} non diffusée: {}", notification.id, e);
+                    return None;
+                }
+            };
+            event.json_data(NotificationResponse::new(notification, actors))
+        }
+        DomainEvent::UnreadCount { count, .. } => {
+            event.json_data(UnreadCountResponse { count: *count })
+        }
+        DomainEvent::PostCounters { post_id, .. } => {
+            if !watched_posts.contains(post_id) {
+                return None;
+            }
+            // Les compteurs d'un post hors de l'audience du client ne sont pas diffusés
+            match state.post_service.get_post(*post_id, Some(uid)).await {
+                Ok(Some(_)) => {}
+                Ok(None) => return None,
+                Err(e) => {
+                    tracing::warn!("Compteurs du post {} non diffusés: {}", post_id, e);
+                    return None;
+                }
+            }
+            event.json_data(&envelope.event)
+        }
+        // Les nouveaux posts sont diffusés par la passerelle WebSocket
+        DomainEvent::PostCreated { .. } => return None,
+    };
+
+    event.ok()
+}
@@ -0,0 +1,129 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+
+use crate::application::dto::{
+    ApiResponse, PaginationQuery, PublicUserResponse, SuggestionResponse, SuggestionsListResponse,
+    UsersListResponse,
+};
+use crate::error::AppError;
+use crate::presentation::extractors::{AuthUser, OptionalAuthUser};
+use crate::AppState;
+
+/// GET /api/v1/users/:uid - Obtenir le profil public d'un utilisateur
+pub async fn get_user(
+    State(state): State<AppState>,
+    viewer: OptionalAuthUser,
+    Path(uid): Path<String>,
+) -> Result<Json<ApiResponse<PublicUserResponse>>, AppError> {
+    let user = state.user_service.get_profile(&uid, viewer.uid()).await?;
+
+    Ok(Json(ApiResponse::success(PublicUserResponse::from(user))))
+}
+
+/// POST /api/v1/users/:uid/follow - Suivre un utilisateur
+pub async fn follow_user(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(uid): Path<String>,
+) -> Result<StatusCode, AppError> {
+    state.user_service.follow(&auth_user.uid, &uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// DELETE /api/v1/users/:uid/follow - Ne plus suivre un utilisateur
+pub async fn unfollow_user(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(uid): Path<String>,
+) -> Result<StatusCode, AppError> {
+    state.user_service.unfollow(&auth_user.uid, &uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/v1/users/:uid/block - Bloquer un utilisateur
+pub async fn block_user(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(uid): Path<String>,
+) -> Result<StatusCode, AppError> {
+    state.user_service.block(&auth_user.uid, &uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// DELETE /api/v1/users/:uid/block - Débloquer un utilisateur
+pub async fn unblock_user(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(uid): Path<String>,
+) -> Result<StatusCode, AppError> {
+    state.user_service.unblock(&auth_user.uid, &uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/v1/blocks - Lister les utilisateurs bloqués
+pub async fn get_blocks(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<UsersListResponse>>, AppError> {
+    let users = state
+        .user_service
+        .get_blocked_users(&auth_user.uid, pagination.limit, pagination.offset)
+        .await?;
+
+    Ok(Json(ApiResponse::success(UsersListResponse::from_users(users))))
+}
+
+/// GET /api/v1/close-friends - Lister ses amis proches
+pub async fn get_close_friends(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<UsersListResponse>>, AppError> {
+    let users = state
+        .user_service
+        .get_close_friends(&auth_user.uid, pagination.limit, pagination.offset)
+        .await?;
+
+    Ok(Json(ApiResponse::success(UsersListResponse::from_users(users))))
+}
+
+/// POST /api/v1/close-friends/:uid - Ajouter un utilisateur à ses amis proches
+pub async fn add_close_friend(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(uid): Path<String>,
+) -> Result<StatusCode, AppError> {
+    state.user_service.add_close_friend(&auth_user.uid, &uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// DELETE /api/v1/close-friends/:uid - Retirer un utilisateur de ses amis proches
+pub async fn remove_close_friend(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(uid): Path<String>,
+) -> Result<StatusCode, AppError> {
+    state.user_service.remove_close_friend(&auth_user.uid, &uid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/v1/users/suggestions - Comptes à suivre
+pub async fn get_suggestions(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<SuggestionsListResponse>>, AppError> {
+    let suggestions = state
+        .suggestion_service
+        .get_suggestions(&auth_user.uid, pagination.limit)
+        .await?
+        .into_iter()
+        .map(|(suggestion, user)| SuggestionResponse::new(suggestion, user))
+        .collect();
+
+    Ok(Json(ApiResponse::success(SuggestionsListResponse::new(suggestions))))
+}
@@ -0,0 +1,321 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::Response,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+use crate::application::dto::PostResponse;
+use crate::application::realtime::EventEnvelope;
+use crate::domain::event::DomainEvent;
+use crate::domain::post::normalize_hashtag;
+use crate::error::AppError;
+use crate::AppState;
+
+/// Délai accordé au client pour envoyer son message d'authentification
+const AUTH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Délai d'envoi au-delà duquel un client trop lent est déconnecté
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Nombre maximum de sujets suivis par connexion
+const MAX_SUBSCRIPTIONS: usize = 20;
+
+/// Taille maximum d'un message reçu du client
+const MAX_MESSAGE_SIZE: usize = 16 * 1024;
+
+/// Sujet auquel un client peut s'abonner
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "topic", rename_all = "snake_case")]
+pub enum Topic {
+    /// Nouveaux posts racines (timeline publique)
+    Timeline,
+    /// Posts et réponses d'un utilisateur
+    User { uid: String },
+    /// Réponses dans un fil de discussion
+    Thread { post_id: Uuid },
+    /// Posts contenant un hashtag
+    Hashtag { tag: String },
+}
+
+impl Topic {
+    /// Vrai si le post publié par `event` concerne ce sujet
+    fn matches(&self, event: &DomainEvent) -> bool {
+        let DomainEvent::PostCreated {
+            post_id: id,
+            author_uid,
+            parent_id,
+            root_id,
+            hashtags,
+        } = event
+        else {
+            return false;
+        };
+
+        match self {
+            Topic::Timeline => parent_id.is_none(),
+            Topic::User { uid } => author_uid == uid,
+            Topic::Thread { post_id } => {
+                id != post_id
+                    && (root_id.unwrap_or(*id) == *post_id || *parent_id == Some(*post_id))
+            }
+            Topic::Hashtag { tag } => hashtags.contains(tag),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ClientMessage {
+    Auth {
+        token: String,
+    },
+    Subscribe {
+        #[serde(flatten)]
+        topic: Topic,
+    },
+    Unsubscribe {
+        #[serde(flatten)]
+        topic: Topic,
+    },
+    Ping,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage {
+    Ready {
+        uid: String,
+    },
+    Subscribed {
+        #[serde(flatten)]
+        topic: Topic,
+    },
+    Unsubscribed {
+        #[serde(flatten)]
+        topic: Topic,
+    },
+    Post {
+        topics: Vec<Topic>,
+        post: Box<PostResponse>,
+    },
+    /// Des posts ont été perdus parce que le client ne lisait pas assez vite
+    Lagged {
+        missed: u64,
+    },
+    Pong,
+    Error {
+        message: String,
+    },
+}
+
+/// GET /api/v1/ws - Passerelle WebSocket des timelines en direct
+pub async fn websocket_handler(State(state): State<AppState>, ws: WebSocketUpgrade) -> Response {
+    ws.max_message_size(MAX_MESSAGE_SIZE)
+        .on_upgrade(move |socket| handle_socket(state, socket))
+}
+
+async fn handle_socket(state: AppState, mut socket: WebSocket) {
+    let uid = match authenticate_socket(&state, &mut socket).await {
+        Ok(uid) => uid,
+        Err(e) => {
+            let _ = send(&mut socket, &ServerMessage::Error { message: e.to_string() }).await;
+            let _ = socket.send(Message::Close(None)).await;
+            return;
+        }
+    };
+
+    if send(&mut socket, &ServerMessage::Ready { uid: uid.clone() }).await.is_err() {
+        return;
+    }
+
+    // Le tampon du canal de diffusion absorbe les pics : un client qui ne suit
+    // pas reçoit un message `lagged` plutôt que de ralentir les autres
+    let mut receiver = state.event_hub.subscribe(None).receiver;
+    let mut topics: HashSet<Topic> = HashSet::new();
+
+    loop {
+        let reply = tokio::select! {
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Text(text))) => {
+                    Some(handle_client_message(&state, &uid, &mut topics, &text).await)
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                _ => None,
+            },
+            received = receiver.recv() => match received {
+                Ok(envelope) => match &envelope.event {
+                    DomainEvent::PostCreated { post_id, .. } => {
+                        live_post(&state, &uid, &topics, &envelope, *post_id).await
+                    }
+                    _ => None,
+                },
+                Err(RecvError::Lagged(missed)) => Some(ServerMessage::Lagged { missed }),
+                Err(RecvError::Closed) => break,
+            },
+        };
+
+        if let Some(reply) = reply {
+            if send(&mut socket, &reply).await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// Le premier message doit être `{"type": "auth", "token": "<Firebase ID token>"}`
+async fn authenticate_socket(state: &AppState, socket: &mut WebSocket) -> Result<String, AppError> {
+    let message = tokio::time::timeout(AUTH_TIMEOUT, socket.recv())
+        .await
+        .map_err(|_| AppError::Unauthorized("Authentification attendue".into()))?;
+
+    let Some(Ok(Message::Text(text))) = message else {
+        return Err(AppError::Unauthorized("Authentification attendue".into()));
+    };
+
+    match serde_json::from_str::<ClientMessage>(&text) {
+        Ok(ClientMessage::Auth { token }) => {
+            let claims = state.firebase.verify_id_token(&token).await?;
+            Ok(claims.uid)
+        }
+        _ => Err(AppError::Unauthorized("Authentification attendue".into())),
+    }
+}
+
+async fn handle_client_message(
+    state: &AppState,
+    uid: &str,
+    topics: &mut HashSet<Topic>,
+    text: &str,
+) -> ServerMessage {
+    let message = match serde_json::from_str::<ClientMessage>(text) {
+        Ok(message) => message,
+        Err(e) => {
+            return ServerMessage::Error {
+                message: format!("Message invalide: {}", e),
+            }
+        }
+    };
+
+    let result = match message {
+        ClientMessage::Auth { .. } => Err(AppError::Validation("Déjà authentifié".into())),
+        ClientMessage::Ping => Ok(ServerMessage::Pong),
+        ClientMessage::Subscribe { topic } => subscribe(state, uid, topics, topic).await,
+        ClientMessage::Unsubscribe { topic } => {
+            let topic = normalize_topic(topic);
+            topics.remove(&topic);
+            Ok(ServerMessage::Unsubscribed { topic })
+        }
+    };
+
+    result.unwrap_or_else(|e| ServerMessage::Error {
+        message: e.to_string(),
+    })
+}
+
+fn normalize_topic(topic: Topic) -> Topic {
+    match topic {
+        Topic::Hashtag { tag } => Topic::Hashtag {
+            tag: normalize_hashtag(&tag),
+        },
+        topic => topic,
+    }
+}
+
+async fn subscribe(
+    state: &AppState,
+    uid: &str,
+    topics: &mut HashSet<Topic>,
+    topic: Topic,
+) -> Result<ServerMessage, AppError> {
+    let topic = normalize_topic(topic);
+
+    if !topics.contains(&topic) && topics.len() >= MAX_SUBSCRIPTIONS {
+        return Err(AppError::Validation(format!(
+            "Vous ne pouvez pas suivre plus de {} sujets",
+            MAX_SUBSCRIPTIONS
+        )));
+    }
+
+    // Les sujets masqués par un blocage sont traités comme inexistants
+    match &topic {
+        Topic::Timeline => {}
+        Topic::User { uid: user_uid } => {
+            state.user_service.get_profile(user_uid, Some(uid)).await?;
+        }
+        Topic::Thread { post_id } => {
+            state
+                .post_service
+                .get_post(*post_id, Some(uid))
+                .await?
+                .ok_or_else(|| AppError::NotFound("Post non trouvé".into()))?;
+        }
+        Topic::Hashtag { tag } => {
+            if tag.is_empty() {
+                return Err(AppError::Validation("Hashtag invalide".into()));
+            }
+        }
+    }
+
+    topics.insert(topic.clone());
+    Ok(ServerMessage::Subscribed { topic })
+}
+
+/// Message à envoyer pour un nouveau post, s'il correspond à un sujet suivi
+/// et n'est ni bloqué ni masqué pour ce client. La réponse du post est
+/// construite une seule fois par événement, pour toutes les connexions.
+async fn live_post(
+    state: &AppState,
+    uid: &str,
+    topics: &HashSet<Topic>,
+    envelope: &EventEnvelope,
+    post_id: Uuid,
+) -> Option<ServerMessage> {
+    let matched: Vec<Topic> = topics
+        .iter()
+        .filter(|topic| topic.matches(&envelope.event))
+        .cloned()
+        .collect();
+    if matched.is_empty() {
+        return None;
+    }
+
+    let result = async {
+        let shared = envelope
+            .post_response
+            .get_or_try_init(|| state.post_service.build_live_response(post_id))
+            .await?;
+        let Some(shared) = shared else {
+            return Ok(None);
+        };
+        state.post_service.live_response_for(shared, uid).await
+    }
+    .await;
+
+    match result {
+        Ok(Some(post)) => Some(ServerMessage::Post {
+            topics: matched,
+            post: Box::new(post),
+        }),
+        Ok(None) => None,
+        Err(e) => {
+            tracing::warn!("Post {} non diffusé: {}", post_id, e);
+            None
+        }
+    }
+}
+
+async fn send(socket: &mut WebSocket, message: &ServerMessage) -> Result<(), ()> {
+    let text = serde_json::to_string(message).map_err(|_| ())?;
+    match tokio::time::timeout(SEND_TIMEOUT, socket.send(Message::Text(text))).await {
+        Ok(Ok(())) => Ok(()),
+        _ => Err(()),
+    }
+}
@@ -26,16 +26,40 @@ pub async fn auth_middleware(
         .and_then(|h| h.to_str().ok())
         .ok_or_else(|| AppError::Unauthorized("Header Authorization manquant".into()))?;
 
+    let user = authenticate(&state, auth_header).await?;
+    request.extensions_mut().insert(user);
+
+    Ok(next.run(request).await)
+}
+
+/// Variante pour les routes publiques : une requête sans header Authorization
+/// passe en anonyme, mais un token présent et invalide est rejeté.
+pub async fn optional_auth_middleware(
+    State(state): State<AppState>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Result<Response, AppError> {
+    if let Some(auth_header) = request.headers().get("Authorization") {
+        let auth_header = auth_header
+            .to_str()
+            .map_err(|_| AppError::Unauthorized("Format d'autorisation invalide".into()))?;
+
+        let user = authenticate(&state, auth_header).await?;
+        request.extensions_mut().insert(user);
+    }
+
+    Ok(next.run(request).await)
+}
+
+async fn authenticate(state: &AppState, auth_header: &str) -> Result<AuthenticatedUser, AppError> {
     let token = auth_header
         .strip_prefix("Bearer ")
         .ok_or_else(|| AppError::Unauthorized("Format d'autorisation invalide".into()))?;
 
     let claims = state.firebase.verify_id_token(token).await?;
 
-    request.extensions_mut().insert(AuthenticatedUser {
+    Ok(AuthenticatedUser {
         uid: claims.uid,
         email: claims.email,
-    });
-
-    Ok(next.run(request).await)
+    })
 }
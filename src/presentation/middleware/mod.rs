@@ -1,3 +1,3 @@
 mod auth;
 
-pub use auth::{auth_middleware, AuthenticatedUser};
+pub use auth::{auth_middleware, optional_auth_middleware, AuthenticatedUser};
@@ -1,46 +1,270 @@
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
-    routing::{delete, get, post},
+    routing::{delete, get, post, put},
     Router,
 };
 use tower_http::cors::{Any, CorsLayer};
+use tower_http::services::ServeDir;
 use tower_http::trace::TraceLayer;
 
 use crate::presentation::handlers;
-use crate::presentation::middleware::auth_middleware;
+use crate::presentation::middleware::{auth_middleware, optional_auth_middleware};
 use crate::AppState;
 
 pub fn create_router(state: AppState) -> Router {
     // Routes publiques
-    let public_routes = Router::new()
-        .route("/health", get(handlers::health_check))
+    let public_routes = Router::new().route("/health", get(handlers::health_check));
+
+    // Routes publiques personnalisées si un token est fourni
+    let optional_auth_routes = Router::new()
         // Timeline et lecture de posts (public)
-        .route("/api/v1/posts", get(handlers::get_posts))
-        .route("/api/v1/posts/{id}", get(handlers::get_post))
-        .route("/api/v1/posts/{id}/replies", get(handlers::get_post_replies))
-        .route("/api/v1/users/{uid}/posts", get(handlers::get_user_posts));
+        .route("/posts", get(handlers::get_posts))
+        .route("/posts/:id", get(handlers::get_post))
+        .route("/posts/:id/replies", get(handlers::get_post_replies))
+        .route("/hashtags/trending", get(handlers::get_trending_hashtags))
+        .route("/hashtags/:tag/posts", get(handlers::get_hashtag_posts))
+        .route("/users/:uid", get(handlers::get_user))
+        .route("/users/:uid/posts", get(handlers::get_user_posts))
+        .route("/users/:uid/mentions", get(handlers::get_user_mentions))
+        .route("/search/posts", get(handlers::search_posts))
+        .route("/search/users", get(handlers::search_users))
+        .layer(middleware::from_fn_with_state(state.clone(), optional_auth_middleware));
+
+    // Taille maximale des envois d'images (la marge couvre l'enveloppe multipart)
+    let upload_limit = state.config.media_max_bytes + 64 * 1024;
 
     // Routes protégées par authentification
     let protected_routes = Router::new()
         // Auth
         .route("/auth/register", post(handlers::register))
         .route("/auth/me", get(handlers::get_me))
+        .route(
+            "/auth/me/settings",
+            get(handlers::get_settings).put(handlers::update_settings),
+        )
+        .route("/auth/me/content-policy", get(handlers::get_content_policy))
+        .route(
+            "/auth/me/avatar",
+            put(handlers::update_avatar).layer(DefaultBodyLimit::max(upload_limit)),
+        )
+        .route(
+            "/auth/me/banner",
+            put(handlers::update_banner).layer(DefaultBodyLimit::max(upload_limit)),
+        )
         // Posts (écriture)
         .route("/posts", post(handlers::create_post))
-        .route("/posts/{id}", delete(handlers::delete_post))
-        .route("/posts/{id}/replies", post(handlers::create_reply))
+        .route("/posts/:id", delete(handlers::delete_post))
+        .route("/posts/:id/replies", post(handlers::create_reply))
+        .route("/posts/:id/poll/votes", post(handlers::vote_in_poll))
+        .route(
+            "/posts/:id/pin",
+            post(handlers::pin_post).delete(handlers::unpin_post),
+        )
+        .route(
+            "/posts/:id/hide",
+            post(handlers::hide_reply).delete(handlers::unhide_reply),
+        )
+        .route(
+            "/posts/:id/label",
+            put(handlers::label_post).delete(handlers::unlabel_post),
+        )
+        // Brouillons et posts planifiés
+        .route(
+            "/drafts",
+            get(handlers::get_drafts).post(handlers::create_draft),
+        )
+        .route(
+            "/drafts/:id",
+            get(handlers::get_draft)
+                .put(handlers::update_draft)
+                .delete(handlers::delete_draft),
+        )
+        .route("/drafts/:id/schedule", put(handlers::schedule_draft))
+        // Médias
+        .route(
+            "/media",
+            post(handlers::upload_media).layer(DefaultBodyLimit::max(upload_limit)),
+        )
+        .route(
+            "/posts/:id/like",
+            post(handlers::like_post).delete(handlers::unlike_post),
+        )
+        // Favoris
+        .route(
+            "/posts/:id/bookmark",
+            post(handlers::bookmark_post).delete(handlers::unbookmark_post),
+        )
+        .route("/bookmarks", get(handlers::get_bookmarks))
+        .route(
+            "/bookmarks/folders",
+            get(handlers::get_bookmark_folders).post(handlers::create_bookmark_folder),
+        )
+        .route(
+            "/bookmarks/folders/:id",
+            put(handlers::rename_bookmark_folder).delete(handlers::delete_bookmark_folder),
+        )
+        // Relations entre utilisateurs
+        .route("/users/suggestions", get(handlers::get_suggestions))
+        .route(
+            "/users/:uid/follow",
+            post(handlers::follow_user).delete(handlers::unfollow_user),
+        )
+        .route(
+            "/users/:uid/block",
+            post(handlers::block_user).delete(handlers::unblock_user),
+        )
+        .route("/blocks", get(handlers::get_blocks))
+        .route("/close-friends", get(handlers::get_close_friends))
+        .route(
+            "/close-friends/:uid",
+            post(handlers::add_close_friend).delete(handlers::remove_close_friend),
+        )
+        // Masquages
+        .route(
+            "/mutes/users",
+            get(handlers::get_muted_users).post(handlers::mute_user),
+        )
+        .route("/mutes/users/:uid", delete(handlers::unmute_user))
+        .route(
+            "/mutes/threads",
+            get(handlers::get_muted_threads).post(handlers::mute_thread),
+        )
+        .route("/mutes/threads/:post_id", delete(handlers::unmute_thread))
+        .route(
+            "/mutes/words",
+            get(handlers::get_muted_words).post(handlers::create_muted_word),
+        )
+        .route(
+            "/mutes/words/:id",
+            put(handlers::update_muted_word).delete(handlers::delete_muted_word),
+        )
+        // Notifications
+        .route("/notifications", get(handlers::get_notifications))
+        .route(
+            "/notifications/unread-count",
+            get(handlers::get_unread_notifications_count),
+        )
+        .route(
+            "/notifications/read-all",
+            post(handlers::mark_all_notifications_read),
+        )
+        .route(
+            "/notifications/:id/read",
+            post(handlers::mark_notification_read),
+        )
+        // Messages privés
+        .route(
+            "/conversations",
+            get(handlers::get_conversations).post(handlers::start_conversation),
+        )
+        .route(
+            "/conversations/:id",
+            get(handlers::get_conversation).delete(handlers::leave_conversation),
+        )
+        .route(
+            "/conversations/:id/messages",
+            get(handlers::get_messages).post(handlers::send_message),
+        )
+        .route(
+            "/conversations/:id/messages/:message_id",
+            delete(handlers::delete_message),
+        )
+        .route(
+            "/conversations/:id/read",
+            post(handlers::mark_conversation_read),
+        )
+        // Temps réel
+        .route("/stream", get(handlers::stream_events))
         .layer(middleware::from_fn_with_state(state.clone(), auth_middleware));
 
+    // Passerelle WebSocket : l'authentification se fait par le premier message
+    let websocket_routes = Router::new().route("/ws", get(handlers::websocket_handler));
+
     // Configuration CORS
     let cors = CorsLayer::new()
         .allow_origin(Any)
         .allow_methods(Any)
         .allow_headers(Any);
 
-    Router::new()
-        .merge(public_routes)
-        .nest("/api/v1", protected_routes)
+    let mut router = Router::new().merge(public_routes);
+
+    // Fichiers du stockage local ; avec S3, ils sont servis par le bucket
+    if state.config.media_storage == "local" {
+        router = router.nest_service("/media", ServeDir::new(&state.config.media_local_dir));
+    }
+
+    router
+        .nest(
+            "/api/v1",
+            optional_auth_routes
+                .merge(protected_routes)
+                .merge(websocket_routes),
+        )
         .layer(cors)
         .layer(TraceLayer::new_for_http())
         .with_state(state)
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Method, Request, StatusCode};
+    use sqlx::postgres::PgPoolOptions;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::config::AppConfig;
+    use crate::infrastructure::firebase::FirebaseClient;
+
+    /// Routeur complet sur une base jamais contactée : suffisant pour les
+    /// requêtes rejetées avant tout accès aux données
+    fn test_router() -> Router {
+        std::env::set_var("DATABASE_URL", "postgres://localhost/authify_routes_test");
+        std::env::set_var("FIREBASE_PROJECT_ID", "authify-test");
+        std::env::set_var("EVENT_BUS", "memory");
+        std::env::set_var("MEDIA_STORAGE", "local");
+        let config = AppConfig::from_env().expect("configuration de test");
+
+        let db_pool = PgPoolOptions::new()
+            .connect_lazy(&config.database_url)
+            .expect("URL de base valide");
+        let firebase = FirebaseClient::without_keys(&config);
+        let state = AppState::from_parts(config, db_pool, firebase).expect("état de test");
+
+        create_router(state)
+    }
+
+    async fn status(router: &Router, method: Method, uri: &str) -> StatusCode {
+        router
+            .clone()
+            .oneshot(Request::builder().method(method).uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn les_routes_parametrees_sont_atteintes() {
+        let router = test_router();
+
+        // L'identifiant est extrait du chemin (et refusé car ce n'est pas un UUID)
+        assert_eq!(
+            status(&router, Method::GET, "/api/v1/posts/pas-un-uuid").await,
+            StatusCode::BAD_REQUEST
+        );
+        // La route existe et exige une authentification
+        assert_eq!(
+            status(&router, Method::POST, "/api/v1/users/alice/follow").await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status(&router, Method::DELETE, "/api/v1/conversations/abc/messages/def").await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status(&router, Method::GET, "/api/v1/inconnue/abc").await,
+            StatusCode::NOT_FOUND
+        );
+    }
+}